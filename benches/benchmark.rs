@@ -1,67 +1,161 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-use std::fs::read_to_string;
-use std::path::Path;
-use std::sync::LazyLock;
+use criterion::Criterion;
+// Only needed when at least one year is enabled.
+#[cfg(feature = "_any_year")]
+use {criterion::criterion_group, std::fs::read_to_string, std::path::Path, std::sync::LazyLock};
 
+/// Days after the optional `;` only have a first part, for example the last day of a calendar.
+#[cfg(feature = "_any_year")]
 macro_rules! benchmark {
-    ($year:tt $($day:tt),* $(,)?) => {
+    ($year:tt $($day:tt),* $(; $($single:tt),*)?) => {
         pub mod $year {
             use super::*;
-            $(
-                pub fn $day(c: &mut Criterion) {
-                    let data = {
-                        static DATA: LazyLock<String> = LazyLock::new(|| {
-                            let path = Path::new("input")
-                                .join(stringify!($year))
-                                .join(stringify!($day))
-                                .with_extension("txt");
-                            read_to_string(path).unwrap()
-                        });
-                        &DATA
-                    };
-
-                    let mut group = c.benchmark_group(format!("{}_{}", stringify!($year), stringify!($day)));
-
-                    // Individual benchmarks
-                    group.bench_function("parse", |b| {
-                        b.iter(|| aoc::$year::$day::parse(data))
-                    });
 
-                    let input = aoc::$year::$day::parse(data);
-
-                    group.bench_function("part1", |b| {
-                        b.iter(|| aoc::$year::$day::part1(&input))
-                    });
-
-                    group.bench_function("part2", |b| {
-                        b.iter(|| aoc::$year::$day::part2(&input))
-                    });
-
-                    // Total time benchmark
-                    group.bench_function("total", |b| {
-                        b.iter(|| {
-                            let input = aoc::$year::$day::parse(data);
-                            let part1 = aoc::$year::$day::part1(&input);
-                            let part2 = aoc::$year::$day::part2(&input);
-                            (input, part1, part2)
-                        })
-                    });
-
-                    group.finish();
-                }
-            )*
+            $(bench!($year $day part1, part2);)*
+            $($(bench!($year $single part1);)*)?
 
             criterion_group!(
                 name = benches;
                 config = Criterion::default();
-                targets = $($day,)*
+                targets = $($day,)* $($($single,)*)?
             );
         }
     }
 }
 
+#[cfg(feature = "_any_year")]
+macro_rules! bench {
+    ($year:tt $day:tt $($part:ident),+) => {
+        pub fn $day(c: &mut Criterion) {
+            // Days without an input file are skipped.
+            let Some(data) = ({
+                static DATA: LazyLock<Option<String>> = LazyLock::new(|| {
+                    let path = Path::new("input")
+                        .join(stringify!($year))
+                        .join(stringify!($day))
+                        .with_extension("txt");
+                    read_to_string(path).ok()
+                });
+                DATA.as_deref()
+            }) else {
+                return;
+            };
+
+            let mut group = c.benchmark_group(format!("{}_{}", stringify!($year), stringify!($day)));
+
+            // Individual benchmarks
+            group.bench_function("parse", |b| {
+                b.iter(|| aoc::$year::$day::parse(data))
+            });
+
+            let input = aoc::$year::$day::parse(data);
+
+            $(group.bench_function(stringify!($part), |b| {
+                b.iter(|| aoc::$year::$day::$part(&input))
+            });)+
+
+            // Total time benchmark
+            group.bench_function("total", |b| {
+                b.iter(|| {
+                    let input = aoc::$year::$day::parse(data);
+                    let answers = ($(aoc::$year::$day::$part(&input),)+);
+                    (input, answers)
+                })
+            });
+
+            group.finish();
+        }
+    }
+}
+
+#[cfg(feature = "year2015")]
+benchmark!(year2015
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2016")]
+benchmark!(year2016
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2017")]
+benchmark!(year2017
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2018")]
+benchmark!(year2018
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2019")]
+benchmark!(year2019
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2020")]
+benchmark!(year2020
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2021")]
+benchmark!(year2021
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2022")]
+benchmark!(year2022
+    day01, day02, day03, day04, day05, day06, day07, day08
+);
+
+#[cfg(feature = "year2023")]
 benchmark!(year2023
-    day01
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2024")]
+benchmark!(year2024
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
 );
 
-criterion_main!(year2023::benches);
+#[cfg(feature = "year2025")]
+benchmark!(year2025
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11;
+    day12
+);
+
+// Equivalent to `criterion_main!` but only includes the years enabled by cargo features.
+fn main() {
+    #[cfg(feature = "year2015")]
+    year2015::benches();
+    #[cfg(feature = "year2016")]
+    year2016::benches();
+    #[cfg(feature = "year2017")]
+    year2017::benches();
+    #[cfg(feature = "year2018")]
+    year2018::benches();
+    #[cfg(feature = "year2019")]
+    year2019::benches();
+    #[cfg(feature = "year2020")]
+    year2020::benches();
+    #[cfg(feature = "year2021")]
+    year2021::benches();
+    #[cfg(feature = "year2022")]
+    year2022::benches();
+    #[cfg(feature = "year2023")]
+    year2023::benches();
+    #[cfg(feature = "year2024")]
+    year2024::benches();
+    #[cfg(feature = "year2025")]
+    year2025::benches();
+
+    Criterion::default().configure_from_args().final_summary();
+}
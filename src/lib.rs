@@ -6,9 +6,60 @@ macro_rules! library {
 }
 
 library!(util "Utility modules to handle common recurring Advent of Code patterns."
-    ansi, bitset, grid, hash, heap, integer, iter, math, md5, parse, point, slice, thread
+    ansi, assembunny, bitset, cycle, differential, duet, elfcode, grid, handheld, hash, heap, image, intcode, integer, iter, knot, math, md5, parse, point, slice, sparse, thread, timer
 );
 
+#[cfg(feature = "year2015")]
+library!(year2015 "Help Santa by solving puzzles to fuel his weather machine."
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2016")]
+library!(year2016 "Recover the sleigh coordinates stolen by the Easter Bunny."
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2017")]
+library!(year2017 "Get digitized into the computer to fix the printer."
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2018")]
+library!(year2018 "Fix the anomalies in the timeline."
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2019")]
+library!(year2019 "Rescue Santa from deep space."
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2020")]
+library!(year2020 "Take a well-earned vacation."
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2021")]
+library!(year2021 "Find the sleigh keys at the bottom of the ocean."
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2022")]
+library!(year2022 "Collect star fruit to feed the reindeer."
+    day01, day02, day03, day04, day05, day06, day07, day08
+);
+
+#[cfg(feature = "year2023")]
 library!(year2023 "Restore global snow production."
-    day01
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2024")]
+library!(year2024 "Locate the Chief Historian in time for the big Christmas sleigh launch."
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2025")]
+library!(year2025 "Decorate the North Pole in twelve days."
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12
 );
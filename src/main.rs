@@ -1,11 +1,14 @@
 use aoc::util::ansi::*;
+use aoc::util::differential::*;
 use aoc::util::parse::*;
+use aoc::util::timer;
+#[cfg(feature = "_any_year")]
 use aoc::*;
 use std::env::args;
-use std::fs::read_to_string;
-use std::iter::empty;
+use std::fs::{read_dir, read_to_string};
+use std::panic::{catch_unwind, set_hook, take_hook};
 use std::path::{Path, PathBuf};
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 fn main() {
     // Parse command line options
@@ -19,12 +22,51 @@ fn main() {
     };
 
     // Filter solutions
-    let solutions: Vec<_> = empty()
-        .chain(year2023())
+    let years: Vec<Vec<Solution>> = vec![
+        #[cfg(feature = "year2015")]
+        year2015(),
+        #[cfg(feature = "year2016")]
+        year2016(),
+        #[cfg(feature = "year2017")]
+        year2017(),
+        #[cfg(feature = "year2018")]
+        year2018(),
+        #[cfg(feature = "year2019")]
+        year2019(),
+        #[cfg(feature = "year2020")]
+        year2020(),
+        #[cfg(feature = "year2021")]
+        year2021(),
+        #[cfg(feature = "year2022")]
+        year2022(),
+        #[cfg(feature = "year2023")]
+        year2023(),
+        #[cfg(feature = "year2024")]
+        year2024(),
+        #[cfg(feature = "year2025")]
+        year2025(),
+    ];
+    let solutions: Vec<_> = years
+        .into_iter()
+        .flatten()
         .filter(|solution| year.is_none_or(|y: u32| y == solution.year))
         .filter(|solution| day.is_none_or(|d: u32| d == solution.day))
         .collect();
 
+    // Optionally compare solutions against their reference implementations instead.
+    if args().any(|a| a == "--differential") {
+        differential(&solutions);
+        return;
+    }
+
+    // Optionally record named timers inside each solution.
+    let verbose = args().any(|a| a == "--verbose");
+    timer::enable(verbose);
+
+    if verbose && cfg!(not(feature = "timer")) {
+        eprintln!("{BOLD}{RED}Timers are compiled out, rebuild with --features timer{RESET}");
+    }
+
     // Pretty print output for each solution.
     let mut duration = Duration::ZERO;
 
@@ -33,16 +75,29 @@ fn main() {
         day,
         path,
         wrapper,
+        ..
     } in &solutions
     {
-        if let Ok(data) = read_to_string(path) {
+        // A directory of inputs with the same name as the input file, for example
+        // `input/year2023/day01/*.txt`, runs the solution against every file it contains.
+        let directory = path.with_extension("");
+
+        if directory.is_dir() {
+            println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
+            duration += run_many(path, &directory, *wrapper);
+        } else if let Ok(data) = read_to_string(path) {
             let instant = Instant::now();
-            let (part1, part2) = wrapper(data);
+            let answers = wrapper(data);
             duration += instant.elapsed();
 
             println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
-            println!("    Part 1: {part1}");
-            println!("    Part 2: {part2}");
+            for (part, answer) in answers.iter().enumerate() {
+                println!("    Part {}: {answer}", part + 1);
+            }
+
+            if verbose {
+                print_timers();
+            }
         } else {
             eprintln!("{BOLD}{RED}{year} Day {day:02}{RESET}");
             eprintln!("    Missing input!");
@@ -55,42 +110,314 @@ fn main() {
 
     // Optionally print totals.
     if args().any(|a| a == "--totals") {
-        println!("{BOLD}{YELLOW}⭐ {}{RESET}", 2 * solutions.len());
+        let stars: usize = solutions
+            .iter()
+            .map(|solution| stars(solution, &solutions))
+            .sum();
+        println!("{BOLD}{YELLOW}⭐ {stars}{RESET}");
         println!("{BOLD}{WHITE}🕓 {} ms{RESET}", duration.as_millis());
     }
 }
 
+/// One star per part. The last day of a calendar only has one puzzle, its second star is awarded
+/// for finishing every other day of the year.
+fn stars(solution: &Solution, solutions: &[Solution]) -> usize {
+    let days = solutions
+        .iter()
+        .filter(|other| other.year == solution.year)
+        .count();
+    let complete = solution.parts == 1 && days == solution.day as usize;
+    solution.parts + complete as usize
+}
+
+/// Prints the tree of named timers recorded while running the last solution.
+fn print_timers() {
+    let records = timer::take();
+    let labels: Vec<_> = records
+        .iter()
+        .map(|record| match record.count {
+            1 => format!("{}{}", "  ".repeat(record.depth), record.name),
+            n => format!("{}{} (×{n})", "  ".repeat(record.depth), record.name),
+        })
+        .collect();
+    let width = labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    for (label, record) in labels.iter().zip(&records) {
+        let padding = " ".repeat(width - label.chars().count());
+        println!(
+            "    {WHITE}{label}{padding}  {:>8} µs{RESET}",
+            record.total.as_micros()
+        );
+    }
+}
+
+/// Runs a solution against the default input file (if present) and every `.txt` file in
+/// `directory`, printing a table of answers and timings. Panics are caught and reported
+/// per input so that a solution relying on a property of one particular input stands out.
+fn run_many(path: &Path, directory: &Path, wrapper: fn(String) -> Vec<String>) -> Duration {
+    let inputs = inputs(path);
+
+    // Silence the default panic message, the payload is shown in the table instead.
+    let hook = take_hook();
+    set_hook(Box::new(|_| ()));
+
+    let mut duration = Duration::ZERO;
+    let mut rows = vec![[
+        "Input".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+        "Time".to_string(),
+    ]];
+
+    for input in &inputs {
+        let name = input
+            .strip_prefix(directory)
+            .unwrap_or(input)
+            .display()
+            .to_string();
+        let Ok(data) = read_to_string(input) else {
+            rows.push([
+                name,
+                format!("{RED}Unreadable{RESET}"),
+                String::new(),
+                String::new(),
+            ]);
+            continue;
+        };
+
+        let instant = Instant::now();
+        let result = catch_unwind(|| wrapper(data));
+        let elapsed = instant.elapsed();
+        timer::take();
+
+        match result {
+            Ok(answers) => {
+                duration += elapsed;
+                let mut answers = answers.into_iter();
+                let part1 = answers.next().unwrap_or_default();
+                let part2 = answers.next().unwrap_or_default();
+                rows.push([name, part1, part2, format!("{} µs", elapsed.as_micros())]);
+            }
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                rows.push([name, format!("{RED}Panic!{RESET}"), message, String::new()]);
+            }
+        }
+    }
+
+    set_hook(hook);
+
+    // Align columns, ignoring the width of any ANSI escape codes.
+    let visible = |s: &str| s.replace(RED, "").replace(RESET, "").chars().count();
+    let widths: Vec<_> = (0..4)
+        .map(|i| rows.iter().map(|row| visible(&row[i])).max().unwrap())
+        .collect();
+
+    for (index, row) in rows.iter().enumerate() {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell}{}", " ".repeat(width - visible(cell))))
+            .collect();
+        let line = cells.join("  ");
+        if index == 0 {
+            println!("    {BOLD}{}{RESET}", line.trim_end());
+        } else {
+            println!("    {}", line.trim_end());
+        }
+    }
+
+    duration
+}
+
+/// Checks each selected day that has a reference implementation against generated inputs
+/// and every real input available, reporting the first minimized divergence.
+fn differential(solutions: &[Solution]) {
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+
+    let hook = take_hook();
+    set_hook(Box::new(|_| ()));
+
+    for (year, day, differential) in differentials() {
+        let Some(solution) = solutions.iter().find(|s| s.year == year && s.day == day) else {
+            continue;
+        };
+        let real: Vec<_> = inputs(&solution.path)
+            .iter()
+            .filter_map(|path| read_to_string(path).ok())
+            .collect();
+
+        println!("{BOLD}{YELLOW}{year} Day {day:02}{RESET}");
+
+        match differential().check(seed, 1000, &real) {
+            Ok(checked) => println!("    {GREEN}Matched{RESET} {checked} inputs"),
+            Err(divergence) => {
+                println!("    {RED}Diverged{RESET} using seed {seed}");
+                divergence
+                    .to_string()
+                    .lines()
+                    .for_each(|line| println!("    {line}"));
+            }
+        }
+    }
+
+    set_hook(hook);
+}
+
+/// The default input file (if present) followed by every `.txt` file in the directory of the
+/// same name.
+fn inputs(path: &Path) -> Vec<PathBuf> {
+    let mut inputs: Vec<_> = read_dir(path.with_extension(""))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|input| input.extension().is_some_and(|e| e == "txt"))
+        .collect();
+    inputs.sort_unstable();
+    if path.is_file() {
+        inputs.insert(0, path.to_path_buf());
+    }
+    inputs
+}
+
 struct Solution {
     year: u32,
     day: u32,
+    parts: usize,
     path: PathBuf,
-    wrapper: fn(String) -> (String, String),
+    wrapper: fn(String) -> Vec<String>,
 }
 
+/// Days after the optional `;` only have a first part, for example the last day of a calendar.
+#[cfg(feature = "_any_year")]
 macro_rules! run {
-    ($year:tt $($day:tt),*) => {
+    ($year:tt $($day:tt),* $(; $($single:tt),*)?) => {
         fn $year() -> Vec<Solution> {
+            vec![
+                $(solution!($year $day part1, part2),)*
+                $($(solution!($year $single part1),)*)?
+            ]
+        }
+    }
+}
+
+#[cfg(feature = "_any_year")]
+macro_rules! solution {
+    ($year:tt $day:tt $($part:ident),+) => {{
+        let year = stringify!($year);
+        let day = stringify!($day);
+        let path = Path::new("input").join(year).join(day).with_extension("txt");
+
+        let wrapper = |data: String| {
+            use $year::$day::*;
+
+            let input = {
+                let _span = timer::span("parse");
+                parse(&data)
+            };
+
             vec![$({
-                let year = stringify!($year);
-                let day = stringify!($day);
-                let path = Path::new("input").join(year).join(day).with_extension("txt");
+                let _span = timer::span(stringify!($part));
+                $part(&input).to_string()
+            }),+]
+        };
+
+        let parts = [$(stringify!($part)),+].len();
+        Solution { year: year.unsigned(), day: day.unsigned(), parts, path, wrapper }
+    }};
+}
+
+#[cfg(feature = "year2015")]
+run!(year2015
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
 
-                let wrapper = |data: String| {
-                    use $year::$day::*;
+#[cfg(feature = "year2016")]
+run!(year2016
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2017")]
+run!(year2017
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2018")]
+run!(year2018
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
 
-                    let input = parse(&data);
-                    let part1 = part1(&input);
-                    let part2 = part2(&input);
+#[cfg(feature = "year2019")]
+run!(year2019
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
 
-                    (part1.to_string(), part2.to_string())
-                };
+#[cfg(feature = "year2020")]
+run!(year2020
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
 
-                Solution { year: year.unsigned(), day: day.unsigned(), path, wrapper }
+#[cfg(feature = "year2021")]
+run!(year2021
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2022")]
+run!(year2022
+    day01, day02, day03, day04, day05, day06, day07, day08
+);
+
+#[cfg(feature = "year2023")]
+run!(year2023
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2024")]
+run!(year2024
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24;
+    day25
+);
+
+#[cfg(feature = "year2025")]
+run!(year2025
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11;
+    day12
+);
+
+macro_rules! differential {
+    ($($(#[$meta:meta])* $year:ident::$day:ident),* $(,)?) => {
+        fn differentials() -> Vec<(u32, u32, fn() -> Differential)> {
+            vec![$($(#[$meta])* {
+                let year = stringify!($year).unsigned();
+                let day = stringify!($day).unsigned();
+                let differential: fn() -> Differential = $year::$day::reference::differential;
+                (year, day, differential)
             },)*]
         }
     }
 }
 
-run!(year2023
-    day01
+differential!(
+    #[cfg(feature = "year2023")]
+    year2023::day01,
 );
@@ -0,0 +1,183 @@
+//! [Assembunny](https://adventofcode.com/2016/day/12) interpreter shared by three days of 2016.
+//!
+//! Programs spend almost all their time in two tight loops, an addition
+//! (`inc a, dec b, jnz b -2`) and a multiplication built from an addition nested inside
+//! another loop. Both patterns are recognized as they are about to execute and replaced by a
+//! single arithmetic step. Matching happens at run time rather than ahead of time, as `tgl`
+//! can rewrite any instruction while the program runs.
+//!
+//! Like the [Intcode computer], execution pauses on each `out` instruction so the caller can
+//! inspect the signal and decide whether to continue.
+//!
+//! [Intcode computer]: crate::util::intcode
+use crate::util::parse::*;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(usize),
+    Value(i64),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Cpy(Operand, Operand),
+    Inc(Operand),
+    Dec(Operand),
+    Jnz(Operand, Operand),
+    Tgl(Operand),
+    Out(Operand),
+}
+
+#[derive(Clone)]
+pub struct Computer {
+    pub registers: [i64; 4],
+    pc: usize,
+    program: Vec<Instruction>,
+}
+
+impl Computer {
+    pub fn parse(input: &str) -> Computer {
+        let operand = |token: &str| match token.as_bytes()[0] {
+            b @ b'a'..=b'd' => Operand::Register((b - b'a') as usize),
+            _ => Operand::Value(token.signed()),
+        };
+
+        let program = input
+            .lines()
+            .map(|line| {
+                let tokens: Vec<_> = line.split_ascii_whitespace().collect();
+                match tokens[..] {
+                    ["cpy", x, y] => Instruction::Cpy(operand(x), operand(y)),
+                    ["inc", x] => Instruction::Inc(operand(x)),
+                    ["dec", x] => Instruction::Dec(operand(x)),
+                    ["jnz", x, y] => Instruction::Jnz(operand(x), operand(y)),
+                    ["tgl", x] => Instruction::Tgl(operand(x)),
+                    ["out", x] => Instruction::Out(operand(x)),
+                    _ => panic!("Unknown instruction {line}"),
+                }
+            })
+            .collect();
+
+        Computer { registers: [0; 4], pc: 0, program }
+    }
+
+    /// Runs until the next `out` instruction, returning its value, or `None` once the program
+    /// counter leaves the program.
+    pub fn run(&mut self) -> Option<i64> {
+        use Instruction::*;
+        use Operand::*;
+
+        while self.pc < self.program.len() {
+            if self.multiply() || self.add() {
+                continue;
+            }
+
+            match self.program[self.pc] {
+                Cpy(x, Register(y)) => self.registers[y] = self.value(x),
+                Inc(Register(x)) => self.registers[x] += 1,
+                Dec(Register(x)) => self.registers[x] -= 1,
+                Jnz(x, y) if self.value(x) != 0 => {
+                    let target = self.pc as i64 + self.value(y);
+                    // Jumping before the start ends the program just like jumping past the end.
+                    self.pc = target.try_into().unwrap_or(usize::MAX);
+                    continue;
+                }
+                Tgl(x) => {
+                    let target = self.pc as i64 + self.value(x);
+                    if let Some(instruction) =
+                        usize::try_from(target).ok().and_then(|t| self.program.get_mut(t))
+                    {
+                        *instruction = toggle(*instruction);
+                    }
+                }
+                Out(x) => {
+                    self.pc += 1;
+                    return Some(self.value(x));
+                }
+                // Toggling can produce invalid instructions, such as copying into a value,
+                // which are skipped.
+                _ => (),
+            }
+
+            self.pc += 1;
+        }
+
+        None
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(r) => self.registers[r],
+            Operand::Value(v) => v,
+        }
+    }
+
+    /// `inc x, dec y, jnz y -2` (in either order) adds `y` to `x` and clears `y`.
+    fn add(&mut self) -> bool {
+        use Instruction::*;
+        use Operand::*;
+
+        let Some(&[first, second, Jnz(Register(y), Value(-2))]) =
+            self.program.get(self.pc..self.pc + 3)
+        else {
+            return false;
+        };
+        let (x, d) = match (first, second) {
+            (Inc(Register(x)), Dec(Register(d))) | (Dec(Register(d)), Inc(Register(x))) => (x, d),
+            _ => return false,
+        };
+        if d != y || x == y {
+            return false;
+        }
+
+        self.registers[x] += self.registers[y];
+        self.registers[y] = 0;
+        self.pc += 3;
+        true
+    }
+
+    /// `cpy b c, <add c to a>, dec d, jnz d -5` adds `b * d` to `a` and clears `c` and `d`.
+    fn multiply(&mut self) -> bool {
+        use Instruction::*;
+        use Operand::*;
+
+        let Some(&[Cpy(b, Register(c)), first, second, inner, Dec(Register(d)), outer]) =
+            self.program.get(self.pc..self.pc + 6)
+        else {
+            return false;
+        };
+        let (a, x) = match (first, second) {
+            (Inc(Register(a)), Dec(Register(x))) | (Dec(Register(x)), Inc(Register(a))) => (a, x),
+            _ => return false,
+        };
+        if inner != Jnz(Register(c), Value(-2))
+            || outer != Jnz(Register(d), Value(-5))
+            || x != c
+            || a == c
+            || a == d
+            || c == d
+            || b == Register(c)
+            || b == Register(d)
+            || b == Register(a)
+        {
+            return false;
+        }
+
+        self.registers[a] += self.value(b) * self.registers[d];
+        self.registers[c] = 0;
+        self.registers[d] = 0;
+        self.pc += 6;
+        true
+    }
+}
+
+fn toggle(instruction: Instruction) -> Instruction {
+    use Instruction::*;
+
+    match instruction {
+        Inc(x) => Dec(x),
+        Dec(x) | Tgl(x) | Out(x) => Inc(x),
+        Jnz(x, y) => Cpy(x, y),
+        Cpy(x, y) => Jnz(x, y),
+    }
+}
@@ -0,0 +1,98 @@
+//! Skips ahead in simulations that eventually repeat, for puzzles that ask for the state after
+//! far too many steps to simulate one at a time.
+//!
+//! Starting from an initial state, [`detect`] applies a step function and stores every state
+//! in a [`FastMap`] along with the step it was first seen. The first repeat gives the start
+//! and length of the loop, after which the state at any step is one of the states already seen:
+//!
+//! ```
+//!   # use aoc::util::cycle::Cycle;
+//!
+//!   // 3, 6, 12, 24, 13, 26, 17, 34, 33, 31, 27, 19, 3, ...
+//!   let cycle = Cycle::detect(3, |&n| 2 * n % 35);
+//!   assert_eq!((cycle.start, cycle.length), (0, 12));
+//!   assert_eq!(*cycle.nth(1_000_000_000), 13);
+//!
+//!   // 10, 5, 16, 8, 4, 2, 1, 4, 2, 1, ...
+//!   let cycle = Cycle::detect(10, |&n| if n % 2 == 0 { n / 2 } else { 3 * n + 1 });
+//!   assert_eq!((cycle.start, cycle.length), (4, 3));
+//!   assert_eq!(*cycle.nth(2), 16);
+//!   assert_eq!(*cycle.nth(1_000_000_000), 4);
+//! ```
+//!
+//! Sometimes only part of the state repeats while the rest drifts by the same amount each
+//! loop, for example a pattern that moves a little further every time. [`detect_by`] compares
+//! states using a key derived from each one, then [`position`] and [`repeats`] give enough
+//! information to extrapolate the drifting part.
+//!
+//! [`detect`]: Cycle::detect
+//! [`detect_by`]: Cycle::detect_by
+//! [`position`]: Cycle::position
+//! [`repeats`]: Cycle::repeats
+use crate::util::hash::*;
+use std::hash::Hash;
+
+pub struct Cycle<S> {
+    /// Step of the first state that is part of the loop.
+    pub start: usize,
+    /// Number of steps before the loop repeats.
+    pub length: usize,
+    /// Every state from step zero up to and including the first repeat at `start + length`.
+    pub history: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash> Cycle<S> {
+    pub fn detect(initial: S, step: impl FnMut(&S) -> S) -> Self {
+        Cycle::detect_by(initial, step, S::clone)
+    }
+}
+
+impl<S> Cycle<S> {
+    /// Finds a loop where states are considered equal when their keys are equal.
+    pub fn detect_by<K: Eq + Hash>(
+        initial: S,
+        mut step: impl FnMut(&S) -> S,
+        mut key: impl FnMut(&S) -> K,
+    ) -> Self {
+        let mut seen = FastMap::new();
+        let mut history = vec![initial];
+
+        loop {
+            let index = history.len() - 1;
+            if let Some(start) = seen.insert(key(&history[index]), index) {
+                return Cycle { start, length: index - start, history };
+            }
+
+            let next = step(&history[index]);
+            history.push(next);
+        }
+    }
+
+    /// Index into `history` of a state equivalent to step `n`.
+    #[inline]
+    pub fn position(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    /// Number of complete loops skipped when going from step `n` back to its [`position`].
+    ///
+    /// [`position`]: Cycle::position
+    #[inline]
+    pub fn repeats(&self, n: usize) -> usize {
+        if n < self.start {
+            0
+        } else {
+            (n - self.start) / self.length
+        }
+    }
+
+    /// State at step `n`, without simulating every step.
+    #[inline]
+    pub fn nth(&self, n: usize) -> &S {
+        &self.history[self.position(n)]
+    }
+}
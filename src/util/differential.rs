@@ -0,0 +1,166 @@
+//! Differential testing of an optimized solution against a slow but obviously correct reference.
+//!
+//! A day opts in by providing a `reference` module containing a straightforward implementation
+//! of both parts together with an input generator, then exposing both through a
+//! [`Differential`]. The [`check`] method runs both implementations on many generated inputs
+//! (plus any real inputs supplied) and reports the first case where they disagree.
+//!
+//! Generated inputs that trigger a divergence are usually large and noisy, so the failing input
+//! is [`minimize`]d by repeatedly deleting lines then individual characters for as long as the
+//! two implementations still disagree, in the spirit of
+//! [delta debugging](https://en.wikipedia.org/wiki/Delta_debugging).
+//!
+//! A panic is treated as an answer in its own right. If only one side panics that's a
+//! divergence, however if both panic then the input is considered invalid and skipped.
+//!
+//! [`check`]: Differential::check
+use std::fmt;
+use std::panic::catch_unwind;
+
+/// Solves both parts of a puzzle returning the answers as strings.
+pub type Solver = fn(&str) -> (String, String);
+
+/// Creates a random puzzle input.
+pub type Generator = fn(&mut Rng) -> String;
+
+pub struct Differential {
+    pub optimized: Solver,
+    pub reference: Solver,
+    pub generate: Generator,
+}
+
+pub struct Divergence {
+    pub input: String,
+    pub optimized: Option<(String, String)>,
+    pub reference: Option<(String, String)>,
+}
+
+impl Differential {
+    /// Compares both implementations on `cases` generated inputs followed by every input in
+    /// `real`, returning the total number of inputs checked or the first divergence found.
+    pub fn check(&self, seed: u64, cases: usize, real: &[String]) -> Result<usize, Divergence> {
+        let mut rng = Rng::new(seed);
+        let generated = (0..cases).map(|_| (self.generate)(&mut rng));
+
+        let mut checked = 0;
+
+        for input in generated.chain(real.iter().cloned()) {
+            if self.diverges(&input) {
+                let input = minimize(&input, |candidate| self.diverges(candidate));
+                let optimized = run(self.optimized, &input);
+                let reference = run(self.reference, &input);
+                return Err(Divergence { input, optimized, reference });
+            }
+            checked += 1;
+        }
+
+        Ok(checked)
+    }
+
+    fn diverges(&self, input: &str) -> bool {
+        match (run(self.optimized, input), run(self.reference, input)) {
+            (Some(optimized), Some(reference)) => optimized != reference,
+            (None, None) => false,
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let answers = |answers: &Option<(String, String)>| match answers {
+            Some((part1, part2)) => format!("{part1} / {part2}"),
+            None => "panic!".to_string(),
+        };
+
+        writeln!(f, "Optimized: {}", answers(&self.optimized))?;
+        writeln!(f, "Reference: {}", answers(&self.reference))?;
+        writeln!(f, "Input:")?;
+        write!(f, "{}", self.input)
+    }
+}
+
+/// Shrinks `input` while `fails` still holds, first removing whole lines then single characters.
+pub fn minimize(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut lines: Vec<String> = input.lines().map(String::from).collect();
+    let join = |lines: &[String]| lines.iter().map(|line| format!("{line}\n")).collect::<String>();
+
+    // Remove chunks of lines, halving the chunk size each time no chunk can be removed.
+    let mut size = lines.len().div_ceil(2);
+    while size > 0 {
+        let mut start = 0;
+        let mut removed = false;
+
+        while start < lines.len() {
+            let end = (start + size).min(lines.len());
+            let candidate: Vec<_> = [&lines[..start], &lines[end..]].concat();
+
+            if !candidate.is_empty() && fails(&join(&candidate)) {
+                lines = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+
+        if !removed {
+            size /= 2;
+        }
+    }
+
+    // Then remove individual characters within each remaining line.
+    for index in 0..lines.len() {
+        let mut position = 0;
+
+        while position < lines[index].len() {
+            let mut candidate = lines.clone();
+            candidate[index].remove(position);
+
+            if fails(&join(&candidate)) {
+                lines = candidate;
+            } else {
+                position += 1;
+            }
+        }
+    }
+
+    join(&lines)
+}
+
+fn run(solver: Solver, input: &str) -> Option<(String, String)> {
+    catch_unwind(|| solver(input)).ok()
+}
+
+/// Small deterministic [SplitMix64](https://prng.di.unimi.it/splitmix64.c) pseudo random number
+/// generator, so that failing cases can be reproduced from their seed.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Random number from `0` inclusive to `n` exclusive.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// Random number from `lower` to `upper` inclusive.
+    pub fn between(&mut self, lower: usize, upper: usize) -> usize {
+        lower + self.below(upper - lower + 1)
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
@@ -0,0 +1,139 @@
+//! [Duet](https://adventofcode.com/2017/day/18) assembly interpreter shared by two days of 2017.
+//!
+//! The [coprocessor](https://adventofcode.com/2017/day/23) runs a variant of the same
+//! language, swapping `add`, `mod`, `snd`, `rcv` and `jgz` for `sub` and `jnz`. Both variants
+//! are parsed into the same instruction set so that either program runs on one machine.
+//!
+//! Like the [Intcode computer], execution is resumable. [`Computer::run`] pauses on every
+//! `snd` and whenever `rcv` finds no queued value, so the caller decides what sending and
+//! receiving mean. Day 18 gives these instructions two different meanings, sounds in the first
+//! part and messages between a pair of programs in the second.
+//!
+//! [Intcode computer]: crate::util::intcode
+use crate::util::parse::*;
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(usize),
+    Value(i64),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Snd(Operand),
+    Set(usize, Operand),
+    Add(usize, Operand),
+    Sub(usize, Operand),
+    Mul(usize, Operand),
+    Mod(usize, Operand),
+    Rcv(usize),
+    Jgz(Operand, Operand),
+    Jnz(Operand, Operand),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum State {
+    Send(i64),
+    /// Waiting for a value to store in the register, call [`Computer::input`] then
+    /// [`Computer::run`] to resume.
+    Receive(usize),
+    Halted,
+}
+
+#[derive(Clone)]
+pub struct Computer {
+    pub registers: [i64; 26],
+    /// Number of `mul` instructions executed so far.
+    pub multiplies: usize,
+    pc: i64,
+    program: Vec<Instruction>,
+    input: VecDeque<i64>,
+}
+
+impl Computer {
+    pub fn parse(input: &str) -> Computer {
+        let register = |token: &str| (token.as_bytes()[0] - b'a') as usize;
+        let operand = |token: &str| match token.as_bytes()[0] {
+            b'a'..=b'z' => Operand::Register(register(token)),
+            _ => Operand::Value(token.signed()),
+        };
+
+        let program = input
+            .lines()
+            .map(|line| {
+                let tokens: Vec<_> = line.split_ascii_whitespace().collect();
+                match tokens[..] {
+                    ["snd", x] => Instruction::Snd(operand(x)),
+                    ["set", x, y] => Instruction::Set(register(x), operand(y)),
+                    ["add", x, y] => Instruction::Add(register(x), operand(y)),
+                    ["sub", x, y] => Instruction::Sub(register(x), operand(y)),
+                    ["mul", x, y] => Instruction::Mul(register(x), operand(y)),
+                    ["mod", x, y] => Instruction::Mod(register(x), operand(y)),
+                    ["rcv", x] => Instruction::Rcv(register(x)),
+                    ["jgz", x, y] => Instruction::Jgz(operand(x), operand(y)),
+                    ["jnz", x, y] => Instruction::Jnz(operand(x), operand(y)),
+                    _ => panic!("Unknown instruction {line}"),
+                }
+            })
+            .collect();
+
+        Computer { registers: [0; 26], multiplies: 0, pc: 0, program, input: VecDeque::new() }
+    }
+
+    pub fn input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    /// Runs until the next `snd`, the program counter leaves the program, or a `rcv` finds the
+    /// queue empty. In the last case the program counter is left on the `rcv` instruction so
+    /// that it is retried on the next call.
+    pub fn run(&mut self) -> State {
+        use Instruction::*;
+
+        while let Some(&instruction) =
+            usize::try_from(self.pc).ok().and_then(|pc| self.program.get(pc))
+        {
+            match instruction {
+                Snd(x) => {
+                    self.pc += 1;
+                    return State::Send(self.value(x));
+                }
+                Set(x, y) => self.registers[x] = self.value(y),
+                Add(x, y) => self.registers[x] += self.value(y),
+                Sub(x, y) => self.registers[x] -= self.value(y),
+                Mul(x, y) => {
+                    self.registers[x] *= self.value(y);
+                    self.multiplies += 1;
+                }
+                Mod(x, y) => self.registers[x] = self.registers[x].rem_euclid(self.value(y)),
+                Rcv(x) => {
+                    let Some(value) = self.input.pop_front() else {
+                        return State::Receive(x);
+                    };
+                    self.registers[x] = value;
+                }
+                Jgz(x, y) if self.value(x) > 0 => {
+                    self.pc += self.value(y);
+                    continue;
+                }
+                Jnz(x, y) if self.value(x) != 0 => {
+                    self.pc += self.value(y);
+                    continue;
+                }
+                Jgz(..) | Jnz(..) => (),
+            }
+
+            self.pc += 1;
+        }
+
+        State::Halted
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(r) => self.registers[r],
+            Operand::Value(v) => v,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! [Elfcode](https://adventofcode.com/2018/day/16) device used by four days of 2018.
+//!
+//! Day 16 works out which number corresponds to each of the sixteen [`Opcode`]s by observing
+//! their effect on registers, so opcodes can be applied individually with [`Opcode::execute`].
+//! Later days bind the instruction pointer to a register and run whole programs with
+//! [`Program::run`].
+//!
+//! The programs of days 19 and 21 hide slow loops, so their solutions decompile the algorithm
+//! and read constants out of the parsed [`Program`] rather than running it to completion.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 16] = [
+        Opcode::Addr,
+        Opcode::Addi,
+        Opcode::Mulr,
+        Opcode::Muli,
+        Opcode::Banr,
+        Opcode::Bani,
+        Opcode::Borr,
+        Opcode::Bori,
+        Opcode::Setr,
+        Opcode::Seti,
+        Opcode::Gtir,
+        Opcode::Gtri,
+        Opcode::Gtrr,
+        Opcode::Eqir,
+        Opcode::Eqri,
+        Opcode::Eqrr,
+    ];
+
+    /// Applies the opcode to any number of registers, storing the result in register `c`.
+    pub fn execute(self, a: usize, b: usize, c: usize, registers: &mut [usize]) {
+        use Opcode::*;
+
+        let r = &*registers;
+        registers[c] = match self {
+            Addr => r[a] + r[b],
+            Addi => r[a] + b,
+            Mulr => r[a] * r[b],
+            Muli => r[a] * b,
+            Banr => r[a] & r[b],
+            Bani => r[a] & b,
+            Borr => r[a] | r[b],
+            Bori => r[a] | b,
+            Setr => r[a],
+            Seti => a,
+            Gtir => (a > r[b]) as usize,
+            Gtri => (r[a] > b) as usize,
+            Gtrr => (r[a] > r[b]) as usize,
+            Eqir => (a == r[b]) as usize,
+            Eqri => (r[a] == b) as usize,
+            Eqrr => (r[a] == r[b]) as usize,
+        };
+    }
+}
+
+impl From<&str> for Opcode {
+    fn from(name: &str) -> Self {
+        match name {
+            "addr" => Opcode::Addr,
+            "addi" => Opcode::Addi,
+            "mulr" => Opcode::Mulr,
+            "muli" => Opcode::Muli,
+            "banr" => Opcode::Banr,
+            "bani" => Opcode::Bani,
+            "borr" => Opcode::Borr,
+            "bori" => Opcode::Bori,
+            "setr" => Opcode::Setr,
+            "seti" => Opcode::Seti,
+            "gtir" => Opcode::Gtir,
+            "gtri" => Opcode::Gtri,
+            "gtrr" => Opcode::Gtrr,
+            "eqir" => Opcode::Eqir,
+            "eqri" => Opcode::Eqri,
+            "eqrr" => Opcode::Eqrr,
+            _ => panic!("Unknown opcode {name}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+}
+
+pub struct Program {
+    /// Register bound to the instruction pointer.
+    pub ip: usize,
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn parse(input: &str) -> Program {
+        let mut lines = input.lines();
+        let ip = lines.next().unwrap().unsigned();
+        let instructions = lines
+            .map(|line| {
+                let opcode = Opcode::from(&line[..4]);
+                let [a, b, c] = line.iter_unsigned().chunk::<3>().next().unwrap();
+                Instruction { opcode, a, b, c }
+            })
+            .collect();
+        Program { ip, instructions }
+    }
+
+    /// Runs until the instruction pointer leaves the program, or `stop` returns `true` when
+    /// called with the instruction pointer and registers before each instruction.
+    pub fn run(
+        &self,
+        registers: &mut [usize; 6],
+        mut stop: impl FnMut(usize, &[usize; 6]) -> bool,
+    ) {
+        let mut ip = 0;
+
+        while ip < self.instructions.len() && !stop(ip, registers) {
+            let Instruction { opcode, a, b, c } = self.instructions[ip];
+            registers[self.ip] = ip;
+            opcode.execute(a, b, c, registers);
+            ip = registers[self.ip] + 1;
+        }
+    }
+}
@@ -21,12 +21,194 @@
 //! creates a grid of the same size, that can be used for in BFS algorithms for tracking visited
 //! location or for tracking cost in Djikstra.
 //!
+//! Other cell types are parsed with [`parse_with`], mapping each byte through a closure, for
+//! example turning digits into their values or tiles into an `enum`. Any grid can be shown by
+//! passing a function that renders each cell as a `char` to [`render`], then using the result
+//! with `format!` or `println!`. [`write`] sends the same output to any [`Write`] instead. Grids
+//! of bytes implement [`Display`] directly:
+//!
+//! ```
+//!   # use aoc::util::grid::Grid;
+//!
+//!   let grid = Grid::parse_with("12\n34", |b| b - b'0');
+//!   assert_eq!(grid.bytes, [1, 2, 3, 4]);
+//!
+//!   let odd = grid.render(|&n| if n % 2 == 1 { '#' } else { '.' });
+//!   assert_eq!(odd.to_string(), "#.\n#.\n");
+//!
+//!   let mut out = Vec::new();
+//!   grid.write(&mut out, |&n| (b'0' + n) as char).unwrap();
+//!   assert_eq!(out, b"12\n34\n");
+//!   assert_eq!(Grid::parse("ab\ncd").to_string(), "ab\ncd\n");
+//!
+//!   let empty: Grid<u8> = Grid { width: 0, height: 2, bytes: Vec::new() };
+//!   assert_eq!(empty.to_string(), "\n\n");
+//!   assert_eq!(format!("{empty:?}"), "Grid 0x2\n[]\n[]\n");
+//! ```
+//!
+//! Rows are contiguous so [`row`] returns a slice, while [`column`] steps through the cells of
+//! one column. [`iter`] pairs every cell with its point in reading order. A rectangular [`view`]
+//! borrows part of a grid with its own coordinates starting from the origin, and can be copied
+//! out with [`to_grid`]. Going the other way, [`paste`] copies a whole grid into another:
+//!
+//! ```
+//!   # use aoc::util::grid::Grid;
+//!   # use aoc::util::point::Point;
+//!
+//!   let mut grid = Grid::parse("abc\ndef\nghi");
+//!   assert_eq!(grid.row(1), b"def");
+//!   assert!(grid.column(2).eq(b"cfi"));
+//!
+//!   let view = grid.view(Point::new(1, 1), 2, 2);
+//!   assert_eq!(view[Point::new(0, 1)], b'h');
+//!   assert_eq!(view.to_grid(), Grid::parse("ef\nhi"));
+//!
+//!   let empty = grid.view(Point::new(3, 0), 0, 3);
+//!   assert_eq!(empty.iter().count(), 0);
+//!   assert_eq!(grid.view(Point::new(0, 3), 3, 0).to_grid().bytes, []);
+//!
+//!   let narrow = empty.to_grid();
+//!   assert_eq!(narrow.rows().count(), 3);
+//!   assert_eq!(narrow.to_string(), "\n\n\n");
+//!   grid.paste(Point::new(3, 0), &narrow);
+//!
+//!   grid.paste(Point::new(0, 1), &Grid::parse("xy"));
+//!   assert_eq!(grid, Grid::parse("abc\nxyf\nghi"));
+//!   assert_eq!(grid.iter().find(|&(_, &b)| b == b'y'), Some((Point::new(1, 1), &b'y')));
+//! ```
+//!
+//! Indexing with a point outside the grid is a bug that would otherwise read the wrong cell, so
+//! it always panics, even in release builds:
+//!
+//! ```should_panic
+//!   # use aoc::util::grid::Grid;
+//!   # use aoc::util::point::Point;
+//!
+//!   let grid = Grid::parse("ab\ncd");
+//!   let _ = grid[Point::new(2, 0)];
+//! ```
+//!
+//! Use [`get`] when a point may be out of range. Puzzles where the map repeats forever in every
+//! direction can [`wrap`] points back into range, or index through a [`wrapped`] view that does
+//! so automatically:
+//!
+//! ```
+//!   # use aoc::util::grid::Grid;
+//!   # use aoc::util::point::Point;
+//!
+//!   let grid = Grid::parse("ab\ncd");
+//!   assert_eq!(grid.get(Point::new(1, 1)), Some(&b'd'));
+//!   assert_eq!(grid.get(Point::new(2, 0)), None);
+//!
+//!   assert_eq!(grid.wrap(Point::new(-1, 5)), Point::new(1, 1));
+//!   assert_eq!(grid.wrapped()[Point::new(4, -3)], b'c');
+//! ```
+//!
+//! Geometric transforms return a new grid, rotating clockwise or counter clockwise, transposing
+//! or flipping. Square grids can also be transformed in place without allocating. Puzzles that
+//! match tiles in any rotation or reflection can loop over all eight [`orientations`]:
+//!
+//! ```
+//!   # use aoc::util::grid::Grid;
+//!
+//!   let grid = Grid::parse("ab\ncd");
+//!   assert_eq!(grid.rotate_clockwise(), Grid::parse("ca\ndb"));
+//!   assert_eq!(grid.transpose(), Grid::parse("ac\nbd"));
+//!   assert_eq!(grid.flip_horizontal(), Grid::parse("ba\ndc"));
+//!   assert_eq!(grid.orientations().count(), 8);
+//!
+//!   let wide = Grid::parse("abc\ndef");
+//!   assert_eq!(wide.rotate_clockwise(), Grid::parse("da\neb\nfc"));
+//!   assert_eq!(wide.rotate_counter_clockwise(), Grid::parse("cf\nbe\nad"));
+//!
+//!   let mut square = Grid::parse("abc\ndef\nghi");
+//!   let expected = square.rotate_counter_clockwise();
+//!   square.rotate_counter_clockwise_in_place();
+//!   assert_eq!(square, expected);
+//! ```
+//!
+//! Searches share the same building blocks. [`orthogonal`] and [`diagonal`] iterate over the
+//! neighbors of a point that are inside the grid. Movement rules are closures given the point
+//! moved from and the point moved to, so that rules can depend on both cells, for example a
+//! maximum change in height. [`bfs`] and [`bfs_many`] return the distance to every cell, with
+//! cells that can't be reached left as [`UNREACHABLE`]. [`flood_fill`] collects a single
+//! region and [`components`] labels every region at once:
+//!
+//! ```
+//!   # use aoc::util::grid::*;
+//!   # use aoc::util::point::Point;
+//!
+//!   let grid = Grid::parse("..#\n#..\n..#");
+//!   let open = |_, to: Point| grid[to] == b'.';
+//!
+//!   let distance = grid.bfs(Point::new(0, 0), open);
+//!   assert_eq!(distance[Point::new(1, 2)], 3);
+//!   assert_eq!(distance[Point::new(2, 0)], UNREACHABLE);
+//!
+//!   let (labels, count) = grid.components(|from, to| grid[from] == grid[to]);
+//!   assert_eq!(count, 4);
+//!   assert_eq!(labels[Point::new(2, 0)], 1);
+//!   assert_eq!(grid.flood_fill(Point::new(0, 0), open).len(), 6);
+//! ```
+//!
+//! [`Grid3`] is the 3 dimensional equivalent indexed by [`Point3`], for voxel puzzles. Cells
+//! are solid or empty according to a closure. [`surface_area`] counts every face of a solid
+//! cell that doesn't touch another solid cell. Air pockets trapped inside are excluded by
+//! [`exterior_area`], which flood fills empty cells inward from outside the grid:
+//!
+//! ```
+//!   # use aoc::util::grid::Grid3;
+//!   # use aoc::util::point::Point3;
+//!
+//!   let cubes = [
+//!       [2, 2, 2], [1, 2, 2], [3, 2, 2], [2, 1, 2], [2, 3, 2], [2, 2, 1], [2, 2, 3],
+//!       [2, 2, 4], [2, 2, 6], [1, 2, 5], [3, 2, 5], [2, 1, 5], [2, 3, 5],
+//!   ];
+//!   let mut lava = Grid3::new(4, 4, 7, false);
+//!   cubes.into_iter().for_each(|cube| lava[Point3::from(cube)] = true);
+//!
+//!   assert!(lava.contains(Point3::new(3, 3, 6)));
+//!   assert!(!lava.contains(Point3::new(0, 0, 7)));
+//!   assert_eq!(lava.surface_area(|&solid| solid), 64);
+//!   assert_eq!(lava.exterior_area(|&solid| solid), 58);
+//! ```
+//!
 //! [`Point`]: crate::util::point
+//! [`Point3`]: crate::util::point::Point3
+//! [`surface_area`]: Grid3::surface_area
+//! [`exterior_area`]: Grid3::exterior_area
 //! [`parse`]: Grid::parse
 //! [`same_size_with`]: Grid::same_size_with
+//! [`parse_with`]: Grid::parse_with
+//! [`render`]: Grid::render
+//! [`write`]: Grid::write
+//! [`Write`]: std::io::Write
+//! [`Display`]: std::fmt::Display
+//! [`row`]: Grid::row
+//! [`column`]: Grid::column
+//! [`iter`]: Grid::iter
+//! [`view`]: Grid::view
+//! [`to_grid`]: View::to_grid
+//! [`paste`]: Grid::paste
+//! [`get`]: Grid::get
+//! [`wrap`]: Grid::wrap
+//! [`wrapped`]: Grid::wrapped
+//! [`orientations`]: Grid::orientations
+//! [`orthogonal`]: Grid::orthogonal
+//! [`diagonal`]: Grid::diagonal
+//! [`bfs`]: Grid::bfs
+//! [`bfs_many`]: Grid::bfs_many
+//! [`flood_fill`]: Grid::flood_fill
+//! [`components`]: Grid::components
 use crate::util::point::*;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::io::{self, Write};
 use std::ops::{Index, IndexMut};
 
+/// Distance to cells that a breadth first search never reaches.
+pub const UNREACHABLE: u32 = u32::MAX;
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     pub width: i32,
@@ -46,14 +228,7 @@ impl Grid<u8> {
     }
 
     pub fn print(&self) {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let point = Point::new(x, y);
-                print!("{}", self[point] as char);
-            }
-            println!();
-        }
-        println!();
+        println!("{self}");
     }
 }
 
@@ -73,9 +248,93 @@ impl<T: Copy> Grid<T> {
     pub fn new(width: i32, height: i32, value: T) -> Grid<T> {
         Grid { width, height, bytes: vec![value; (width * height) as usize] }
     }
+
+    /// Copies all of `other` into this grid with its top left corner at `origin`.
+    pub fn paste(&mut self, origin: Point, other: &Grid<T>) {
+        assert!(self.fits(origin, other.width, other.height), "Pasted grid must fit");
+
+        let start = origin.x as usize;
+        for (y, row) in other.rows().enumerate() {
+            self.row_mut(origin.y + y as i32)[start..start + row.len()].copy_from_slice(row);
+        }
+    }
+
+    /// Swaps rows and columns, reflecting along the main diagonal.
+    #[must_use]
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |p| Point::new(p.y, p.x))
+    }
+
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |p| Point::new(p.y, self.height - 1 - p.x))
+    }
+
+    #[must_use]
+    pub fn rotate_180(&self) -> Grid<T> {
+        self.remap(self.width, self.height, |p| {
+            Point::new(self.width - 1 - p.x, self.height - 1 - p.y)
+        })
+    }
+
+    #[must_use]
+    pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |p| Point::new(self.width - 1 - p.y, p.x))
+    }
+
+    /// Mirrors left to right, reversing each row.
+    #[must_use]
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        self.remap(self.width, self.height, |p| Point::new(self.width - 1 - p.x, p.y))
+    }
+
+    /// Mirrors top to bottom, reversing the order of the rows.
+    #[must_use]
+    pub fn flip_vertical(&self) -> Grid<T> {
+        self.remap(self.width, self.height, |p| Point::new(p.x, self.height - 1 - p.y))
+    }
+
+    /// All 8 rotations and reflections, starting with the grid itself then each clockwise
+    /// rotation, followed by the same for the horizontally flipped grid.
+    pub fn orientations(&self) -> impl Iterator<Item = Grid<T>> {
+        [self.clone(), self.flip_horizontal()].into_iter().flat_map(|grid| {
+            std::iter::successors(Some(grid), |grid| Some(grid.rotate_clockwise())).take(4)
+        })
+    }
+
+    /// Builds a grid of the given size, reading each cell from the point returned by `source`.
+    fn remap(&self, width: i32, height: i32, source: impl Fn(Point) -> Point) -> Grid<T> {
+        let bytes = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(|p| self[source(p)])
+            .collect();
+        Grid { width, height, bytes }
+    }
 }
 
 impl<T> Grid<T> {
+    /// Creates a grid from 2 dimensional ASCII input, converting each byte with `convert`.
+    #[inline]
+    pub fn parse_with(input: &str, convert: impl FnMut(u8) -> T) -> Self {
+        let raw: Vec<_> = input.lines().map(str::as_bytes).collect();
+        let width = raw[0].len() as i32;
+        let height = raw.len() as i32;
+        let bytes = raw.iter().flat_map(|slice| slice.iter().copied()).map(convert).collect();
+        Grid { width, height, bytes }
+    }
+
+    /// Borrows the grid as something that implements both [`Display`] and [`Debug`], drawing
+    /// each cell as the `char` returned by `cell` and ending every row with a newline.
+    pub fn render<F: Fn(&T) -> char>(&self, cell: F) -> Render<'_, T, F> {
+        Render { grid: self, cell }
+    }
+
+    /// Writes the grid row by row to any [`Write`], drawing each cell as the `char` returned by
+    /// `cell`.
+    pub fn write(&self, out: &mut impl Write, cell: impl Fn(&T) -> char) -> io::Result<()> {
+        write!(out, "{}", self.render(cell))
+    }
+
     #[inline]
     pub fn same_size_with<U: Copy>(&self, value: U) -> Grid<U> {
         Grid {
@@ -89,6 +348,375 @@ impl<T> Grid<T> {
     pub fn contains(&self, point: Point) -> bool {
         point.x >= 0 && point.x < self.width && point.y >= 0 && point.y < self.height
     }
+
+    /// Checked access that returns `None` for points outside the grid.
+    #[inline]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point).then(|| &self[point])
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point).then(|| &mut self[point])
+    }
+
+    /// Maps any point into range as if the grid were tiled infinitely in every direction,
+    /// so that leaving one edge re-enters from the opposite edge.
+    #[inline]
+    pub fn wrap(&self, point: Point) -> Point {
+        Point::new(point.x.rem_euclid(self.width), point.y.rem_euclid(self.height))
+    }
+
+    /// Borrows the grid as an infinite tiling, indexed by any point.
+    #[inline]
+    pub fn wrapped(&self) -> Wrapped<'_, T> {
+        Wrapped { grid: self }
+    }
+
+    #[inline]
+    pub fn row(&self, y: i32) -> &[T] {
+        let start = (self.width * y) as usize;
+        &self.bytes[start..start + self.width as usize]
+    }
+
+    #[inline]
+    pub fn row_mut(&mut self, y: i32) -> &mut [T] {
+        let start = (self.width * y) as usize;
+        &mut self.bytes[start..start + self.width as usize]
+    }
+
+    /// Each row as a slice from top to bottom. A grid with no columns still has `height` rows,
+    /// each of them empty.
+    #[inline]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Cells in column `x` from top to bottom.
+    #[inline]
+    pub fn column(&self, x: i32) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(0 <= x && x < self.width, "Column {x} is outside the grid");
+        self.bytes[x as usize..].iter().step_by(self.width as usize)
+    }
+
+    /// Each column from left to right.
+    #[inline]
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every point in reading order, left to right then top to bottom.
+    #[inline]
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell along with its point, in reading order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.bytes)
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(&mut self.bytes)
+    }
+
+    /// Borrows the `width` by `height` rectangle with its top left corner at `origin`. Views
+    /// with no width or height are allowed and contain no cells.
+    pub fn view(&self, origin: Point, width: i32, height: i32) -> View<'_, T> {
+        assert!(self.fits(origin, width, height), "View must be inside the grid");
+        View { grid: self, origin, width, height }
+    }
+
+    /// Whether a `width` by `height` rectangle with its top left corner at `origin` is inside the
+    /// grid. Empty rectangles may start on the right or bottom edge.
+    #[inline]
+    fn fits(&self, origin: Point, width: i32, height: i32) -> bool {
+        width >= 0
+            && height >= 0
+            && origin.x >= 0
+            && origin.y >= 0
+            && origin.x + width <= self.width
+            && origin.y + height <= self.height
+    }
+
+    /// Neighbors in the four [`ORTHOGONAL`] directions that are inside the grid. The iterator
+    /// doesn't borrow the grid, so cells can be modified while looping.
+    #[inline]
+    pub fn orthogonal(&self, point: Point) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        ORTHOGONAL.into_iter().map(move |o| point + o).filter(move |&p| inside(width, height, p))
+    }
+
+    /// All eight [`DIAGONAL`] neighbors that are inside the grid.
+    #[inline]
+    pub fn diagonal(&self, point: Point) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        DIAGONAL.into_iter().map(move |o| point + o).filter(move |&p| inside(width, height, p))
+    }
+
+    /// Fewest orthogonal steps from `start` to every cell, moving only when
+    /// `can_move(from, to)` returns `true`.
+    pub fn bfs(&self, start: Point, can_move: impl FnMut(Point, Point) -> bool) -> Grid<u32> {
+        self.bfs_many([start], can_move)
+    }
+
+    /// Fewest orthogonal steps from the nearest of any number of starting points to every cell.
+    pub fn bfs_many(
+        &self,
+        starts: impl IntoIterator<Item = Point>,
+        mut can_move: impl FnMut(Point, Point) -> bool,
+    ) -> Grid<u32> {
+        let mut distance = self.same_size_with(UNREACHABLE);
+        let mut todo = VecDeque::new();
+
+        for start in starts {
+            if distance[start] == UNREACHABLE {
+                distance[start] = 0;
+                todo.push_back(start);
+            }
+        }
+
+        while let Some(point) = todo.pop_front() {
+            let steps = distance[point] + 1;
+            for next in self.orthogonal(point) {
+                if distance[next] == UNREACHABLE && can_move(point, next) {
+                    distance[next] = steps;
+                    todo.push_back(next);
+                }
+            }
+        }
+
+        distance
+    }
+
+    /// Every cell reachable from `start`, including `start` itself, moving orthogonally only
+    /// when `can_move(from, to)` returns `true`.
+    pub fn flood_fill(
+        &self,
+        start: Point,
+        mut can_move: impl FnMut(Point, Point) -> bool,
+    ) -> Vec<Point> {
+        let mut seen = self.same_size_with(false);
+        let mut todo = vec![start];
+        let mut region = Vec::new();
+        seen[start] = true;
+
+        while let Some(point) = todo.pop() {
+            region.push(point);
+            for next in self.orthogonal(point) {
+                if !seen[next] && can_move(point, next) {
+                    seen[next] = true;
+                    todo.push(next);
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Labels each region of orthogonally adjacent cells joined when `connected(from, to)`
+    /// returns `true`, returning the labels and the number of regions. Labels count up from zero
+    /// in reading order. Cells that aren't connected to any neighbor form a region on their
+    /// own, so to ignore cells such as walls skip them when using the labels.
+    pub fn components(&self, mut connected: impl FnMut(Point, Point) -> bool) -> (Grid<u32>, u32) {
+        let mut labels = self.same_size_with(UNREACHABLE);
+        let mut count = 0;
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let start = Point::new(x, y);
+                if labels[start] != UNREACHABLE {
+                    continue;
+                }
+
+                labels[start] = count;
+                let mut todo = vec![start];
+
+                while let Some(point) = todo.pop() {
+                    for next in self.orthogonal(point) {
+                        if labels[next] == UNREACHABLE && connected(point, next) {
+                            labels[next] = count;
+                            todo.push(next);
+                        }
+                    }
+                }
+
+                count += 1;
+            }
+        }
+
+        (labels, count)
+    }
+
+    /// Transposes a square grid without allocating.
+    pub fn transpose_in_place(&mut self) {
+        assert_eq!(self.width, self.height, "Grid must be square");
+        let size = self.width as usize;
+
+        for y in 0..size {
+            for x in y + 1..size {
+                self.bytes.swap(y * size + x, x * size + y);
+            }
+        }
+    }
+
+    /// Rotates a square grid clockwise without allocating.
+    pub fn rotate_clockwise_in_place(&mut self) {
+        self.transpose_in_place();
+        self.flip_horizontal_in_place();
+    }
+
+    /// Rotating by 180 degrees is the same as reversing every cell, so works for any grid.
+    pub fn rotate_180_in_place(&mut self) {
+        self.bytes.reverse();
+    }
+
+    /// Rotates a square grid counter clockwise without allocating.
+    pub fn rotate_counter_clockwise_in_place(&mut self) {
+        self.transpose_in_place();
+        self.flip_vertical_in_place();
+    }
+
+    pub fn flip_horizontal_in_place(&mut self) {
+        // A grid with no columns has no cells to move.
+        self.bytes.chunks_exact_mut(self.width.max(1) as usize).for_each(<[T]>::reverse);
+    }
+
+    pub fn flip_vertical_in_place(&mut self) {
+        let width = self.width as usize;
+        let height = self.height as usize;
+
+        for y in 0..height / 2 {
+            let (top, bottom) = self.bytes.split_at_mut((height - 1 - y) * width);
+            top[y * width..(y + 1) * width].swap_with_slice(&mut bottom[..width]);
+        }
+    }
+}
+
+/// Rectangular part of a [`Grid`] created by [`Grid::view`]. Points are relative to the top
+/// left corner of the view.
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<'a, T> View<'a, T> {
+    #[inline]
+    pub fn contains(&self, point: Point) -> bool {
+        inside(self.width, self.height, point)
+    }
+
+    #[inline]
+    pub fn row(&self, y: i32) -> &'a [T] {
+        let start = self.origin.x as usize;
+        &self.grid.row(self.origin.y + y)[start..start + self.width as usize]
+    }
+
+    #[inline]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y))
+    }
+
+    /// Every cell along with its point relative to the view, in reading order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &'a T)> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter().enumerate().map(move |(x, value)| (Point::new(x as i32, y as i32), value))
+        })
+    }
+}
+
+impl<T: Copy> View<'_, T> {
+    /// Copies the view out into a new grid of the same size.
+    pub fn to_grid(&self) -> Grid<T> {
+        let bytes = self.rows().flatten().copied().collect();
+        Grid { width: self.width, height: self.height, bytes }
+    }
+}
+
+impl<T> Index<Point> for View<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point) -> &Self::Output {
+        assert!(self.contains(index), "{index:?} is outside the view");
+        &self.grid[self.origin + index]
+    }
+}
+
+/// Infinite tiling of a [`Grid`] created by [`Grid::wrapped`].
+pub struct Wrapped<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<T> Index<Point> for Wrapped<'_, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point) -> &Self::Output {
+        &self.grid[self.grid.wrap(index)]
+    }
+}
+
+/// Created by [`Grid::render`].
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    cell: F,
+}
+
+impl<T, F: Fn(&T) -> char> Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let Render { grid, cell } = self;
+        for y in 0..grid.height {
+            for value in grid.row(y) {
+                write!(f, "{}", cell(value))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T, F: Fn(&T) -> char> Debug for Render<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.render(|&b| b as char), f)
+    }
+}
+
+/// Shows the size followed by one line per row, with each cell in its own [`Debug`] format.
+impl<T: Debug> Debug for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "Grid {}x{}", self.width, self.height)?;
+        for y in 0..self.height {
+            writeln!(f, "{:?}", self.row(y))?;
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+fn inside(width: i32, height: i32, point: Point) -> bool {
+    point.x >= 0 && point.x < width && point.y >= 0 && point.y < height
 }
 
 impl<T> Index<Point> for Grid<T> {
@@ -96,6 +724,7 @@ impl<T> Index<Point> for Grid<T> {
 
     #[inline]
     fn index(&self, index: Point) -> &Self::Output {
+        assert!(self.contains(index), "{index:?} is outside the grid");
         &self.bytes[(self.width * index.y + index.x) as usize]
     }
 }
@@ -103,6 +732,137 @@ impl<T> Index<Point> for Grid<T> {
 impl<T> IndexMut<Point> for Grid<T> {
     #[inline]
     fn index_mut(&mut self, index: Point) -> &mut Self::Output {
+        assert!(self.contains(index), "{index:?} is outside the grid");
         &mut self.bytes[(self.width * index.y + index.x) as usize]
     }
 }
+
+/// Dense 3 dimensional grid, stored with `x` varying fastest then `y` then `z`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid3<T> {
+    pub width: i32,
+    pub height: i32,
+    pub depth: i32,
+    pub bytes: Vec<T>,
+}
+
+impl<T: Copy> Grid3<T> {
+    pub fn new(width: i32, height: i32, depth: i32, value: T) -> Grid3<T> {
+        Grid3 { width, height, depth, bytes: vec![value; (width * height * depth) as usize] }
+    }
+}
+
+impl<T> Grid3<T> {
+    #[inline]
+    pub fn same_size_with<U: Copy>(&self, value: U) -> Grid3<U> {
+        Grid3::new(self.width, self.height, self.depth, value)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Point3) -> bool {
+        inside3(self.width, self.height, self.depth, point)
+    }
+
+    /// Neighbors in the six [`ORTHOGONAL3`] directions that are inside the grid.
+    #[inline]
+    pub fn orthogonal(&self, point: Point3) -> impl Iterator<Item = Point3> {
+        let (width, height, depth) = (self.width, self.height, self.depth);
+        ORTHOGONAL3
+            .into_iter()
+            .map(move |o| point + o)
+            .filter(move |&p| inside3(width, height, depth, p))
+    }
+
+    /// Empty cells connected to the outside of the grid through other empty cells. Cells on the
+    /// boundary touch the outside, so flood filling starts from every empty boundary cell.
+    pub fn exterior(&self, solid: impl Fn(&T) -> bool) -> Grid3<bool> {
+        let mut outside = self.same_size_with(false);
+        let mut todo = Vec::new();
+
+        for z in 0..self.depth {
+            for y in 0..self.height {
+                for x in 0..self.width {
+                    let point = Point3::new(x, y, z);
+                    let boundary = x == 0
+                        || y == 0
+                        || z == 0
+                        || x == self.width - 1
+                        || y == self.height - 1
+                        || z == self.depth - 1;
+
+                    if boundary && !solid(&self[point]) {
+                        outside[point] = true;
+                        todo.push(point);
+                    }
+                }
+            }
+        }
+
+        while let Some(point) = todo.pop() {
+            for next in self.orthogonal(point) {
+                if !outside[next] && !solid(&self[next]) {
+                    outside[next] = true;
+                    todo.push(next);
+                }
+            }
+        }
+
+        outside
+    }
+
+    /// Faces of solid cells next to an empty cell or the edge of the grid.
+    pub fn surface_area(&self, solid: impl Fn(&T) -> bool) -> usize {
+        self.faces(&solid, |point| !self.contains(point) || !solid(&self[point]))
+    }
+
+    /// Faces of solid cells that can be reached from outside the grid, ignoring trapped pockets.
+    pub fn exterior_area(&self, solid: impl Fn(&T) -> bool) -> usize {
+        let outside = self.exterior(&solid);
+        self.faces(&solid, |point| !self.contains(point) || outside[point])
+    }
+
+    fn faces(&self, solid: impl Fn(&T) -> bool, open: impl Fn(Point3) -> bool) -> usize {
+        let mut faces = 0;
+
+        for z in 0..self.depth {
+            for y in 0..self.height {
+                for x in 0..self.width {
+                    let point = Point3::new(x, y, z);
+                    if solid(&self[point]) {
+                        faces += ORTHOGONAL3.iter().filter(|&&o| open(point + o)).count();
+                    }
+                }
+            }
+        }
+
+        faces
+    }
+
+    #[inline]
+    fn index_of(&self, point: Point3) -> usize {
+        assert!(self.contains(point), "{point:?} is outside the grid");
+        (self.width * (self.height * point.z + point.y) + point.x) as usize
+    }
+}
+
+#[inline]
+fn inside3(width: i32, height: i32, depth: i32, point: Point3) -> bool {
+    inside(width, height, Point::new(point.x, point.y)) && point.z >= 0 && point.z < depth
+}
+
+impl<T> Index<Point3> for Grid3<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point3) -> &Self::Output {
+        &self.bytes[self.index_of(index)]
+    }
+}
+
+impl<T> IndexMut<Point3> for Grid3<T> {
+    #[inline]
+    fn index_mut(&mut self, index: Point3) -> &mut Self::Output {
+        let index = self.index_of(index);
+        &mut self.bytes[index]
+    }
+}
@@ -0,0 +1,63 @@
+//! [Handheld game console](https://adventofcode.com/2020/day/8) boot code interpreter.
+//!
+//! The boot code has only three instructions and no conditional jumps, so a program either
+//! terminates by moving past its last instruction or repeats forever once any instruction runs
+//! a second time. [`run`] detects the repeat by remembering which instructions have already
+//! executed, returning the accumulator in either case.
+use crate::util::parse::*;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Acc(i32),
+    Jmp(i32),
+    Nop(i32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum State {
+    /// About to execute an instruction for the second time.
+    Loop(i32),
+    Terminated(i32),
+}
+
+pub fn parse(input: &str) -> Vec<Instruction> {
+    input
+        .lines()
+        .map(|line| {
+            let (operation, argument) = line.split_once(' ').unwrap();
+            let argument = argument.signed();
+            match operation {
+                "acc" => Instruction::Acc(argument),
+                "jmp" => Instruction::Jmp(argument),
+                "nop" => Instruction::Nop(argument),
+                _ => panic!("Unknown instruction {line}"),
+            }
+        })
+        .collect()
+}
+
+pub fn run(program: &[Instruction]) -> State {
+    let mut seen = vec![false; program.len()];
+    let mut accumulator = 0;
+    let mut pc = 0;
+
+    while pc < program.len() {
+        if seen[pc] {
+            return State::Loop(accumulator);
+        }
+        seen[pc] = true;
+
+        match program[pc] {
+            Instruction::Acc(delta) => accumulator += delta,
+            Instruction::Jmp(offset) => {
+                pc = pc.wrapping_add_signed(offset as isize);
+                continue;
+            }
+            Instruction::Nop(_) => (),
+        }
+
+        pc += 1;
+    }
+
+    State::Terminated(accumulator)
+}
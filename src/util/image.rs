@@ -0,0 +1,256 @@
+//! Renders a [`Grid`] as an image, useful for sharing visualizations or eyeballing large maps.
+//!
+//! Each cell is converted to an RGB color by a mapping function, which can be any
+//! `Fn(&T) -> [u8; 3]` closure or a lookup table built with [`palette`]. The resulting [`Image`]
+//! can optionally be enlarged with [`scale`] then written as either a binary
+//! [PPM](https://netpbm.sourceforge.net/doc/ppm.html) or a [PNG](https://www.w3.org/TR/png/).
+//!
+//! ```
+//!   # use aoc::util::grid::Grid;
+//!   # use aoc::util::image::*;
+//!
+//!   let grid = Grid::parse("#.\n.#");
+//!   let image = Image::from_grid(&grid, palette(&[(b'#', WHITE)])).scale(4);
+//!   assert_eq!((image.width, image.height), (8, 8));
+//!
+//!   let mut png = Vec::new();
+//!   image.write_png(&mut png).unwrap();
+//!   assert_eq!(&png[1..4], b"PNG");
+//! ```
+//!
+//! Scaling by zero is a bug and panics. Images without any pixels can still be written as PPM,
+//! but PNG requires at least one pixel so [`write_png`] returns an error instead:
+//!
+//! ```should_panic
+//!   # use aoc::util::grid::Grid;
+//!   # use aoc::util::image::*;
+//!
+//!   let grid = Grid::parse("#.\n.#");
+//!   Image::from_grid(&grid, palette(&[(b'#', WHITE)])).scale(0);
+//! ```
+//!
+//! ```
+//!   # use aoc::util::grid::Grid;
+//!   # use aoc::util::image::*;
+//!
+//!   let grid: Grid<u8> = Grid { width: 0, height: 2, bytes: Vec::new() };
+//!   let empty = Image::from_grid(&grid, palette(&[(b'#', WHITE)])).scale(3);
+//!   assert_eq!((empty.width, empty.height), (0, 6));
+//!   assert!(empty.write_png(&mut Vec::new()).is_err());
+//!
+//!   let mut ppm = Vec::new();
+//!   empty.write_ppm(&mut ppm).unwrap();
+//!   assert_eq!(ppm, b"P6\n0 6\n255\n");
+//! ```
+//!
+//! The PNG encoder is self contained. Pixel data is wrapped in uncompressed
+//! [stored deflate blocks](https://www.rfc-editor.org/rfc/rfc1951#section-3.2.4) so files are
+//! larger than necessary, but simple to produce and readable by any viewer.
+//!
+//! Animations are written by a [`Frames`] sequence as numbered files, for example
+//! `frame_00000.png`, `frame_00001.png`, ... that can be stitched together by external tools.
+//!
+//! [`Grid`]: crate::util::grid
+//! [`scale`]: Image::scale
+//! [`write_png`]: Image::write_png
+use crate::util::grid::*;
+use std::fs::{create_dir_all, File};
+use std::io::{BufWriter, Error, ErrorKind, Result, Write};
+use std::path::{Path, PathBuf};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const RED: Rgb = [255, 0, 0];
+pub const GREEN: Rgb = [0, 255, 0];
+pub const BLUE: Rgb = [0, 0, 255];
+
+/// Creates a mapping function from a list of `(cell, color)` pairs. Cells without an entry
+/// are drawn in black.
+pub fn palette<T: PartialEq>(entries: &[(T, Rgb)]) -> impl Fn(&T) -> Rgb + '_ {
+    move |cell| entries.iter().find(|(key, _)| key == cell).map_or(BLACK, |&(_, color)| color)
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Self {
+        Image {
+            width: grid.width as usize,
+            height: grid.height as usize,
+            pixels: grid.bytes.iter().map(color).collect(),
+        }
+    }
+
+    /// Enlarges each pixel into a `factor` by `factor` square. The factor must be at least one.
+    pub fn scale(&self, factor: usize) -> Self {
+        assert!(factor > 0, "Scale factor must be at least 1");
+        let width = self.width * factor;
+        let height = self.height * factor;
+        let mut pixels = Vec::with_capacity(width * height);
+
+        // An image with no columns has no pixels, so there are no rows to enlarge.
+        for row in self.pixels.chunks_exact(self.width.max(1)) {
+            let start = pixels.len();
+            row.iter().for_each(|&pixel| pixels.extend(std::iter::repeat_n(pixel, factor)));
+            for _ in 1..factor {
+                pixels.extend_from_within(start..start + width);
+            }
+        }
+
+        Image { width, height, pixels }
+    }
+
+    pub fn write_ppm(&self, mut writer: impl Write) -> Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(self.pixels.as_flattened())
+    }
+
+    /// PNG doesn't allow images without any pixels, so writing an empty image is an error.
+    pub fn write_png(&self, mut writer: impl Write) -> Result<()> {
+        if self.width == 0 || self.height == 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, "PNG images must not be empty"));
+        }
+
+        // Each scanline is prefixed by filter type 0 (none).
+        let stride = 1 + 3 * self.width;
+        let mut raw = Vec::with_capacity(stride * self.height);
+        for row in self.pixels.chunks_exact(self.width) {
+            raw.push(0);
+            raw.extend_from_slice(row.as_flattened());
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        // Bit depth 8, color type 2 (RGB), default compression, filter and no interlace.
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        writer.write_all(b"\x89PNG\r\n\x1a\n")?;
+        chunk(&mut writer, b"IHDR", &header)?;
+        chunk(&mut writer, b"IDAT", &zlib(&raw))?;
+        chunk(&mut writer, b"IEND", &[])
+    }
+
+    pub fn save_ppm(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_ppm(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_png(&mut writer)?;
+        writer.flush()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+}
+
+/// Writes an animated sequence as consecutively numbered image files in a directory.
+pub struct Frames {
+    directory: PathBuf,
+    format: Format,
+    scale: usize,
+    index: usize,
+}
+
+impl Frames {
+    pub fn new(directory: impl AsRef<Path>, format: Format, scale: usize) -> Result<Self> {
+        if scale == 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, "Scale factor must be at least 1"));
+        }
+
+        let directory = directory.as_ref().to_path_buf();
+        create_dir_all(&directory)?;
+        Ok(Frames { directory, format, scale, index: 0 })
+    }
+
+    /// Renders `grid` as the next frame, returning the path of the file written.
+    pub fn push<T>(&mut self, grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Result<PathBuf> {
+        let image = Image::from_grid(grid, color).scale(self.scale);
+        let extension = match self.format {
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+        };
+        let path = self.directory.join(format!("frame_{:05}.{extension}", self.index));
+
+        match self.format {
+            Format::Ppm => image.save_ppm(&path)?,
+            Format::Png => image.save_png(&path)?,
+        }
+
+        self.index += 1;
+        Ok(path)
+    }
+}
+
+fn chunk(writer: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> Result<()> {
+    writer.write_all(&(data.len() as u32).to_be_bytes())?;
+    writer.write_all(kind)?;
+    writer.write_all(data)?;
+
+    let crc = !crc32(crc32(!0, kind), data);
+    writer.write_all(&crc.to_be_bytes())
+}
+
+/// Wraps data in a zlib stream made up of stored (uncompressed) deflate blocks.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let blocks = data.len().div_ceil(65535).max(1);
+    let mut out = Vec::with_capacity(data.len() + 5 * blocks + 6);
+    out.extend_from_slice(&[0x78, 0x01]);
+
+    let mut chunks = data.chunks(65535).peekable();
+    if chunks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = chunks.next() {
+        let last = chunks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(mut crc: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb88320 & mask);
+        }
+    }
+    crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let mut a = 1;
+    let mut b = 0;
+
+    // 5552 is the largest block size where `b` can't overflow a `u32` before the modulo.
+    for block in data.chunks(5552) {
+        for &byte in block {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+
+    (b << 16) | a
+}
@@ -0,0 +1,161 @@
+//! [Intcode](https://adventofcode.com/2019/day/9) virtual machine used by half the days of 2019.
+//!
+//! Execution is resumable. [`Computer::run`] executes instructions until the program either
+//! needs input that hasn't been queued yet, produces an output or halts, then returns control
+//! to the caller. This makes it easy to drive several machines at once, for example chaining
+//! amplifiers or simulating a network, by alternating between them.
+//!
+//! Memory grows on demand when an address past the end of the program is written. Reads past
+//! the end return zero without growing. The whole machine is [`Clone`] so that a search can
+//! snapshot its state and branch.
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum State {
+    /// Waiting for more input, call [`Computer::input`] then [`Computer::run`] to resume.
+    Input,
+    Output(i64),
+    Halted,
+}
+
+#[derive(Clone)]
+pub struct Computer {
+    pc: usize,
+    base: i64,
+    memory: Vec<i64>,
+    input: VecDeque<i64>,
+}
+
+impl Computer {
+    pub fn new(code: &[i64]) -> Computer {
+        Computer { pc: 0, base: 0, memory: code.to_vec(), input: VecDeque::new() }
+    }
+
+    pub fn input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    /// Queues each byte of `ascii` as a separate input.
+    pub fn input_ascii(&mut self, ascii: &str) {
+        self.input.extend(ascii.bytes().map(i64::from));
+    }
+
+    pub fn read(&self, address: usize) -> i64 {
+        self.memory.get(address).copied().unwrap_or(0)
+    }
+
+    pub fn write(&mut self, address: usize, value: i64) {
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+        self.memory[address] = value;
+    }
+
+    /// Runs until the next output, the program halts, or an input instruction finds the queue
+    /// empty. In the last case the program counter is left on the input instruction so that it
+    /// is retried on the next call.
+    pub fn run(&mut self) -> State {
+        loop {
+            let op = self.read(self.pc);
+
+            match op % 100 {
+                1 => {
+                    let value = self.param(op, 1) + self.param(op, 2);
+                    self.store(op, 3, value);
+                    self.pc += 4;
+                }
+                2 => {
+                    let value = self.param(op, 1) * self.param(op, 2);
+                    self.store(op, 3, value);
+                    self.pc += 4;
+                }
+                3 => {
+                    let Some(value) = self.input.pop_front() else {
+                        return State::Input;
+                    };
+                    self.store(op, 1, value);
+                    self.pc += 2;
+                }
+                4 => {
+                    let value = self.param(op, 1);
+                    self.pc += 2;
+                    return State::Output(value);
+                }
+                5 => {
+                    self.pc = if self.param(op, 1) != 0 {
+                        self.param(op, 2) as usize
+                    } else {
+                        self.pc + 3
+                    };
+                }
+                6 => {
+                    self.pc = if self.param(op, 1) == 0 {
+                        self.param(op, 2) as usize
+                    } else {
+                        self.pc + 3
+                    };
+                }
+                7 => {
+                    let value = (self.param(op, 1) < self.param(op, 2)) as i64;
+                    self.store(op, 3, value);
+                    self.pc += 4;
+                }
+                8 => {
+                    let value = (self.param(op, 1) == self.param(op, 2)) as i64;
+                    self.store(op, 3, value);
+                    self.pc += 4;
+                }
+                9 => {
+                    self.base += self.param(op, 1);
+                    self.pc += 2;
+                }
+                99 => return State::Halted,
+                _ => panic!("Unknown opcode {op} at {}", self.pc),
+            }
+        }
+    }
+
+    /// Runs until halted, collecting every output. Panics if more input is needed.
+    pub fn run_to_end(&mut self) -> Vec<i64> {
+        let mut outputs = Vec::new();
+        loop {
+            match self.run() {
+                State::Output(value) => outputs.push(value),
+                State::Halted => return outputs,
+                State::Input => panic!("Unexpected input request at {}", self.pc),
+            }
+        }
+    }
+
+    /// Runs until more input is needed or the program halts, collecting outputs as text.
+    pub fn run_ascii(&mut self) -> String {
+        let mut text = String::new();
+        while let State::Output(value) = self.run() {
+            text.push(value as u8 as char);
+        }
+        text
+    }
+
+    /// Address of the `index`th parameter of the current instruction, taking into account its
+    /// mode: 0 for position, 1 for immediate and 2 for relative.
+    fn address(&self, op: i64, index: usize) -> usize {
+        let mode = op / [0, 100, 1000, 10000][index] % 10;
+        let address = self.pc + index;
+
+        match mode {
+            0 => self.read(address) as usize,
+            1 => address,
+            2 => (self.base + self.read(address)) as usize,
+            _ => panic!("Unknown mode {mode} at {}", self.pc),
+        }
+    }
+
+    fn param(&self, op: i64, index: usize) -> i64 {
+        self.read(self.address(op, index))
+    }
+
+    fn store(&mut self, op: i64, index: usize, value: i64) {
+        let address = self.address(op, index);
+        self.write(address, value);
+    }
+}
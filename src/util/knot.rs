@@ -0,0 +1,48 @@
+//! [Knot hash](https://adventofcode.com/2017/day/10) used by two days of 2017.
+//!
+//! Reversing a section that wraps around the end of the list is awkward. Instead the list is
+//! rotated left after each step so that the current position is always at index zero, making
+//! every reversal a contiguous prefix. The total rotation is tracked and undone at the end.
+//!
+//! The [`sparse`] hash is exposed with a configurable list size and number of rounds so that
+//! the smaller examples can be tested. [`hash`] computes the full 64 round hash of a string
+//! that [`hex`] formats as 32 hexadecimal digits.
+
+/// Suffix appended to the ASCII lengths of the full hash.
+const SUFFIX: [usize; 5] = [17, 31, 73, 47, 23];
+
+/// Ties `rounds` rounds of knots in a list of `size` elements, preserving the position and
+/// skip size between rounds.
+pub fn sparse(size: usize, lengths: &[usize], rounds: usize) -> Vec<u8> {
+    let mut list: Vec<_> = (0..size).map(|i| i as u8).collect();
+    let mut position = 0;
+    let mut skip = 0;
+
+    for _ in 0..rounds {
+        for &length in lengths {
+            let next = length + skip;
+            list[..length].reverse();
+            list.rotate_left(next % size);
+            position += next;
+            skip += 1;
+        }
+    }
+
+    list.rotate_right(position % size);
+    list
+}
+
+/// Reduces each block of 16 numbers in the sparse hash to a single byte using XOR.
+pub fn dense(sparse: &[u8]) -> Vec<u8> {
+    sparse.chunks_exact(16).map(|chunk| chunk.iter().fold(0, |acc, n| acc ^ n)).collect()
+}
+
+/// Full knot hash of `input` as 16 bytes.
+pub fn hash(input: &str) -> Vec<u8> {
+    let lengths: Vec<_> = input.bytes().map(usize::from).chain(SUFFIX).collect();
+    dense(&sparse(256, &lengths, 64))
+}
+
+pub fn hex(dense: &[u8]) -> String {
+    dense.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -21,6 +21,23 @@
 //! and a [`manhattan`] function for the
 //! [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry) between 2 points.
 //!
+//! Voxel puzzles use [`Point3`], which supports the same operators and Manhattan distance. The
+//! [`ORTHOGONAL3`] constant holds the 6 neighbors that share a face and [`DIAGONAL3`] all 26
+//! neighbors that share a face, edge or corner:
+//!
+//! ```
+//!   # use aoc::util::point::*;
+//!
+//!   let a = Point3::new(1, 2, 3);
+//!   let b = Point3::new(-1, 0, 5);
+//!
+//!   assert_eq!(a + b, Point3::new(0, 2, 8));
+//!   assert_eq!(a - b, Point3::new(2, 2, -2));
+//!   assert_eq!(a * 3, Point3::new(3, 6, 9));
+//!   assert_eq!(a.manhattan(b), 6);
+//!   assert!(DIAGONAL3.iter().all(|&n| n != ORIGIN3 && n.manhattan(ORIGIN3) <= 3));
+//! ```
+//!
 //! [`clockwise`]: Point::clockwise
 //! [`counter_clockwise`]: Point::counter_clockwise
 //! [`manhattan`]: Point::manhattan
@@ -46,6 +63,32 @@ pub const DIAGONAL: [Point; 8] = [
     Point::new(1, 1),
 ];
 
+pub const ORIGIN3: Point3 = Point3::new(0, 0, 0);
+pub const ORTHOGONAL3: [Point3; 6] = [
+    Point3::new(-1, 0, 0),
+    Point3::new(1, 0, 0),
+    Point3::new(0, -1, 0),
+    Point3::new(0, 1, 0),
+    Point3::new(0, 0, -1),
+    Point3::new(0, 0, 1),
+];
+// Ordered by z, then y, then x.
+pub const DIAGONAL3: [Point3; 26] = {
+    let mut neighbors = [ORIGIN3; 26];
+    let mut index = 0;
+    let mut i = 0;
+
+    while i < 27 {
+        if i != 13 {
+            neighbors[index] = Point3::new(i % 3 - 1, i / 3 % 3 - 1, i / 9 - 1);
+            index += 1;
+        }
+        i += 1;
+    }
+
+    neighbors
+};
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Point {
     pub x: i32,
@@ -86,7 +129,6 @@ impl Point {
 
 impl From<u8> for Point {
     #[inline]
-    #[must_use]
     fn from(value: u8) -> Self {
         match value {
             b'^' | b'U' => UP,
@@ -110,7 +152,6 @@ impl Add for Point {
     type Output = Self;
 
     #[inline]
-    #[must_use]
     fn add(self, rhs: Self) -> Self {
         Point::new(self.x + rhs.x, self.y + rhs.y)
     }
@@ -128,7 +169,6 @@ impl Mul<i32> for Point {
     type Output = Self;
 
     #[inline]
-    #[must_use]
     fn mul(self, rhs: i32) -> Self {
         Point::new(self.x * rhs, self.y * rhs)
     }
@@ -138,7 +178,6 @@ impl Sub for Point {
     type Output = Self;
 
     #[inline]
-    #[must_use]
     fn sub(self, rhs: Self) -> Self {
         Point::new(self.x - rhs.x, self.y - rhs.y)
     }
@@ -151,3 +190,95 @@ impl SubAssign for Point {
         self.y -= rhs.y;
     }
 }
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    #[inline]
+    #[must_use]
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Point3 { x, y, z }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn signum(self, other: Self) -> Self {
+        Point3::new(
+            (self.x - other.x).signum(),
+            (self.y - other.y).signum(),
+            (self.z - other.z).signum(),
+        )
+    }
+}
+
+impl From<[i32; 3]> for Point3 {
+    #[inline]
+    fn from([x, y, z]: [i32; 3]) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl Hash for Point3 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u32(self.x as u32);
+        state.write_u32(self.y as u32);
+        state.write_u32(self.z as u32);
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl Mul<i32> for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: i32) -> Self {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl SubAssign for Point3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
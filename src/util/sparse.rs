@@ -0,0 +1,160 @@
+//! Unbounded 2 dimensional grid for puzzles where the world grows as the simulation runs, for
+//! example falling sand or spreading elves, so the size of a dense [`Grid`] isn't known up front.
+//!
+//! Cells are stored in a [`FastMap`] keyed by [`Point`] and any cell not stored reads as the
+//! default value, so setting a cell to the default forgets it. Coordinates can be negative. The
+//! bounding box of every stored cell is tracked as cells are set, so the occupied area can be
+//! converted to a dense [`Grid`] with [`to_grid`] or drawn with [`render`]:
+//!
+//! ```
+//!   # use aoc::util::grid::Grid;
+//!   # use aoc::util::point::Point;
+//!   # use aoc::util::sparse::SparseGrid;
+//!
+//!   let mut sparse = SparseGrid::new(b'.');
+//!   sparse.set(Point::new(-1, 0), b'#');
+//!   sparse.set(Point::new(1, 2), b'#');
+//!
+//!   assert_eq!(sparse[Point::new(0, 0)], b'.');
+//!   assert_eq!(sparse.bounds(), Some((Point::new(-1, 0), Point::new(1, 2))));
+//!   assert_eq!(sparse.render(|&b| b as char), "#..\n...\n..#\n");
+//!
+//!   sparse.set(Point::new(5, 5), b'.');
+//!   assert_eq!(sparse.len(), 2);
+//!   assert_eq!(sparse.iter().filter(|&(p, _)| p.x < 0).count(), 1);
+//!
+//!   let empty = SparseGrid::new(b'.');
+//!   assert_eq!(empty.render(|&b| b as char), "");
+//!   assert_eq!(format!("{:?}", empty.to_grid()), "Grid 0x0\n");
+//!
+//!   let grid = Grid::parse("#.\n.#");
+//!   let round_trip = SparseGrid::from_grid(&grid, b'.').to_grid();
+//!   assert_eq!(round_trip, grid);
+//!
+//!   let edges = Grid::parse("...\n.#.\n...");
+//!   let round_trip = SparseGrid::from_grid(&edges, b'.').to_grid();
+//!   assert_eq!(round_trip, edges);
+//! ```
+//!
+//! Removing cells doesn't shrink the bounds, as that would need a scan of every remaining cell.
+//!
+//! [`Grid`]: crate::util::grid
+//! [`Point`]: crate::util::point
+//! [`to_grid`]: SparseGrid::to_grid
+//! [`render`]: SparseGrid::render
+use crate::util::grid::*;
+use crate::util::hash::*;
+use crate::util::point::*;
+use std::ops::Index;
+
+#[derive(Clone)]
+pub struct SparseGrid<T> {
+    cells: FastMap<Point, T>,
+    default: T,
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T: Copy + PartialEq> SparseGrid<T> {
+    pub fn new(default: T) -> Self {
+        SparseGrid { cells: FastMap::new(), default, bounds: None }
+    }
+
+    /// Stores every cell of `grid` that doesn't equal `default`, keeping the same coordinates.
+    /// The bounds start as the whole grid, so converting back gives the same size even when
+    /// the edges are all default cells.
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self {
+        let mut sparse = SparseGrid::new(default);
+        if grid.width > 0 && grid.height > 0 {
+            sparse.bounds = Some((ORIGIN, Point::new(grid.width - 1, grid.height - 1)));
+        }
+
+        for (point, &value) in grid.iter() {
+            if value != default {
+                sparse.set(point, value);
+            }
+        }
+        sparse
+    }
+
+    #[inline]
+    pub fn get(&self, point: Point) -> T {
+        self.cells.get(&point).copied().unwrap_or(self.default)
+    }
+
+    /// Stores a cell and grows the bounds to include it. Setting the default value removes the
+    /// cell instead, leaving the bounds unchanged.
+    #[inline]
+    pub fn set(&mut self, point: Point, value: T) {
+        if value == self.default {
+            self.cells.remove(&point);
+            return;
+        }
+
+        self.cells.insert(point, value);
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+            None => (point, point),
+        });
+    }
+
+    /// Forgets a cell so that it reads as the default value again, returning its old value.
+    #[inline]
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// Every stored cell along with its point, in no particular order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// Number of stored cells, none of which hold the default value.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Top left and bottom right corners, both inclusive, of every cell ever set along with the
+    /// grid it was created from, if any.
+    #[inline]
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// Copies the bounds into a dense grid, with the top left corner of the bounds moved to the
+    /// origin. An empty sparse grid becomes an empty dense grid.
+    pub fn to_grid(&self) -> Grid<T> {
+        let Some((min, max)) = self.bounds else {
+            return Grid { width: 0, height: 0, bytes: Vec::new() };
+        };
+
+        let mut grid = Grid::new(max.x - min.x + 1, max.y - min.y + 1, self.default);
+        for (&point, &value) in &self.cells {
+            grid[point - min] = value;
+        }
+        grid
+    }
+
+    /// Draws the bounds row by row, drawing each cell as the `char` returned by `cell`.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.to_grid().render(cell).to_string()
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: Point) -> &Self::Output {
+        self.cells.get(&index).unwrap_or(&self.default)
+    }
+}
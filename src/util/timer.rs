@@ -0,0 +1,182 @@
+//! Named timers that measure phases inside a solution, for example building a graph versus
+//! searching it.
+//!
+//! Create a [`span`] at the start of a block. The elapsed time is recorded when the returned
+//! guard is dropped. Spans opened while another is active nest beneath it and repeated spans
+//! with the same name under the same parent are aggregated.
+//!
+//! ```
+//!   # use aoc::util::timer;
+//!
+//!   timer::enable(true);
+//!   {
+//!       let _outer = timer::span("solve");
+//!       for _ in 0..3 {
+//!           let _inner = timer::span("step");
+//!       }
+//!   }
+//!
+//!   let records = timer::take();
+//! # #[cfg(feature = "timer")]
+//! # {
+//!   assert_eq!(records[0].name, "solve");
+//!   assert_eq!((records[1].name, records[1].depth, records[1].count), ("step", 1, 3));
+//! # }
+//! ```
+//!
+//! Spans only exist when built with the `timer` feature, which is off by default so that every
+//! span compiles to a no-op, for example when benchmarking. Even with the feature on, recording
+//! is off until [`enable`]d so spans cost a single atomic load during normal runs. Timings are
+//! tracked per thread, so spans opened in worker threads spawned by [`thread`] are not reported.
+//!
+//! [`thread`]: crate::util::thread
+use std::time::Duration;
+
+/// Aggregated timing for one node of the span tree, listed depth first.
+pub struct Record {
+    pub name: &'static str,
+    pub depth: usize,
+    pub count: usize,
+    pub total: Duration,
+}
+
+#[cfg(feature = "timer")]
+mod imp {
+    use super::Record;
+    use std::cell::RefCell;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::{Duration, Instant};
+
+    static ENABLED: AtomicBool = AtomicBool::new(false);
+
+    thread_local! {
+        static TREE: RefCell<Tree> = const {
+            RefCell::new(Tree { nodes: Vec::new(), roots: Vec::new(), stack: Vec::new() })
+        };
+    }
+
+    struct Node {
+        name: &'static str,
+        children: Vec<usize>,
+        count: usize,
+        total: Duration,
+    }
+
+    struct Tree {
+        nodes: Vec<Node>,
+        roots: Vec<usize>,
+        stack: Vec<usize>,
+    }
+
+    pub struct Span {
+        start: Option<Instant>,
+    }
+
+    pub fn span(name: &'static str) -> Span {
+        if !ENABLED.load(Ordering::Relaxed) {
+            return Span { start: None };
+        }
+
+        TREE.with_borrow_mut(|tree| {
+            let siblings = match tree.stack.last() {
+                Some(&parent) => &tree.nodes[parent].children,
+                None => &tree.roots,
+            };
+            let found = siblings.iter().copied().find(|&i| tree.nodes[i].name == name);
+
+            let index = found.unwrap_or_else(|| {
+                let index = tree.nodes.len();
+                let node = Node { name, children: Vec::new(), count: 0, total: Duration::ZERO };
+                tree.nodes.push(node);
+                match tree.stack.last() {
+                    Some(&parent) => tree.nodes[parent].children.push(index),
+                    None => tree.roots.push(index),
+                }
+                index
+            });
+
+            tree.stack.push(index);
+        });
+
+        Span { start: Some(Instant::now()) }
+    }
+
+    impl Drop for Span {
+        fn drop(&mut self) {
+            if let Some(start) = self.start {
+                let elapsed = start.elapsed();
+                TREE.with_borrow_mut(|tree| {
+                    // Spans still open when the timings are taken have nowhere to record.
+                    if let Some(node) = tree.stack.pop().and_then(|i| tree.nodes.get_mut(i)) {
+                        node.count += 1;
+                        node.total += elapsed;
+                    }
+                });
+            }
+        }
+    }
+
+    pub fn enable(on: bool) {
+        ENABLED.store(on, Ordering::Relaxed);
+    }
+
+    pub fn take() -> Vec<Record> {
+        TREE.with_borrow_mut(|tree| {
+            let nodes = std::mem::take(&mut tree.nodes);
+            let roots = std::mem::take(&mut tree.roots);
+            let mut records = Vec::with_capacity(nodes.len());
+            let mut todo: Vec<_> = roots.iter().rev().map(|&i| (i, 0)).collect();
+
+            while let Some((index, depth)) = todo.pop() {
+                let Node { name, count, total, .. } = nodes[index];
+                records.push(Record { name, depth, count, total });
+                todo.extend(nodes[index].children.iter().rev().map(|&i| (i, depth + 1)));
+            }
+
+            // Any spans still open are discarded.
+            tree.stack.clear();
+            records
+        })
+    }
+}
+
+#[cfg(not(feature = "timer"))]
+mod imp {
+    use super::Record;
+
+    pub struct Span;
+
+    #[inline]
+    pub fn span(_name: &'static str) -> Span {
+        Span
+    }
+
+    #[inline]
+    pub fn enable(_on: bool) {}
+
+    #[inline]
+    pub fn take() -> Vec<Record> {
+        Vec::new()
+    }
+}
+
+/// Starts timing a named block, stopping when the returned guard is dropped.
+#[must_use = "the span is recorded when dropped, bind it with `let _span = ...`"]
+#[inline]
+pub fn span(name: &'static str) -> Span {
+    imp::span(name)
+}
+
+/// Turns recording on or off for every thread.
+#[inline]
+pub fn enable(on: bool) {
+    imp::enable(on);
+}
+
+/// Returns the timings recorded by the current thread, then clears them.
+#[inline]
+pub fn take() -> Vec<Record> {
+    imp::take()
+}
+
+pub use imp::Span;
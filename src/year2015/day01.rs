@@ -0,0 +1,21 @@
+//! # Not Quite Lisp
+pub fn parse(input: &str) -> &[u8] {
+    input.trim().as_bytes()
+}
+
+pub fn part1(input: &[u8]) -> i32 {
+    input.iter().map(|&b| if b == b'(' { 1 } else { -1 }).sum()
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    let mut floor = 0;
+
+    for (i, &b) in input.iter().enumerate() {
+        floor += if b == b'(' { 1 } else { -1 };
+        if floor < 0 {
+            return i + 1;
+        }
+    }
+
+    unreachable!()
+}
@@ -0,0 +1,24 @@
+//! # I Was Told There Would Be No Math
+//!
+//! Sorting each present's dimensions means the smallest side is always the first two values.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<[u32; 3]> {
+    input
+        .iter_unsigned()
+        .chunk::<3>()
+        .map(|mut present| {
+            present.sort_unstable();
+            present
+        })
+        .collect()
+}
+
+pub fn part1(input: &[[u32; 3]]) -> u32 {
+    input.iter().map(|[l, w, h]| 2 * (l * w + w * h + h * l) + l * w).sum()
+}
+
+pub fn part2(input: &[[u32; 3]]) -> u32 {
+    input.iter().map(|[l, w, h]| 2 * (l + w) + l * w * h).sum()
+}
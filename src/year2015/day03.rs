@@ -0,0 +1,31 @@
+//! # Perfectly Spherical Houses in a Vacuum
+use crate::util::hash::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Vec<Point> {
+    input.trim().bytes().map(Point::from).collect()
+}
+
+pub fn part1(input: &[Point]) -> usize {
+    deliver(input, |_| true)
+}
+
+pub fn part2(input: &[Point]) -> usize {
+    deliver(input, |i| i % 2 == 0)
+}
+
+/// Santa moves on steps where `santa` is true, Robo-Santa moves otherwise.
+fn deliver(input: &[Point], santa: impl Fn(usize) -> bool) -> usize {
+    let mut santa_position = ORIGIN;
+    let mut robot_position = ORIGIN;
+    let mut visited = FastSet::with_capacity(input.len());
+    visited.insert(ORIGIN);
+
+    for (i, &step) in input.iter().enumerate() {
+        let position = if santa(i) { &mut santa_position } else { &mut robot_position };
+        *position += step;
+        visited.insert(*position);
+    }
+
+    visited.len()
+}
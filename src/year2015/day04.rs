@@ -0,0 +1,70 @@
+//! # The Ideal Stocking Stuffer
+//!
+//! Brute force search split across threads. Each thread claims a batch of numbers from a shared
+//! atomic counter so batches are handed out in increasing order. When a thread finds a match it
+//! sets a flag that stops any thread claiming a new batch, however batches already in progress
+//! run to completion. As every lower batch has been fully checked the smallest match is correct.
+use crate::util::md5::*;
+use crate::util::thread::*;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+
+const BATCH: u32 = 1000;
+
+pub fn parse(input: &str) -> &str {
+    input.trim()
+}
+
+pub fn part1(input: &str) -> u32 {
+    search(input, 0xfffff000)
+}
+
+pub fn part2(input: &str) -> u32 {
+    search(input, 0xffffff00)
+}
+
+/// Finds the lowest number where the first 32 bits of the hash masked by `mask` are zero.
+fn search(key: &str, mask: u32) -> u32 {
+    let done = AtomicBool::new(false);
+    let counter = AtomicU32::new(0);
+    let found = Mutex::new(u32::MAX);
+
+    spawn(|| {
+        while !done.load(Ordering::Relaxed) {
+            let start = counter.fetch_add(BATCH, Ordering::Relaxed);
+
+            if let Some(n) = check_batch(key, start, mask) {
+                done.store(true, Ordering::Relaxed);
+                let mut found = found.lock().unwrap();
+                *found = (*found).min(n);
+            }
+        }
+    });
+
+    found.into_inner().unwrap()
+}
+
+/// Numbers in a batch (apart from the first) share every digit except the last three, so the
+/// buffer is filled once and only the final digits change for each hash.
+fn check_batch(key: &str, start: u32, mask: u32) -> Option<u32> {
+    if start == 0 {
+        return (1..BATCH).find(|n| {
+            let text = format!("{key}{n}");
+            let mut buffer = vec![0; buffer_size(text.len())];
+            buffer[..text.len()].copy_from_slice(text.as_bytes());
+            hash(&mut buffer, text.len()).0 & mask == 0
+        });
+    }
+
+    let prefix = format!("{key}{}", start / BATCH);
+    let size = prefix.len() + 3;
+    let mut buffer = vec![0; buffer_size(size)];
+    buffer[..prefix.len()].copy_from_slice(prefix.as_bytes());
+
+    (0..BATCH).find_map(|n| {
+        buffer[size - 3] = b'0' + (n / 100) as u8;
+        buffer[size - 2] = b'0' + (n / 10 % 10) as u8;
+        buffer[size - 1] = b'0' + (n % 10) as u8;
+        (hash(&mut buffer, size).0 & mask == 0).then_some(start + n)
+    })
+}
@@ -0,0 +1,44 @@
+//! # Doesn't He Have Intern-Elves For This?
+pub fn parse(input: &str) -> Vec<&[u8]> {
+    input.lines().map(str::as_bytes).collect()
+}
+
+pub fn part1(input: &[&[u8]]) -> usize {
+    input
+        .iter()
+        .filter(|line| {
+            let vowels = line.iter().filter(|b| b"aeiou".contains(b)).count();
+            let double = line.windows(2).any(|w| w[0] == w[1]);
+            let forbidden = line.windows(2).any(|w| matches!(w, b"ab" | b"cd" | b"pq" | b"xy"));
+            vowels >= 3 && double && !forbidden
+        })
+        .count()
+}
+
+pub fn part2(input: &[&[u8]]) -> usize {
+    // Tracks the first position of each pair of letters. Pairs seen in earlier lines are
+    // invalidated by storing the line number alongside the position.
+    let mut first = vec![(usize::MAX, 0); 26 * 26];
+
+    input
+        .iter()
+        .enumerate()
+        .filter(|&(line_number, line)| {
+            let mut pair = false;
+
+            for (i, w) in line.windows(2).enumerate() {
+                let key = 26 * (w[0] - b'a') as usize + (w[1] - b'a') as usize;
+                let (seen_line, position) = first[key];
+
+                if seen_line != line_number {
+                    first[key] = (line_number, i);
+                } else if i - position >= 2 {
+                    pair = true;
+                }
+            }
+
+            let repeat = line.windows(3).any(|w| w[0] == w[2]);
+            pair && repeat
+        })
+        .count()
+}
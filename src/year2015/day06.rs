@@ -0,0 +1,65 @@
+//! # Probably a Fire Hazard
+//!
+//! Each instruction is applied to one row slice at a time, a tight loop that the compiler
+//! can vectorize.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+#[derive(Clone, Copy)]
+pub enum Command {
+    On,
+    Off,
+    Toggle,
+}
+
+pub struct Instruction {
+    command: Command,
+    from: (usize, usize),
+    to: (usize, usize),
+}
+
+pub fn parse(input: &str) -> Vec<Instruction> {
+    input
+        .lines()
+        .map(|line| {
+            let command = if line.starts_with("turn on") {
+                Command::On
+            } else if line.starts_with("turn off") {
+                Command::Off
+            } else {
+                Command::Toggle
+            };
+            let [x1, y1, x2, y2] = line.iter_unsigned().chunk::<4>().next().unwrap();
+            Instruction { command, from: (x1, y1), to: (x2, y2) }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Instruction]) -> u32 {
+    lights(input, |command, light| match command {
+        Command::On => *light = 1,
+        Command::Off => *light = 0,
+        Command::Toggle => *light ^= 1,
+    })
+}
+
+pub fn part2(input: &[Instruction]) -> u32 {
+    lights(input, |command, light| match command {
+        Command::On => *light += 1,
+        Command::Off => *light = light.saturating_sub(1),
+        Command::Toggle => *light += 2,
+    })
+}
+
+fn lights(input: &[Instruction], apply: impl Fn(Command, &mut u32)) -> u32 {
+    let mut grid = vec![0; 1_000_000];
+
+    for &Instruction { command, from: (x1, y1), to: (x2, y2) } in input {
+        for y in y1..=y2 {
+            let row = &mut grid[1000 * y + x1..=1000 * y + x2];
+            row.iter_mut().for_each(|light| apply(command, light));
+        }
+    }
+
+    grid.iter().sum()
+}
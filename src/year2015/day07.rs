@@ -0,0 +1,67 @@
+//! # Some Assembly Required
+//!
+//! Gates are stored in a [`FastMap`] keyed by output wire then evaluated recursively on demand,
+//! caching each wire's signal so that shared sub-circuits are only computed once.
+use crate::util::hash::*;
+
+pub enum Gate<'a> {
+    Wire(&'a str),
+    Not(&'a str),
+    And(&'a str, &'a str),
+    Or(&'a str, &'a str),
+    LeftShift(&'a str, &'a str),
+    RightShift(&'a str, &'a str),
+}
+
+type Circuit<'a> = FastMap<&'a str, Gate<'a>>;
+
+pub fn parse(input: &str) -> Circuit<'_> {
+    input
+        .lines()
+        .map(|line| {
+            let tokens: Vec<_> = line.split_ascii_whitespace().collect();
+            let gate = match tokens[..] {
+                [a, "->", _] => Gate::Wire(a),
+                ["NOT", a, "->", _] => Gate::Not(a),
+                [a, "AND", b, "->", _] => Gate::And(a, b),
+                [a, "OR", b, "->", _] => Gate::Or(a, b),
+                [a, "LSHIFT", b, "->", _] => Gate::LeftShift(a, b),
+                [a, "RSHIFT", b, "->", _] => Gate::RightShift(a, b),
+                _ => unreachable!(),
+            };
+            (tokens[tokens.len() - 1], gate)
+        })
+        .collect()
+}
+
+pub fn part1(input: &Circuit<'_>) -> u16 {
+    signal(input, &mut FastMap::new(), "a")
+}
+
+pub fn part2(input: &Circuit<'_>) -> u16 {
+    let mut cache = FastMap::new();
+    cache.insert("b", part1(input));
+    signal(input, &mut cache, "a")
+}
+
+fn signal<'a>(circuit: &Circuit<'a>, cache: &mut FastMap<&'a str, u16>, wire: &'a str) -> u16 {
+    if let Ok(value) = wire.parse() {
+        return value;
+    }
+    if let Some(&value) = cache.get(wire) {
+        return value;
+    }
+
+    let mut signal = |wire| signal(circuit, cache, wire);
+    let value = match circuit[wire] {
+        Gate::Wire(a) => signal(a),
+        Gate::Not(a) => !signal(a),
+        Gate::And(a, b) => signal(a) & signal(b),
+        Gate::Or(a, b) => signal(a) | signal(b),
+        Gate::LeftShift(a, b) => signal(a) << signal(b),
+        Gate::RightShift(a, b) => signal(a) >> signal(b),
+    };
+
+    cache.insert(wire, value);
+    value
+}
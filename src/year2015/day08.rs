@@ -0,0 +1,32 @@
+//! # Matchsticks
+//!
+//! Only the difference in length is needed, so there's no need to actually decode or encode
+//! any strings.
+pub fn parse(input: &str) -> Vec<&[u8]> {
+    input.lines().map(str::as_bytes).collect()
+}
+
+pub fn part1(input: &[&[u8]]) -> usize {
+    input
+        .iter()
+        .map(|line| {
+            let mut overhead = 2;
+            let mut i = 1;
+
+            while i < line.len() - 1 {
+                if line[i] == b'\\' {
+                    let skip = if line[i + 1] == b'x' { 3 } else { 1 };
+                    overhead += skip;
+                    i += skip;
+                }
+                i += 1;
+            }
+
+            overhead
+        })
+        .sum()
+}
+
+pub fn part2(input: &[&[u8]]) -> usize {
+    input.iter().map(|line| 2 + line.iter().filter(|&&b| b == b'"' || b == b'\\').count()).sum()
+}
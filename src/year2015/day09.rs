@@ -0,0 +1,52 @@
+//! # All in a Single Night
+//!
+//! There are only eight locations so checking every route with [`permutations`] is fast.
+//! Both the shortest and longest distances are found in a single pass.
+//!
+//! [`permutations`]: crate::util::slice::SliceOps::permutations
+use crate::util::hash::*;
+use crate::util::parse::*;
+use crate::util::slice::*;
+
+pub fn parse(input: &str) -> (u32, u32) {
+    let mut indices = FastMap::new();
+    let mut edges = Vec::new();
+
+    for line in input.lines() {
+        let tokens: Vec<_> = line.split_ascii_whitespace().collect();
+        let mut index = |name| {
+            let size = indices.len();
+            *indices.entry(name).or_insert(size)
+        };
+        let from = index(tokens[0]);
+        let to = index(tokens[2]);
+        edges.push((from, to, tokens[4].unsigned::<u32>()));
+    }
+
+    let size = indices.len();
+    let mut distances = vec![0; size * size];
+    for (from, to, distance) in edges {
+        distances[size * from + to] = distance;
+        distances[size * to + from] = distance;
+    }
+
+    let mut shortest = u32::MAX;
+    let mut longest = 0;
+    let mut route: Vec<_> = (0..size).collect();
+
+    route.permutations(|slice| {
+        let total = slice.windows(2).map(|w| distances[size * w[0] + w[1]]).sum();
+        shortest = shortest.min(total);
+        longest = longest.max(total);
+    });
+
+    (shortest, longest)
+}
+
+pub fn part1(input: &(u32, u32)) -> u32 {
+    input.0
+}
+
+pub fn part2(input: &(u32, u32)) -> u32 {
+    input.1
+}
@@ -0,0 +1,37 @@
+//! # Elves Look, Elves Say
+//!
+//! Runs of digits are encoded directly into a reusable output buffer.
+pub fn parse(input: &str) -> (usize, usize) {
+    let mut current: Vec<_> = input.trim().bytes().map(|b| b - b'0').collect();
+    let mut next = Vec::new();
+    let mut lengths = Vec::with_capacity(50);
+
+    for _ in 0..50 {
+        next.clear();
+        let mut i = 0;
+
+        while i < current.len() {
+            let digit = current[i];
+            let mut j = i + 1;
+            while j < current.len() && current[j] == digit {
+                j += 1;
+            }
+            next.push((j - i) as u8);
+            next.push(digit);
+            i = j;
+        }
+
+        std::mem::swap(&mut current, &mut next);
+        lengths.push(current.len());
+    }
+
+    (lengths[39], lengths[49])
+}
+
+pub fn part1(input: &(usize, usize)) -> usize {
+    input.0
+}
+
+pub fn part2(input: &(usize, usize)) -> usize {
+    input.1
+}
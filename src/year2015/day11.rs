@@ -0,0 +1,60 @@
+//! # Corporate Policy
+//!
+//! Passwords are incremented like a base 26 number. When a forbidden letter appears, every
+//! password up to the next letter is invalid so that letter is bumped directly and all letters
+//! to its right reset to `a`.
+pub fn parse(input: &str) -> Vec<u8> {
+    input.trim().bytes().collect()
+}
+
+pub fn part1(input: &[u8]) -> String {
+    let mut password = input.to_vec();
+    next_valid(&mut password);
+    String::from_utf8(password).unwrap()
+}
+
+pub fn part2(input: &[u8]) -> String {
+    let mut password = input.to_vec();
+    next_valid(&mut password);
+    next_valid(&mut password);
+    String::from_utf8(password).unwrap()
+}
+
+fn next_valid(password: &mut [u8]) {
+    loop {
+        increment(password);
+
+        if let Some(i) = password.iter().position(|b| matches!(b, b'i' | b'o' | b'l')) {
+            password[i] += 1;
+            password[i + 1..].fill(b'a');
+        }
+
+        if valid(password) {
+            break;
+        }
+    }
+}
+
+fn increment(password: &mut [u8]) {
+    for b in password.iter_mut().rev() {
+        if *b == b'z' {
+            *b = b'a';
+        } else {
+            *b += 1;
+            break;
+        }
+    }
+}
+
+fn valid(password: &[u8]) -> bool {
+    let straight = password.windows(3).any(|w| w[0] + 1 == w[1] && w[1] + 1 == w[2]);
+    let forbidden = password.iter().any(|b| matches!(b, b'i' | b'o' | b'l'));
+
+    // Non overlapping pairs must be at least two positions apart.
+    let pairs: Vec<_> =
+        (0..password.len() - 1).filter(|&i| password[i] == password[i + 1]).collect();
+    let two_pairs =
+        pairs.iter().any(|&i| pairs.iter().any(|&j| j >= i + 2 && password[i] != password[j]));
+
+    straight && !forbidden && two_pairs
+}
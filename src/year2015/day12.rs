@@ -0,0 +1,71 @@
+//! # JSAbacusFramework.io
+//!
+//! A minimal recursive descent parser that only understands the subset of JSON used by the
+//! input. Each value returns both totals at once, part two discarding any object containing a
+//! `"red"` property value.
+pub fn parse(input: &str) -> (i32, i32) {
+    let (total, without_red, _) = value(input.as_bytes(), &mut 0);
+    (total, without_red)
+}
+
+pub fn part1(input: &(i32, i32)) -> i32 {
+    input.0
+}
+
+pub fn part2(input: &(i32, i32)) -> i32 {
+    input.1
+}
+
+/// Returns the sum of all numbers, the sum ignoring red objects and whether the value is the
+/// string `"red"`.
+fn value(bytes: &[u8], index: &mut usize) -> (i32, i32, bool) {
+    match bytes[*index] {
+        b'[' | b'{' => {
+            let object = bytes[*index] == b'{';
+            let mut total = 0;
+            let mut without_red = 0;
+            let mut red = false;
+            *index += 1;
+
+            while bytes[*index] != b']' && bytes[*index] != b'}' {
+                if object {
+                    // Skip the key and colon.
+                    value(bytes, index);
+                    *index += 1;
+                }
+
+                let (t, w, r) = value(bytes, index);
+                total += t;
+                without_red += w;
+                red |= object && r;
+
+                if bytes[*index] == b',' {
+                    *index += 1;
+                }
+            }
+
+            *index += 1;
+            (total, if red { 0 } else { without_red }, false)
+        }
+        b'"' => {
+            let start = *index + 1;
+            *index = start + bytes[start..].iter().position(|&b| b == b'"').unwrap() + 1;
+            (0, 0, &bytes[start..*index - 1] == b"red")
+        }
+        _ => {
+            let negative = bytes[*index] == b'-';
+            if negative {
+                *index += 1;
+            }
+
+            let mut n = 0;
+            while *index < bytes.len() && bytes[*index].is_ascii_digit() {
+                n = 10 * n + (bytes[*index] - b'0') as i32;
+                *index += 1;
+            }
+
+            let n = if negative { -n } else { n };
+            (n, n, false)
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! # Knights of the Dinner Table
+//!
+//! Happiness is symmetric per pair so both directions are summed into a single matrix.
+//! Seating is circular, so the first guest is fixed in place and only the remaining guests are
+//! permuted. Adding ourselves for part two is an extra row and column of zeroes.
+use crate::util::hash::*;
+use crate::util::parse::*;
+use crate::util::slice::*;
+
+pub fn parse(input: &str) -> Vec<Vec<i32>> {
+    let mut indices = FastMap::new();
+    let mut pairs = Vec::new();
+
+    for line in input.lines() {
+        let tokens: Vec<_> = line.trim_end_matches('.').split_ascii_whitespace().collect();
+        let mut index = |name| {
+            let size = indices.len();
+            *indices.entry(name).or_insert(size)
+        };
+        let from = index(tokens[0]);
+        let to = index(tokens[10]);
+        let amount = tokens[3].signed::<i32>();
+        pairs.push((from, to, if tokens[2] == "gain" { amount } else { -amount }));
+    }
+
+    let size = indices.len();
+    let mut happiness = vec![vec![0; size]; size];
+    for (from, to, amount) in pairs {
+        happiness[from][to] += amount;
+        happiness[to][from] += amount;
+    }
+
+    happiness
+}
+
+pub fn part1(input: &[Vec<i32>]) -> i32 {
+    optimal(input)
+}
+
+pub fn part2(input: &[Vec<i32>]) -> i32 {
+    let mut happiness = input.to_vec();
+    happiness.iter_mut().for_each(|row| row.push(0));
+    happiness.push(vec![0; input.len() + 1]);
+    optimal(&happiness)
+}
+
+fn optimal(happiness: &[Vec<i32>]) -> i32 {
+    let size = happiness.len();
+    let mut guests: Vec<_> = (1..size).collect();
+    let mut best = i32::MIN;
+
+    guests.permutations(|slice| {
+        let mut total = happiness[0][slice[0]] + happiness[slice[size - 2]][0];
+        total += slice.windows(2).map(|w| happiness[w[0]][w[1]]).sum::<i32>();
+        best = best.max(total);
+    });
+
+    best
+}
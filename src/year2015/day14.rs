@@ -0,0 +1,34 @@
+//! # Reindeer Olympics
+//!
+//! Each reindeer's position after any number of seconds is a closed form calculation of
+//! complete fly/rest cycles followed by any remaining flying time.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+const RACE: u32 = 2503;
+
+pub fn parse(input: &str) -> Vec<[u32; 3]> {
+    input.iter_unsigned().chunk::<3>().collect()
+}
+
+pub fn part1(input: &[[u32; 3]]) -> u32 {
+    input.iter().map(|&reindeer| distance(reindeer, RACE)).max().unwrap()
+}
+
+pub fn part2(input: &[[u32; 3]]) -> u32 {
+    let mut points = vec![0; input.len()];
+    let mut distances = vec![0; input.len()];
+
+    for time in 1..=RACE {
+        input.iter().zip(&mut distances).for_each(|(&reindeer, d)| *d = distance(reindeer, time));
+        let lead = *distances.iter().max().unwrap();
+        distances.iter().zip(&mut points).filter(|(&d, _)| d == lead).for_each(|(_, p)| *p += 1);
+    }
+
+    *points.iter().max().unwrap()
+}
+
+fn distance([speed, fly, rest]: [u32; 3], time: u32) -> u32 {
+    let cycle = fly + rest;
+    speed * (fly * (time / cycle) + fly.min(time % cycle))
+}
@@ -0,0 +1,44 @@
+//! # Science for Hungry People
+//!
+//! Recursively tries every way of splitting 100 teaspoons between the ingredients,
+//! accumulating the property totals as each amount is chosen.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> (i32, i32) {
+    let ingredients: Vec<[i32; 5]> = input.iter_signed().chunk::<5>().collect();
+    let mut best = (0, 0);
+    mix(&ingredients, 100, [0; 5], &mut best);
+    best
+}
+
+pub fn part1(input: &(i32, i32)) -> i32 {
+    input.0
+}
+
+pub fn part2(input: &(i32, i32)) -> i32 {
+    input.1
+}
+
+fn mix(ingredients: &[[i32; 5]], remaining: i32, totals: [i32; 5], best: &mut (i32, i32)) {
+    let add = |amount: i32| {
+        let mut next = totals;
+        next.iter_mut().zip(ingredients[0]).for_each(|(t, p)| *t += amount * p);
+        next
+    };
+
+    // The last ingredient takes all of the remaining teaspoons.
+    if ingredients.len() == 1 {
+        let totals = add(remaining);
+        let score = totals[..4].iter().map(|&t| t.max(0)).product();
+        best.0 = best.0.max(score);
+        if totals[4] == 500 {
+            best.1 = best.1.max(score);
+        }
+        return;
+    }
+
+    for amount in 0..=remaining {
+        mix(&ingredients[1..], remaining - amount, add(amount), best);
+    }
+}
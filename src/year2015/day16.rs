@@ -0,0 +1,56 @@
+//! # Aunt Sue
+//!
+//! Each compound is checked against the MFCSAM reading, treating compounds that a Sue doesn't
+//! list as unknown rather than zero.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<Vec<(&str, u32)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, compounds) = line.split_once(": ").unwrap();
+            compounds
+                .split(", ")
+                .map(|compound| {
+                    let (name, amount) = compound.split_once(": ").unwrap();
+                    (name, amount.unsigned())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Vec<(&str, u32)>]) -> usize {
+    find(input, |_, reading, amount| amount == reading)
+}
+
+pub fn part2(input: &[Vec<(&str, u32)>]) -> usize {
+    find(input, |name, reading, amount| match name {
+        "cats" | "trees" => amount > reading,
+        "pomeranians" | "goldfish" => amount < reading,
+        _ => amount == reading,
+    })
+}
+
+fn find(input: &[Vec<(&str, u32)>], matches: impl Fn(&str, u32, u32) -> bool) -> usize {
+    let position = input.iter().position(|compounds| {
+        compounds.iter().all(|&(name, amount)| matches(name, reading(name), amount))
+    });
+    position.unwrap() + 1
+}
+
+fn reading(name: &str) -> u32 {
+    match name {
+        "children" => 3,
+        "cats" => 7,
+        "samoyeds" => 2,
+        "pomeranians" => 3,
+        "akitas" => 0,
+        "vizslas" => 0,
+        "goldfish" => 5,
+        "trees" => 3,
+        "cars" => 2,
+        "perfumes" => 1,
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,36 @@
+//! # No Such Thing as Too Much
+//!
+//! Dynamic programming where `ways[n][volume]` counts the combinations of exactly `n`
+//! containers that hold `volume` liters. Both parts are then read from the row of totals
+//! at the target volume.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<usize> {
+    let containers: Vec<_> = input.iter_unsigned().collect();
+    combinations(&containers, 150)
+}
+
+pub fn part1(input: &[usize]) -> usize {
+    input.iter().sum()
+}
+
+pub fn part2(input: &[usize]) -> usize {
+    *input.iter().find(|&&ways| ways > 0).unwrap()
+}
+
+/// Number of combinations that exactly fill `target`, indexed by how many containers are used.
+pub fn combinations(containers: &[usize], target: usize) -> Vec<usize> {
+    let size = containers.len();
+    let mut ways = vec![vec![0; target + 1]; size + 1];
+    ways[0][0] = 1;
+
+    for (i, &container) in containers.iter().enumerate() {
+        for n in (0..=i).rev() {
+            for volume in (container..=target).rev() {
+                ways[n + 1][volume] += ways[n][volume - container];
+            }
+        }
+    }
+
+    ways.iter().map(|row| row[target]).collect()
+}
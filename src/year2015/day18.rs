@@ -0,0 +1,58 @@
+//! # Like a GIF For Your Yard
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(input: &Grid<u8>) -> usize {
+    animate(input, 100, false)
+}
+
+pub fn part2(input: &Grid<u8>) -> usize {
+    animate(input, 100, true)
+}
+
+/// Runs `steps` generations returning the number of lights on. If `stuck` is set then the four
+/// corner lights are always on.
+pub fn animate(grid: &Grid<u8>, steps: usize, stuck: bool) -> usize {
+    let corners = [
+        Point::new(0, 0),
+        Point::new(grid.width - 1, 0),
+        Point::new(0, grid.height - 1),
+        Point::new(grid.width - 1, grid.height - 1),
+    ];
+    let stick = |grid: &mut Grid<u8>| {
+        if stuck {
+            corners.iter().for_each(|&corner| grid[corner] = b'#');
+        }
+    };
+
+    let mut current = grid.clone();
+    let mut next = grid.clone();
+    stick(&mut current);
+
+    for _ in 0..steps {
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                let point = Point::new(x, y);
+                let neighbors = DIAGONAL
+                    .iter()
+                    .map(|&d| point + d)
+                    .filter(|&p| current.contains(p) && current[p] == b'#')
+                    .count();
+
+                next[point] = match (current[point], neighbors) {
+                    (b'#', 2 | 3) | (_, 3) => b'#',
+                    _ => b'.',
+                };
+            }
+        }
+
+        stick(&mut next);
+        std::mem::swap(&mut current, &mut next);
+    }
+
+    current.bytes.iter().filter(|&&b| b == b'#').count()
+}
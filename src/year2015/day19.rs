@@ -0,0 +1,35 @@
+//! # Medicine for Rudolph
+//!
+//! Part two relies on the structure of the replacement rules. Every rule either turns one
+//! element into two, or produces `Rn` ... `Ar` with zero, one or two inner elements separated by
+//! `Y`. Each step therefore adds one element, except that `Rn` and `Ar` come for free and each
+//! `Y` brings a second free element along with it. Working backwards from the element count
+//! gives the number of steps without any searching.
+use crate::util::hash::*;
+
+type Input<'a> = (Vec<(&'a str, &'a str)>, &'a str);
+
+pub fn parse(input: &str) -> Input<'_> {
+    let (prefix, suffix) = input.trim().split_once("\n\n").unwrap();
+    let replacements = prefix.lines().map(|line| line.split_once(" => ").unwrap()).collect();
+    (replacements, suffix)
+}
+
+pub fn part1((replacements, molecule): &Input<'_>) -> usize {
+    let mut distinct = FastSet::new();
+
+    for (from, to) in replacements {
+        for (i, _) in molecule.match_indices(from) {
+            distinct.insert(format!("{}{to}{}", &molecule[..i], &molecule[i + from.len()..]));
+        }
+    }
+
+    distinct.len()
+}
+
+pub fn part2((_, molecule): &Input<'_>) -> usize {
+    let elements = molecule.bytes().filter(u8::is_ascii_uppercase).count();
+    let brackets = molecule.matches("Rn").count() + molecule.matches("Ar").count();
+    let separators = molecule.matches('Y').count();
+    elements - brackets - 2 * separators - 1
+}
@@ -0,0 +1,31 @@
+//! # Infinite Elves and Infinite Houses
+//!
+//! A [sieve](https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes) style approach where each elf
+//! adds presents to every multiple of its number. The answer can be no larger than the house
+//! where the target's own elf alone delivers enough presents, which bounds the sieve size.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> u32 {
+    input.trim().unsigned()
+}
+
+pub fn part1(input: &u32) -> usize {
+    deliver(*input, 10, usize::MAX)
+}
+
+pub fn part2(input: &u32) -> usize {
+    deliver(*input, 11, 50)
+}
+
+fn deliver(target: u32, presents: u32, visits: usize) -> usize {
+    let size = (target / presents) as usize + 1;
+    let mut houses = vec![0; size];
+
+    for elf in 1..size {
+        for house in (elf..size).step_by(elf).take(visits) {
+            houses[house] += presents * elf as u32;
+        }
+    }
+
+    houses.iter().position(|&total| total >= target).unwrap()
+}
@@ -0,0 +1,52 @@
+//! # RPG Simulator 20XX
+//!
+//! There are only 660 possible combinations of equipment, so each is simply tried in turn.
+//! The fight itself doesn't need simulating. The winner is whoever needs the fewest rounds to
+//! defeat their opponent, with ties going to the player who strikes first.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+/// Cost, damage and armor of each item, including empty armor and ring slots.
+const WEAPONS: [[u32; 3]; 5] = [[8, 4, 0], [10, 5, 0], [25, 6, 0], [40, 7, 0], [74, 8, 0]];
+const ARMOR: [[u32; 3]; 6] =
+    [[0, 0, 0], [13, 0, 1], [31, 0, 2], [53, 0, 3], [75, 0, 4], [102, 0, 5]];
+const RINGS: [[u32; 3]; 6] =
+    [[25, 1, 0], [50, 2, 0], [100, 3, 0], [20, 0, 1], [40, 0, 2], [80, 0, 3]];
+
+pub fn parse(input: &str) -> Vec<(u32, bool)> {
+    let [hit_points, damage, armor] = input.iter_unsigned::<u32>().chunk::<3>().next().unwrap();
+
+    // Zero, one or two different rings.
+    let mut rings = vec![[0, 0, 0]];
+    for (i, a) in RINGS.iter().enumerate() {
+        rings.push(*a);
+        for b in &RINGS[i + 1..] {
+            rings.push([a[0] + b[0], a[1] + b[1], a[2] + b[2]]);
+        }
+    }
+
+    let mut outcomes = Vec::new();
+    for weapon in WEAPONS {
+        for armor_piece in ARMOR {
+            for ring in &rings {
+                let cost = weapon[0] + armor_piece[0] + ring[0];
+                let attack = weapon[1] + ring[1];
+                let defense = armor_piece[2] + ring[2];
+
+                let player_turns = hit_points.div_ceil(attack.saturating_sub(armor).max(1));
+                let boss_turns = 100_u32.div_ceil(damage.saturating_sub(defense).max(1));
+                outcomes.push((cost, player_turns <= boss_turns));
+            }
+        }
+    }
+
+    outcomes
+}
+
+pub fn part1(input: &[(u32, bool)]) -> u32 {
+    input.iter().filter(|(_, win)| *win).map(|(cost, _)| *cost).min().unwrap()
+}
+
+pub fn part2(input: &[(u32, bool)]) -> u32 {
+    input.iter().filter(|(_, win)| !*win).map(|(cost, _)| *cost).max().unwrap()
+}
@@ -0,0 +1,133 @@
+//! # Wizard Simulator 20XX
+//!
+//! [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm) using mana
+//! spent as the cost. Each state is one full round, the player's turn followed by the boss's
+//! turn, so that effects are applied consistently at the start of every turn.
+use crate::util::hash::*;
+use crate::util::heap::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    player_hp: i32,
+    player_mana: i32,
+    boss_hp: i32,
+    shield: u8,
+    poison: u8,
+    recharge: u8,
+}
+
+pub fn parse(input: &str) -> [i32; 2] {
+    input.iter_signed().chunk::<2>().next().unwrap()
+}
+
+pub fn part1(input: &[i32; 2]) -> i32 {
+    play(*input, 50, 500, false)
+}
+
+pub fn part2(input: &[i32; 2]) -> i32 {
+    play(*input, 50, 500, true)
+}
+
+/// Least mana needed to win against a boss with `[hit points, damage]`. In `hard` mode the
+/// player loses one hit point at the start of each of their turns.
+pub fn play([boss_hp, boss_damage]: [i32; 2], player_hp: i32, player_mana: i32, hard: bool) -> i32 {
+    let start = State { player_hp, player_mana, boss_hp, shield: 0, poison: 0, recharge: 0 };
+    let mut todo = MinHeap::new();
+    let mut seen = FastSet::new();
+    todo.push(0, start);
+
+    while let Some((spent, mut state)) = todo.pop() {
+        if state.boss_hp <= 0 {
+            return spent;
+        }
+        if !seen.insert(state) {
+            continue;
+        }
+
+        // Player's turn.
+        if hard {
+            state.player_hp -= 1;
+            if state.player_hp <= 0 {
+                continue;
+            }
+        }
+        apply_effects(&mut state);
+        if state.boss_hp <= 0 {
+            return spent;
+        }
+
+        for spell in 0..5 {
+            let Some((cost, mut next)) = cast(state, spell) else {
+                continue;
+            };
+
+            // Boss's turn.
+            apply_effects(&mut next);
+            if next.boss_hp <= 0 {
+                todo.push(spent + cost, next);
+                continue;
+            }
+
+            let armor = if next.shield > 0 { 7 } else { 0 };
+            next.player_hp -= (boss_damage - armor).max(1);
+
+            if next.player_hp > 0 {
+                todo.push(spent + cost, next);
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+fn apply_effects(state: &mut State) {
+    if state.shield > 0 {
+        state.shield -= 1;
+    }
+    if state.poison > 0 {
+        state.poison -= 1;
+        state.boss_hp -= 3;
+    }
+    if state.recharge > 0 {
+        state.recharge -= 1;
+        state.player_mana += 101;
+    }
+}
+
+/// Casts a spell, returning its cost and the resulting state, or `None` if the spell can't be
+/// afforded or its effect is still active.
+fn cast(state: State, spell: usize) -> Option<(i32, State)> {
+    let mut next = state;
+
+    let cost = match spell {
+        0 => {
+            next.boss_hp -= 4;
+            53
+        }
+        1 => {
+            next.boss_hp -= 2;
+            next.player_hp += 2;
+            73
+        }
+        2 if state.shield == 0 => {
+            next.shield = 6;
+            113
+        }
+        3 if state.poison == 0 => {
+            next.poison = 6;
+            173
+        }
+        4 if state.recharge == 0 => {
+            next.recharge = 5;
+            229
+        }
+        _ => return None,
+    };
+
+    (cost <= state.player_mana).then(|| {
+        next.player_mana -= cost;
+        (cost, next)
+    })
+}
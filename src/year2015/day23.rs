@@ -0,0 +1,69 @@
+//! # Opening the Turing Lock
+use crate::util::parse::*;
+
+pub enum Instruction {
+    Half(usize),
+    Triple(usize),
+    Increment(usize),
+    Jump(i32),
+    JumpIfEven(usize, i32),
+    JumpIfOne(usize, i32),
+}
+
+pub fn parse(input: &str) -> Vec<Instruction> {
+    input
+        .lines()
+        .map(|line| {
+            let register = if line.as_bytes()[4] == b'a' { 0 } else { 1 };
+            let offset = || line.signed();
+
+            match &line[..3] {
+                "hlf" => Instruction::Half(register),
+                "tpl" => Instruction::Triple(register),
+                "inc" => Instruction::Increment(register),
+                "jmp" => Instruction::Jump(offset()),
+                "jie" => Instruction::JumpIfEven(register, offset()),
+                "jio" => Instruction::JumpIfOne(register, offset()),
+                _ => unreachable!(),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Instruction]) -> u64 {
+    execute(input, 0)
+}
+
+pub fn part2(input: &[Instruction]) -> u64 {
+    execute(input, 1)
+}
+
+fn execute(program: &[Instruction], a: u64) -> u64 {
+    let mut registers = [a, 0];
+    let mut pc = 0;
+
+    while pc < program.len() as i32 {
+        let mut next = pc + 1;
+
+        match program[pc as usize] {
+            Instruction::Half(r) => registers[r] /= 2,
+            Instruction::Triple(r) => registers[r] *= 3,
+            Instruction::Increment(r) => registers[r] += 1,
+            Instruction::Jump(offset) => next = pc + offset,
+            Instruction::JumpIfEven(r, offset) => {
+                if registers[r].is_multiple_of(2) {
+                    next = pc + offset;
+                }
+            }
+            Instruction::JumpIfOne(r, offset) => {
+                if registers[r] == 1 {
+                    next = pc + offset;
+                }
+            }
+        }
+
+        pc = next;
+    }
+
+    registers[1]
+}
@@ -0,0 +1,50 @@
+//! # It Hangs in the Balance
+//!
+//! Tries every group of packages in order of increasing size, stopping at the first size that
+//! has any group reaching the target weight. The packages are sorted largest first so that
+//! the search can abandon a branch as soon as the weight overshoots.
+//!
+//! This assumes (as holds for the real input) that the remaining packages can always be split
+//! into equal groups.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<u64> {
+    let mut packages: Vec<u64> = input.iter_unsigned().collect();
+    packages.sort_unstable_by(|a, b| b.cmp(a));
+    packages
+}
+
+pub fn part1(input: &[u64]) -> u64 {
+    balance(input, 3)
+}
+
+pub fn part2(input: &[u64]) -> u64 {
+    balance(input, 4)
+}
+
+fn balance(packages: &[u64], groups: u64) -> u64 {
+    let target = packages.iter().sum::<u64>() / groups;
+
+    (1..=packages.len())
+        .find_map(|size| {
+            let mut best = None;
+            search(packages, size, target, 1, &mut best);
+            best
+        })
+        .unwrap()
+}
+
+fn search(packages: &[u64], size: usize, remaining: u64, product: u64, best: &mut Option<u64>) {
+    if size == 0 {
+        if remaining == 0 {
+            *best = Some(best.map_or(product, |b| b.min(product)));
+        }
+        return;
+    }
+
+    for (i, &weight) in packages.iter().enumerate() {
+        if weight <= remaining {
+            search(&packages[i + 1..], size - 1, remaining - weight, product * weight, best);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! # Let It Snow
+//!
+//! Codes are filled in diagonals, so the position of a code in the sequence is a triangular
+//! number plus its column. Each code is the previous multiplied by `252533`, therefore the
+//! `n`th code can be calculated directly using [modular exponentiation].
+//!
+//! [modular exponentiation]: crate::util::math::IntegerMathOps::mod_pow
+use crate::util::iter::*;
+use crate::util::math::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> [u64; 2] {
+    input.iter_unsigned().chunk::<2>().next().unwrap()
+}
+
+pub fn part1(&[row, column]: &[u64; 2]) -> u64 {
+    let diagonal = row + column - 1;
+    let index = diagonal * (diagonal - 1) / 2 + column - 1;
+    (20151125 * 252533_u64.mod_pow(index, 33554393)) % 33554393
+}
@@ -0,0 +1,49 @@
+//! # No Time for a Taxicab
+//!
+//! Part two walks one block at a time, remembering every location visited, until one is seen
+//! for a second time.
+use crate::util::hash::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Vec<(u8, i32)> {
+    input.split(", ").map(|step| (step.as_bytes()[0], step.trim().signed())).collect()
+}
+
+pub fn part1(input: &[(u8, i32)]) -> i32 {
+    let mut position = ORIGIN;
+    let mut direction = UP;
+
+    for &(turn, blocks) in input {
+        direction = rotate(direction, turn);
+        position += direction * blocks;
+    }
+
+    position.manhattan(ORIGIN)
+}
+
+pub fn part2(input: &[(u8, i32)]) -> i32 {
+    let mut position = ORIGIN;
+    let mut direction = UP;
+    let mut seen = FastSet::build([ORIGIN]);
+
+    for &(turn, blocks) in input {
+        direction = rotate(direction, turn);
+        for _ in 0..blocks {
+            position += direction;
+            if !seen.insert(position) {
+                return position.manhattan(ORIGIN);
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+fn rotate(direction: Point, turn: u8) -> Point {
+    if turn == b'L' {
+        direction.counter_clockwise()
+    } else {
+        direction.clockwise()
+    }
+}
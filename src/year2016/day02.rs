@@ -0,0 +1,38 @@
+//! # Bathroom Security
+//!
+//! Both keypads are stored as a grid surrounded by a border of spaces. A move is only made if
+//! the destination is a key, so the border stops the finger leaving the keypad.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(input: &[&str]) -> String {
+    let keypad = "     \n 123 \n 456 \n 789 \n     ";
+    code(input, keypad)
+}
+
+pub fn part2(input: &[&str]) -> String {
+    let keypad = "       \n   1   \n  234  \n 56789 \n  ABC  \n   D   \n       ";
+    code(input, keypad)
+}
+
+fn code(input: &[&str], keypad: &str) -> String {
+    let grid = Grid::parse(keypad);
+    let mut position = grid.find(b'5').unwrap();
+
+    input
+        .iter()
+        .map(|line| {
+            for b in line.bytes() {
+                let next = position + Point::from(b);
+                if grid[next] != b' ' {
+                    position = next;
+                }
+            }
+            grid[position] as char
+        })
+        .collect()
+}
@@ -0,0 +1,29 @@
+//! # Squares With Three Sides
+//!
+//! A triangle is valid when the two shorter sides add up to more than the longest side.
+//! For part two each group of three rows is transposed before checking.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<[u32; 3]> {
+    input.iter_unsigned().chunk::<3>().collect()
+}
+
+pub fn part1(input: &[[u32; 3]]) -> usize {
+    input.iter().filter(|&&sides| valid(sides)).count()
+}
+
+pub fn part2(input: &[[u32; 3]]) -> usize {
+    input
+        .chunks_exact(3)
+        .flat_map(|rows| {
+            (0..3).map(move |column| [rows[0][column], rows[1][column], rows[2][column]])
+        })
+        .filter(|&sides| valid(sides))
+        .count()
+}
+
+fn valid(mut sides: [u32; 3]) -> bool {
+    sides.sort_unstable();
+    sides[0] + sides[1] > sides[2]
+}
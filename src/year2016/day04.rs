@@ -0,0 +1,49 @@
+//! # Security Through Obscurity
+//!
+//! Letters are counted in a fixed size array. Sorting the letters by descending count then
+//! alphabetically gives the expected checksum. Only real rooms are kept.
+use crate::util::parse::*;
+
+pub struct Room<'a> {
+    name: &'a str,
+    sector: u32,
+}
+
+pub fn parse(input: &str) -> Vec<Room<'_>> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let (rest, checksum) = line.trim_end_matches(']').split_once('[').unwrap();
+            let (name, sector) = rest.rsplit_once('-').unwrap();
+
+            let mut counts = [0; 26];
+            name.bytes()
+                .filter(u8::is_ascii_lowercase)
+                .for_each(|b| counts[(b - b'a') as usize] += 1);
+
+            let mut letters: Vec<_> = (0..26).collect();
+            letters.sort_by_key(|&i| std::cmp::Reverse(counts[i]));
+            let expected: String = letters[..5].iter().map(|&i| (b'a' + i as u8) as char).collect();
+
+            (expected == checksum).then(|| Room { name, sector: sector.unsigned() })
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Room<'_>]) -> u32 {
+    input.iter().map(|room| room.sector).sum()
+}
+
+pub fn part2(input: &[Room<'_>]) -> u32 {
+    input.iter().find(|room| decrypt(room.name, room.sector).contains("northpole")).unwrap().sector
+}
+
+/// Rotates each letter forward through the alphabet `sector` times, dashes become spaces.
+pub fn decrypt(name: &str, sector: u32) -> String {
+    name.bytes()
+        .map(|b| match b {
+            b'-' => ' ',
+            _ => (b'a' + ((b - b'a') as u32 + sector % 26) as u8 % 26) as char,
+        })
+        .collect()
+}
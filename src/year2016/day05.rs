@@ -0,0 +1,104 @@
+//! # How About a Nice Game of Chess?
+//!
+//! Brute force search split across threads, collecting every hash that starts with five
+//! zeroes. Threads claim batches of indices in increasing order from a shared atomic counter,
+//! and stop once the hashes found so far are enough to fill both passwords. Every claimed
+//! batch runs to completion, so the hashes found cover every index below the last batch and
+//! the first matches are the correct ones.
+use crate::util::md5::*;
+use crate::util::thread::*;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+
+const BATCH: u32 = 1000;
+
+/// Index and first 32 bits of every matching hash, in increasing order of index.
+pub fn parse(input: &str) -> Vec<(u32, u32)> {
+    let door = input.trim();
+    let done = AtomicBool::new(false);
+    let counter = AtomicU32::new(0);
+    let found = Mutex::new(Vec::new());
+
+    spawn(|| {
+        while !done.load(Ordering::Relaxed) {
+            let start = counter.fetch_add(BATCH, Ordering::Relaxed);
+            let matches = check_batch(door, start);
+
+            if !matches.is_empty() {
+                let mut found = found.lock().unwrap();
+                found.extend(matches);
+                if complete(&found) {
+                    done.store(true, Ordering::Relaxed);
+                }
+            }
+        }
+    });
+
+    let mut found = found.into_inner().unwrap();
+    found.sort_unstable();
+    found
+}
+
+pub fn part1(input: &[(u32, u32)]) -> String {
+    input.iter().take(8).map(|&(_, hash)| hex(hash >> 8)).collect()
+}
+
+pub fn part2(input: &[(u32, u32)]) -> String {
+    let mut password = [None; 8];
+
+    for &(_, hash) in input {
+        let position = (hash >> 8) as usize;
+        if position < 8 && password[position].is_none() {
+            password[position] = Some(hex(hash >> 4));
+        }
+    }
+
+    password.iter().map(|c| c.unwrap()).collect()
+}
+
+/// Hex digit of the lowest 4 bits.
+fn hex(n: u32) -> char {
+    char::from_digit(n & 0xf, 16).unwrap()
+}
+
+/// Enough hashes for the first password and every position of the second.
+fn complete(found: &[(u32, u32)]) -> bool {
+    let positions = found.iter().map(|&(_, hash)| (hash >> 8) & 0xf).filter(|&p| p < 8);
+    found.len() >= 8 && positions.fold(0, |mask, p| mask | (1 << p)) == 0xff
+}
+
+/// Numbers in a batch (apart from the first) share every digit except the last three, so the
+/// buffer is filled once and only the final digits change for each hash.
+fn check_batch(door: &str, start: u32) -> Vec<(u32, u32)> {
+    let mut result = Vec::new();
+    let mut check = |buffer: &mut [u8], size, n| {
+        let (a, ..) = hash(buffer, size);
+        if a & 0xfffff000 == 0 {
+            result.push((n, a));
+        }
+    };
+
+    if start == 0 {
+        for n in 0..BATCH {
+            let text = format!("{door}{n}");
+            let mut buffer = vec![0; buffer_size(text.len())];
+            buffer[..text.len()].copy_from_slice(text.as_bytes());
+            check(&mut buffer, text.len(), n);
+        }
+        return result;
+    }
+
+    let prefix = format!("{door}{}", start / BATCH);
+    let size = prefix.len() + 3;
+    let mut buffer = vec![0; buffer_size(size)];
+    buffer[..prefix.len()].copy_from_slice(prefix.as_bytes());
+
+    for n in 0..BATCH {
+        buffer[size - 3] = b'0' + (n / 100) as u8;
+        buffer[size - 2] = b'0' + (n / 10 % 10) as u8;
+        buffer[size - 1] = b'0' + (n % 10) as u8;
+        check(&mut buffer, size, start + n);
+    }
+
+    result
+}
@@ -0,0 +1,27 @@
+//! # Signals and Noise
+//!
+//! Counts the frequency of each letter in each column, then picks the most and least common.
+pub fn parse(input: &str) -> Vec<[u32; 26]> {
+    let width = input.lines().next().unwrap().len();
+    let mut counts = vec![[0; 26]; width];
+
+    for line in input.lines() {
+        for (column, b) in line.bytes().enumerate() {
+            counts[column][(b - b'a') as usize] += 1;
+        }
+    }
+
+    counts
+}
+
+pub fn part1(input: &[[u32; 26]]) -> String {
+    message(input, |counts| (0..26).max_by_key(|&i| counts[i]))
+}
+
+pub fn part2(input: &[[u32; 26]]) -> String {
+    message(input, |counts| (0..26).filter(|&i| counts[i] > 0).min_by_key(|&i| counts[i]))
+}
+
+fn message(input: &[[u32; 26]], pick: impl Fn(&[u32; 26]) -> Option<usize>) -> String {
+    input.iter().map(|counts| (b'a' + pick(counts).unwrap() as u8) as char).collect()
+}
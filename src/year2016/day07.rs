@@ -0,0 +1,74 @@
+//! # Internet Protocol Version 7
+//!
+//! Scans each address once, tracking whether the current position is inside square brackets.
+//! For part two every `ABA` outside brackets and `BAB` inside is recorded in a table indexed
+//! by the pair of letters, so a match is any pair present in both tables.
+pub fn parse(input: &str) -> Vec<&[u8]> {
+    input.lines().map(str::as_bytes).collect()
+}
+
+pub fn part1(input: &[&[u8]]) -> usize {
+    input
+        .iter()
+        .filter(|address| {
+            let mut outside = false;
+            let mut inside = false;
+            let mut brackets = false;
+
+            for (i, window) in address.windows(4).enumerate() {
+                match address[i] {
+                    b'[' => brackets = true,
+                    b']' => brackets = false,
+                    _ => (),
+                }
+                if let [a, b, c, d] = *window {
+                    if a == d
+                        && b == c
+                        && a != b
+                        && b.is_ascii_lowercase()
+                        && a.is_ascii_lowercase()
+                    {
+                        if brackets {
+                            inside = true
+                        } else {
+                            outside = true
+                        }
+                    }
+                }
+            }
+
+            outside && !inside
+        })
+        .count()
+}
+
+pub fn part2(input: &[&[u8]]) -> usize {
+    input
+        .iter()
+        .filter(|address| {
+            let mut aba = vec![false; 26 * 26];
+            let mut bab = vec![false; 26 * 26];
+            let mut brackets = false;
+
+            for (i, window) in address.windows(3).enumerate() {
+                match address[i] {
+                    b'[' => brackets = true,
+                    b']' => brackets = false,
+                    _ => (),
+                }
+                if let [a, b, c] = *window {
+                    if a == c && a != b && a.is_ascii_lowercase() && b.is_ascii_lowercase() {
+                        let (a, b) = ((a - b'a') as usize, (b - b'a') as usize);
+                        if brackets {
+                            bab[26 * b + a] = true;
+                        } else {
+                            aba[26 * a + b] = true;
+                        }
+                    }
+                }
+            }
+
+            aba.iter().zip(&bab).any(|(&x, &y)| x && y)
+        })
+        .count()
+}
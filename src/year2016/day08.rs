@@ -0,0 +1,48 @@
+//! # Two-Factor Authentication
+//!
+//! Simulates the screen directly. Rotating a row uses the built-in [`rotate_right`], while a
+//! column is copied out, rotated then copied back.
+//!
+//! [`rotate_right`]: slice::rotate_right
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<Vec<bool>> {
+    screen(input, 50, 6)
+}
+
+pub fn part1(input: &[Vec<bool>]) -> usize {
+    input.iter().flatten().filter(|&&lit| lit).count()
+}
+
+pub fn part2(input: &[Vec<bool>]) -> String {
+    let mut result = String::new();
+    for row in input {
+        result.push('\n');
+        result.extend(row.iter().map(|&lit| if lit { '#' } else { '.' }));
+    }
+    result
+}
+
+/// Pixels of a `width` by `height` screen after following every instruction.
+pub fn screen(input: &str, width: usize, height: usize) -> Vec<Vec<bool>> {
+    let mut screen = vec![vec![false; width]; height];
+
+    for line in input.lines() {
+        let [a, b] = {
+            let mut numbers = line.iter_unsigned::<usize>();
+            [numbers.next().unwrap(), numbers.next().unwrap()]
+        };
+
+        if line.starts_with("rect") {
+            screen.iter_mut().take(b).for_each(|row| row[..a].fill(true));
+        } else if line.starts_with("rotate row") {
+            screen[a].rotate_right(b % width);
+        } else {
+            let mut column: Vec<_> = screen.iter().map(|row| row[a]).collect();
+            column.rotate_right(b % height);
+            screen.iter_mut().zip(column).for_each(|(row, lit)| row[a] = lit);
+        }
+    }
+
+    screen
+}
@@ -0,0 +1,36 @@
+//! # Explosives in Cyberspace
+//!
+//! Only the length is needed so nothing is actually decompressed. In part two the length of a
+//! marker's data is found recursively, then multiplied by the repeat count.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> &[u8] {
+    input.trim().as_bytes()
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    length(input, false)
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    length(input, true)
+}
+
+fn length(mut data: &[u8], recursive: bool) -> usize {
+    let mut total = 0;
+
+    while let Some(start) = data.iter().position(|&b| b == b'(') {
+        let end = start + data[start..].iter().position(|&b| b == b')').unwrap();
+        let marker = std::str::from_utf8(&data[start + 1..end]).unwrap();
+        let (size, times) = marker.split_once('x').unwrap();
+        let (size, times): (usize, usize) = (size.unsigned(), times.unsigned());
+
+        let repeated = &data[end + 1..end + 1 + size];
+        let inner = if recursive { length(repeated, true) } else { size };
+
+        total += start + inner * times;
+        data = &data[end + 1 + size..];
+    }
+
+    total + data.len()
+}
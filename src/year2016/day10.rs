@@ -0,0 +1,93 @@
+//! # Balance Bots
+//!
+//! Bots are processed with a work queue. A bot is added to the queue as soon as it holds two
+//! chips, then passing on its chips may in turn add the recipients.
+use crate::util::parse::*;
+use std::collections::VecDeque;
+
+pub struct Input {
+    /// Every comparison as `(bot, low, high)`.
+    comparisons: Vec<(usize, u32, u32)>,
+    outputs: Vec<u32>,
+}
+
+#[derive(Clone, Copy)]
+enum Target {
+    Bot(usize),
+    Output(usize),
+}
+
+pub fn parse(input: &str) -> Input {
+    let target = |kind: &str, index: &str| match kind {
+        "bot" => Target::Bot(index.unsigned()),
+        _ => Target::Output(index.unsigned()),
+    };
+
+    let mut chips: Vec<Vec<u32>> = Vec::new();
+    let mut rules = Vec::new();
+    let mut outputs = Vec::new();
+
+    for line in input.lines() {
+        let tokens: Vec<_> = line.split_ascii_whitespace().collect();
+        match tokens[..] {
+            ["value", value, _, _, _, bot] => {
+                let bot: usize = bot.unsigned();
+                if chips.len() <= bot {
+                    chips.resize(bot + 1, Vec::new());
+                }
+                chips[bot].push(value.unsigned());
+            }
+            [_, bot, _, _, _, low_kind, low, _, _, _, high_kind, high] => {
+                let bot: usize = bot.unsigned();
+                if rules.len() <= bot {
+                    rules.resize(bot + 1, None);
+                }
+                rules[bot] = Some((target(low_kind, low), target(high_kind, high)));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    chips.resize(rules.len().max(chips.len()), Vec::new());
+    let mut todo: VecDeque<_> = (0..chips.len()).filter(|&bot| chips[bot].len() == 2).collect();
+    let mut comparisons = Vec::new();
+
+    while let Some(bot) = todo.pop_front() {
+        let (low, high) = (chips[bot][0].min(chips[bot][1]), chips[bot][0].max(chips[bot][1]));
+        chips[bot].clear();
+        comparisons.push((bot, low, high));
+
+        let (low_target, high_target) = rules[bot].unwrap();
+        for (target, value) in [(low_target, low), (high_target, high)] {
+            match target {
+                Target::Bot(next) => {
+                    chips[next].push(value);
+                    if chips[next].len() == 2 {
+                        todo.push_back(next);
+                    }
+                }
+                Target::Output(index) => {
+                    if outputs.len() <= index {
+                        outputs.resize(index + 1, 0);
+                    }
+                    outputs[index] = value;
+                }
+            }
+        }
+    }
+
+    Input { comparisons, outputs }
+}
+
+pub fn part1(input: &Input) -> usize {
+    compares(input, 17, 61)
+}
+
+pub fn part2(input: &Input) -> u32 {
+    input.outputs[..3].iter().product()
+}
+
+/// The bot responsible for comparing the chips `low` and `high`.
+pub fn compares(input: &Input, low: u32, high: u32) -> usize {
+    input.comparisons.iter().find(|&&(_, l, h)| (l, h) == (low, high)).unwrap().0
+}
@@ -0,0 +1,129 @@
+//! # Radioisotope Thermoelectric Generators
+//!
+//! [Breadth first search] over every arrangement of items. The key optimization is that pairs
+//! of a generator and its microchip are interchangeable, so a state only needs the sorted list
+//! of `(generator floor, microchip floor)` pairs and the elevator's floor. Each floor fits in
+//! two bits, so a state is packed into a single integer.
+//!
+//! [Breadth first search]: https://en.wikipedia.org/wiki/Breadth-first_search
+use crate::util::hash::*;
+use std::collections::VecDeque;
+
+pub fn parse(input: &str) -> Vec<(u8, u8)> {
+    let mut generators = FastMap::new();
+    let mut microchips = FastMap::new();
+
+    for (floor, line) in input.lines().enumerate() {
+        let words: Vec<_> = line.split(|c: char| !c.is_ascii_alphanumeric() && c != '-').collect();
+
+        for pair in words.windows(2) {
+            if pair[1] == "generator" {
+                generators.insert(pair[0], floor as u8);
+            } else if pair[1] == "microchip" {
+                microchips.insert(pair[0].trim_end_matches("-compatible"), floor as u8);
+            }
+        }
+    }
+
+    generators.iter().map(|(element, &floor)| (floor, microchips[element])).collect()
+}
+
+pub fn part1(input: &[(u8, u8)]) -> u32 {
+    bfs(input)
+}
+
+pub fn part2(input: &[(u8, u8)]) -> u32 {
+    let mut pairs = input.to_vec();
+    pairs.extend([(0, 0), (0, 0)]);
+    bfs(&pairs)
+}
+
+fn bfs(pairs: &[(u8, u8)]) -> u32 {
+    let start = pack(0, pairs.to_vec());
+    let end = pack(3, vec![(3, 3); pairs.len()]);
+
+    let mut todo = VecDeque::from([(start, 0)]);
+    let mut seen = FastSet::build([start]);
+
+    while let Some((state, steps)) = todo.pop_front() {
+        if state == end {
+            return steps;
+        }
+
+        let (elevator, pairs) = unpack(state, pairs.len());
+        // Items are numbered with generators as even and microchips as odd.
+        let items: Vec<_> =
+            (0..2 * pairs.len()).filter(|&i| floor(&pairs, i) == elevator).collect();
+        let lowest = pairs.iter().map(|&(g, m)| g.min(m)).min().unwrap();
+
+        for next in [elevator.wrapping_sub(1), elevator + 1] {
+            // Never move below the lowest floor that has any items.
+            if next > 3 || next < lowest {
+                continue;
+            }
+
+            for (i, &first) in items.iter().enumerate() {
+                for second in items[i..].iter().map(Some).chain([None]) {
+                    let mut moved = pairs.clone();
+                    set(&mut moved, first, next);
+                    if let Some(&second) = second {
+                        if second == first {
+                            continue;
+                        }
+                        set(&mut moved, second, next);
+                    }
+
+                    if safe(&moved) {
+                        let state = pack(next, moved);
+                        if seen.insert(state) {
+                            todo.push_back((state, steps + 1));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+fn floor(pairs: &[(u8, u8)], item: usize) -> u8 {
+    let (generator, microchip) = pairs[item / 2];
+    if item.is_multiple_of(2) {
+        generator
+    } else {
+        microchip
+    }
+}
+
+fn set(pairs: &mut [(u8, u8)], item: usize, floor: u8) {
+    let pair = &mut pairs[item / 2];
+    if item.is_multiple_of(2) {
+        pair.0 = floor;
+    } else {
+        pair.1 = floor;
+    }
+}
+
+/// A microchip is fried if it's on a floor with another generator but not its own.
+fn safe(pairs: &[(u8, u8)]) -> bool {
+    pairs.iter().all(|&(generator, microchip)| {
+        generator == microchip || pairs.iter().all(|&(other, _)| other != microchip)
+    })
+}
+
+fn pack(elevator: u8, mut pairs: Vec<(u8, u8)>) -> u64 {
+    pairs.sort_unstable();
+    pairs.iter().fold(elevator as u64, |acc, &(g, m)| (acc << 4) | ((g as u64) << 2) | m as u64)
+}
+
+fn unpack(state: u64, size: usize) -> (u8, Vec<(u8, u8)>) {
+    let pairs = (0..size)
+        .rev()
+        .map(|i| {
+            let bits = (state >> (4 * i)) & 0xf;
+            ((bits >> 2) as u8, (bits & 3) as u8)
+        })
+        .collect();
+    (((state >> (4 * size)) & 3) as u8, pairs)
+}
@@ -0,0 +1,26 @@
+//! # Leonardo's Monorail
+//!
+//! Runs the program on the shared [assembunny interpreter], which turns the addition loops
+//! that compute Fibonacci numbers into single steps.
+//!
+//! [assembunny interpreter]: crate::util::assembunny
+use crate::util::assembunny::*;
+
+pub fn parse(input: &str) -> Computer {
+    Computer::parse(input)
+}
+
+pub fn part1(input: &Computer) -> i64 {
+    execute(input, 0)
+}
+
+pub fn part2(input: &Computer) -> i64 {
+    execute(input, 1)
+}
+
+fn execute(input: &Computer, c: i64) -> i64 {
+    let mut computer = input.clone();
+    computer.registers[2] = c;
+    computer.run();
+    computer.registers[0]
+}
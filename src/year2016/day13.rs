@@ -0,0 +1,58 @@
+//! # A Maze of Twisty Little Cubicles
+//!
+//! [Breadth first search] computing walls on the fly from the office designer's favorite
+//! number.
+//!
+//! [Breadth first search]: https://en.wikipedia.org/wiki/Breadth-first_search
+use crate::util::hash::*;
+use crate::util::parse::*;
+use std::collections::VecDeque;
+
+pub fn parse(input: &str) -> u32 {
+    input.unsigned()
+}
+
+pub fn part1(input: &u32) -> u32 {
+    steps(input, (31, 39))
+}
+
+pub fn part2(input: &u32) -> usize {
+    let mut count = 0;
+    bfs(*input, |_, steps| {
+        count += (steps <= 50) as usize;
+        steps > 50
+    });
+    count
+}
+
+/// Fewest steps from `(1, 1)` to `target`.
+pub fn steps(input: &u32, target: (u32, u32)) -> u32 {
+    let mut result = 0;
+    bfs(*input, |point, steps| {
+        result = steps;
+        point == target
+    });
+    result
+}
+
+/// Visits each open location in order of distance until `stop` returns `true`.
+fn bfs(favorite: u32, mut stop: impl FnMut((u32, u32), u32) -> bool) {
+    let open = |x: u32, y: u32| {
+        (x * x + 3 * x + 2 * x * y + y + y * y + favorite).count_ones().is_multiple_of(2)
+    };
+    let mut todo = VecDeque::from([((1, 1), 0)]);
+    let mut seen = FastSet::build([(1, 1)]);
+
+    while let Some(((x, y), steps)) = todo.pop_front() {
+        if stop((x, y), steps) {
+            return;
+        }
+
+        let neighbors = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+        for (nx, ny) in neighbors {
+            if nx != u32::MAX && ny != u32::MAX && open(nx, ny) && seen.insert((nx, ny)) {
+                todo.push_back(((nx, ny), steps + 1));
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! # One-Time Pad
+//!
+//! Hashes are generated in blocks of indices spread across threads, as each needs to be checked
+//! against the following thousand hashes anyway. Each hash is reduced to the digit of its first
+//! triple (if any) and a bitmask of the digits that appear five times in a row, after which
+//! finding keys is cheap.
+//!
+//! Key stretching in part two repeatedly hashes the lowercase hex representation, which is
+//! written straight into a reused buffer to avoid allocating.
+use crate::util::md5::*;
+use crate::util::thread::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+const BLOCK: usize = 8000;
+const BATCH: usize = 100;
+
+pub fn parse(input: &str) -> &str {
+    input.trim()
+}
+
+pub fn part1(input: &str) -> usize {
+    keys(input, 0)
+}
+
+pub fn part2(input: &str) -> usize {
+    keys(input, 2016)
+}
+
+/// Index that produces the 64th key.
+fn keys(salt: &str, stretch: usize) -> usize {
+    let mut hashes = Vec::new();
+    let mut found = 0;
+
+    for index in 0.. {
+        while hashes.len() <= index + 1000 {
+            extend(&mut hashes, salt, stretch);
+        }
+
+        if let Some(digit) = hashes[index].0 {
+            if hashes[index + 1..=index + 1000].iter().any(|&(_, fives)| fives & (1 << digit) != 0)
+            {
+                found += 1;
+                if found == 64 {
+                    return index;
+                }
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+/// Hashes the next block of indices in parallel.
+fn extend(hashes: &mut Vec<(Option<u8>, u16)>, salt: &str, stretch: usize) {
+    let start = hashes.len();
+    let counter = AtomicUsize::new(start);
+    let results = Mutex::new(Vec::with_capacity(BLOCK));
+
+    spawn(|| loop {
+        let first = counter.fetch_add(BATCH, Ordering::Relaxed);
+        if first >= start + BLOCK {
+            break;
+        }
+
+        let batch: Vec<_> =
+            (first..first + BATCH).map(|index| (index, features(salt, index, stretch))).collect();
+        results.lock().unwrap().extend(batch);
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_unstable_by_key(|&(index, _)| index);
+    hashes.extend(results.into_iter().map(|(_, features)| features));
+}
+
+fn features(salt: &str, index: usize, stretch: usize) -> (Option<u8>, u16) {
+    let text = format!("{salt}{index}");
+    let mut buffer = vec![0; buffer_size(text.len().max(32))];
+    buffer[..text.len()].copy_from_slice(text.as_bytes());
+    let mut digest = hash(&mut buffer, text.len());
+
+    if stretch > 0 {
+        // Clear any leftover salt and padding so the buffer is laid out for 32 byte inputs.
+        buffer.fill(0);
+        for _ in 0..stretch {
+            write_hex(&mut buffer, digest);
+            digest = hash(&mut buffer[..64], 32);
+        }
+    }
+
+    let (a, b, c, d) = digest;
+    let digits: Vec<_> = [a, b, c, d]
+        .iter()
+        .flat_map(|&word| (0..8).rev().map(move |i| ((word >> (4 * i)) & 0xf) as u8))
+        .collect();
+
+    let triple = digits.windows(3).find(|w| w[0] == w[1] && w[1] == w[2]).map(|w| w[0]);
+    let fives = digits
+        .windows(5)
+        .filter(|w| w.iter().all(|&digit| digit == w[0]))
+        .fold(0, |mask, w| mask | (1 << w[0]));
+
+    (triple, fives)
+}
+
+fn write_hex(buffer: &mut [u8], (a, b, c, d): (u32, u32, u32, u32)) {
+    const HEX: &[u8] = b"0123456789abcdef";
+
+    for (i, word) in [a, b, c, d].into_iter().enumerate() {
+        for j in 0..8 {
+            buffer[8 * i + j] = HEX[((word >> (28 - 4 * j)) & 0xf) as usize];
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! # Timing is Everything
+//!
+//! The capsule reaches disc `i` (counting from one) at time `t + i`, so each disc requires
+//! `t ≡ -(position + i) mod size`. The constraints are combined one disc at a time using
+//! [sieving]: step through candidates that satisfy every disc so far, increasing the step by
+//! each disc's size as the sizes are coprime.
+//!
+//! [sieving]: https://en.wikipedia.org/wiki/Chinese_remainder_theorem#Search_by_sieving
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<[u64; 4]> {
+    input.iter_unsigned().chunk::<4>().collect()
+}
+
+pub fn part1(input: &[[u64; 4]]) -> u64 {
+    align(input.iter().map(|&[disc, size, _, position]| (disc, size, position)))
+}
+
+pub fn part2(input: &[[u64; 4]]) -> u64 {
+    let extra = (input.len() as u64 + 1, 11, 0);
+    align(input.iter().map(|&[disc, size, _, position]| (disc, size, position)).chain([extra]))
+}
+
+fn align(discs: impl Iterator<Item = (u64, u64, u64)>) -> u64 {
+    let mut time = 0;
+    let mut step = 1;
+
+    for (disc, size, position) in discs {
+        while (position + time + disc) % size != 0 {
+            time += step;
+        }
+        step *= size;
+    }
+
+    time
+}
@@ -0,0 +1,33 @@
+//! # Dragon Checksum
+//!
+//! Each round of the checksum pairs up digits, writing `1` for equal pairs. Combining rounds,
+//! a checksum digit covers a chunk of `2ᵏ` digits where `2ᵏ` is the largest power of two
+//! dividing the disk length, and is `1` exactly when the chunk has an even number of ones.
+//! This computes the checksum in a single pass over the data.
+pub fn parse(input: &str) -> Vec<bool> {
+    input.trim().bytes().map(|b| b == b'1').collect()
+}
+
+pub fn part1(input: &[bool]) -> String {
+    checksum(input, 272)
+}
+
+pub fn part2(input: &[bool]) -> String {
+    checksum(input, 35651584)
+}
+
+/// Checksum of the data generated to fill a disk of `length`.
+pub fn checksum(input: &[bool], length: usize) -> String {
+    let mut data = input.to_vec();
+    while data.len() < length {
+        let reversed: Vec<_> = data.iter().rev().map(|&bit| !bit).collect();
+        data.push(false);
+        data.extend(reversed);
+    }
+    data.truncate(length);
+
+    let chunk = 1 << length.trailing_zeros();
+    data.chunks(chunk)
+        .map(|chunk| if chunk.iter().filter(|&&bit| bit).count() % 2 == 0 { '1' } else { '0' })
+        .collect()
+}
@@ -0,0 +1,103 @@
+//! # Two Steps Forward
+//!
+//! The doors depend on the path taken so far, so each state is the path itself. A
+//! [breadth first search] finds the shortest path. Finding the longest path means exploring
+//! every path, so the search expands a frontier of states on one thread, then hands these out
+//! to every thread which each continue with a [depth first search].
+//!
+//! [breadth first search]: https://en.wikipedia.org/wiki/Breadth-first_search
+//! [depth first search]: https://en.wikipedia.org/wiki/Depth-first_search
+use crate::util::md5::*;
+use crate::util::thread::*;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Clone)]
+struct State {
+    path: Vec<u8>,
+    x: u32,
+    y: u32,
+}
+
+pub fn parse(input: &str) -> (String, usize) {
+    let passcode = input.trim().as_bytes();
+    let start = State { path: passcode.to_vec(), x: 0, y: 0 };
+    let mut todo = VecDeque::from([start]);
+    let mut shortest = None;
+    let longest = AtomicUsize::new(0);
+
+    // Single threaded breadth first search until the frontier is large enough to share out.
+    while let Some(state) = todo.pop_front() {
+        if todo.len() >= 100 && shortest.is_some() {
+            todo.push_front(state);
+            break;
+        }
+
+        for next in moves(&state) {
+            if (next.x, next.y) == (3, 3) {
+                let length = next.path.len() - passcode.len();
+                shortest.get_or_insert_with(|| {
+                    String::from_utf8(next.path[passcode.len()..].to_vec()).unwrap()
+                });
+                longest.fetch_max(length, Ordering::Relaxed);
+            } else {
+                todo.push_back(next);
+            }
+        }
+    }
+
+    spawn_batches(todo.into(), |batch| {
+        for state in batch {
+            dfs(&state, passcode.len(), &longest);
+        }
+    });
+
+    (shortest.unwrap(), longest.into_inner())
+}
+
+pub fn part1(input: &(String, usize)) -> String {
+    input.0.clone()
+}
+
+pub fn part2(input: &(String, usize)) -> usize {
+    input.1
+}
+
+fn dfs(state: &State, offset: usize, longest: &AtomicUsize) {
+    for next in moves(state) {
+        if (next.x, next.y) == (3, 3) {
+            longest.fetch_max(next.path.len() - offset, Ordering::Relaxed);
+        } else {
+            dfs(&next, offset, longest);
+        }
+    }
+}
+
+/// Open doors are a hex digit from `b` to `f` in the first four digits of the hash, for up,
+/// down, left and right in that order.
+fn moves(state: &State) -> Vec<State> {
+    let size = state.path.len();
+    let mut buffer = vec![0; buffer_size(size)];
+    buffer[..size].copy_from_slice(&state.path);
+    let (a, ..) = hash(&mut buffer, size);
+
+    let open = |i: u32| (a >> (28 - 4 * i)) & 0xf > 0xa;
+    let State { x, y, .. } = *state;
+    let candidates = [
+        (b'U', y > 0, x, y.wrapping_sub(1)),
+        (b'D', y < 3, x, y + 1),
+        (b'L', x > 0, x.wrapping_sub(1), y),
+        (b'R', x < 3, x + 1, y),
+    ];
+
+    candidates
+        .into_iter()
+        .enumerate()
+        .filter(|&(i, (_, inside, ..))| inside && open(i as u32))
+        .map(|(_, (step, _, x, y))| {
+            let mut path = state.path.clone();
+            path.push(step);
+            State { path, x, y }
+        })
+        .collect()
+}
@@ -0,0 +1,31 @@
+//! # Like a Rogue
+//!
+//! A tile is a trap when exactly one of its left and right neighbors in the previous row is a
+//! trap, the center tile never matters. Storing each row as bits of an integer, the next row
+//! is the previous shifted left XOR the previous shifted right, computing every tile at once.
+pub fn parse(input: &str) -> &str {
+    input.trim()
+}
+
+pub fn part1(input: &str) -> u32 {
+    safe(input, 40)
+}
+
+pub fn part2(input: &str) -> u32 {
+    safe(input, 400_000)
+}
+
+/// Number of safe tiles in the first `rows` rows.
+pub fn safe(input: &str, rows: usize) -> u32 {
+    let width = input.len() as u32;
+    let mask = (1_u128 << width) - 1;
+    let mut row = input.bytes().fold(0_u128, |acc, b| (acc << 1) | (b == b'^') as u128);
+    let mut total = 0;
+
+    for _ in 0..rows {
+        total += width - row.count_ones();
+        row = ((row << 1) ^ (row >> 1)) & mask;
+    }
+
+    total
+}
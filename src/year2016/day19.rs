@@ -0,0 +1,33 @@
+//! # An Elephant Named Joseph
+//!
+//! Both parts have closed form solutions, found by simulating small circles and spotting the
+//! pattern.
+//!
+//! Part one is the [Josephus problem](https://en.wikipedia.org/wiki/Josephus_problem) with
+//! `k = 2`. Writing `n = 2ᵐ + l`, the winner is `2l + 1`.
+//!
+//! In part two, with `3ᵐ` the largest power of three not greater than `n`, the winner is `n`
+//! when `n = 3ᵐ`, `n - 3ᵐ` up to `2 × 3ᵐ` and `2n - 3 × 3ᵐ` after that.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> u32 {
+    input.unsigned()
+}
+
+pub fn part1(input: &u32) -> u32 {
+    let n = *input;
+    2 * (n - (1 << n.ilog2())) + 1
+}
+
+pub fn part2(input: &u32) -> u32 {
+    let n = *input;
+    let power = 3_u32.pow(n.ilog(3));
+
+    if n == power {
+        n
+    } else if n <= 2 * power {
+        n - power
+    } else {
+        2 * n - 3 * power
+    }
+}
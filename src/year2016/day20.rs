@@ -0,0 +1,39 @@
+//! # Firewall Rules
+//!
+//! Sorting the ranges by start then merging overlapping or adjacent ranges leaves disjoint
+//! blocked ranges, with the allowed addresses in the gaps between them.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<[u64; 2]> {
+    let mut ranges: Vec<_> = input.iter_unsigned().chunk::<2>().collect();
+    ranges.sort_unstable();
+
+    let mut merged: Vec<[u64; 2]> = Vec::new();
+    for [start, end] in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last[1] + 1 => last[1] = last[1].max(end),
+            _ => merged.push([start, end]),
+        }
+    }
+    merged
+}
+
+pub fn part1(input: &[[u64; 2]]) -> u64 {
+    if input[0][0] > 0 {
+        0
+    } else {
+        input[0][1] + 1
+    }
+}
+
+pub fn part2(input: &[[u64; 2]]) -> u64 {
+    allowed(input, u32::MAX as u64)
+}
+
+/// Number of allowed addresses from 0 to `max` inclusive.
+pub fn allowed(input: &[[u64; 2]], max: u64) -> u64 {
+    let blocked: u64 =
+        input.iter().map(|&[start, end]| end.min(max) + 1 - start.min(max + 1)).sum();
+    max + 1 - blocked
+}
@@ -0,0 +1,82 @@
+//! # Scrambled Letters and Hash
+//!
+//! Applies each operation directly to a vector of bytes. Rotating based on the position of a
+//! letter isn't always reversible for other lengths, so rather than inverting each operation
+//! part two scrambles every one of the 40,320 permutations of the password until finding the
+//! one that matches.
+use crate::util::parse::*;
+use crate::util::slice::*;
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(input: &[&str]) -> String {
+    scramble(input, "abcdefgh")
+}
+
+pub fn part2(input: &[&str]) -> String {
+    unscramble(input, "fbgdceah")
+}
+
+pub fn scramble(input: &[&str], password: &str) -> String {
+    let mut password = password.as_bytes().to_vec();
+    scramble_bytes(input, &mut password);
+    String::from_utf8(password).unwrap()
+}
+
+/// Finds the password that scrambles to `scrambled`.
+pub fn unscramble(input: &[&str], scrambled: &str) -> String {
+    let mut letters = scrambled.as_bytes().to_vec();
+    letters.sort_unstable();
+    let mut result = None;
+
+    letters.permutations(|candidate| {
+        if result.is_none() {
+            let mut password = candidate.to_vec();
+            scramble_bytes(input, &mut password);
+            if password == scrambled.as_bytes() {
+                result = Some(String::from_utf8(candidate.to_vec()).unwrap());
+            }
+        }
+    });
+
+    result.unwrap()
+}
+
+fn scramble_bytes(input: &[&str], password: &mut [u8]) {
+    let size = password.len();
+    let find = |password: &[u8], letter: &str| {
+        password.iter().position(|&b| b == letter.as_bytes()[0]).unwrap()
+    };
+
+    for line in input {
+        let tokens: Vec<_> = line.split_ascii_whitespace().collect();
+        match tokens[..] {
+            ["swap", "position", x, _, _, y] => password.swap(x.unsigned(), y.unsigned()),
+            ["swap", "letter", x, _, _, y] => {
+                let (x, y) = (find(password, x), find(password, y));
+                password.swap(x, y);
+            }
+            ["rotate", "left", x, _] => password.rotate_left(x.unsigned::<usize>() % size),
+            ["rotate", "right", x, _] => password.rotate_right(x.unsigned::<usize>() % size),
+            ["rotate", "based", .., x] => {
+                let index = find(password, x);
+                let steps = 1 + index + (index >= 4) as usize;
+                password.rotate_right(steps % size);
+            }
+            ["reverse", _, x, _, y] => password[x.unsigned()..=y.unsigned()].reverse(),
+            ["move", _, x, _, _, y] => {
+                let (x, y): (usize, usize) = (x.unsigned(), y.unsigned());
+                let letter = password[x];
+                if x < y {
+                    password.copy_within(x + 1..=y, x);
+                } else {
+                    password.copy_within(y..x, y + 1);
+                }
+                password[y] = letter;
+            }
+            _ => panic!("Unknown operation {line}"),
+        }
+    }
+}
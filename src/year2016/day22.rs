@@ -0,0 +1,82 @@
+//! # Grid Computing
+//!
+//! Part one sorts the available space so that the number of nodes with enough room for each
+//! node's data is found with a binary search.
+//!
+//! Part two relies on the shape of the puzzle input. Apart from a few huge nodes that act as
+//! walls, every node can hold the data of any other, but only one node is empty. Moving data is
+//! then the same as moving the empty node around like a
+//! [sliding puzzle](https://en.wikipedia.org/wiki/Sliding_puzzle). The empty node first moves
+//! next to the goal data with a [breadth first search]. After that, each step moving the goal
+//! data one node to the left takes five moves: one swap plus four to bring the empty node back
+//! around in front of it.
+//!
+//! [breadth first search]: https://en.wikipedia.org/wiki/Breadth-first_search
+use crate::util::hash::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+use std::collections::VecDeque;
+
+pub struct Node {
+    x: u32,
+    y: u32,
+    size: u32,
+    used: u32,
+    available: u32,
+}
+
+pub fn parse(input: &str) -> Vec<Node> {
+    input
+        .lines()
+        .filter(|line| line.starts_with("/dev"))
+        .map(|line| line.iter_unsigned().chunk::<6>().next().unwrap())
+        .map(|[x, y, size, used, available, _]| Node { x, y, size, used, available })
+        .collect()
+}
+
+pub fn part1(input: &[Node]) -> usize {
+    let mut available: Vec<_> = input.iter().map(|node| node.available).collect();
+    available.sort_unstable();
+
+    input
+        .iter()
+        .filter(|node| node.used > 0)
+        .map(|node| {
+            let fits = available.len() - available.partition_point(|&a| a < node.used);
+            // A node can't move data to itself.
+            fits - (node.available >= node.used) as usize
+        })
+        .sum()
+}
+
+pub fn part2(input: &[Node]) -> u32 {
+    let empty = input.iter().find(|node| node.used == 0).unwrap();
+    let walls: FastSet<_> =
+        input.iter().filter(|node| node.used > empty.size).map(|node| (node.x, node.y)).collect();
+    let nodes: FastSet<_> = input.iter().map(|node| (node.x, node.y)).collect();
+    let width = input.iter().map(|node| node.x).max().unwrap();
+
+    let target = (width - 1, 0);
+    let mut todo = VecDeque::from([((empty.x, empty.y), 0)]);
+    let mut seen = FastSet::build([(empty.x, empty.y)]);
+
+    while let Some(((x, y), steps)) = todo.pop_front() {
+        if (x, y) == target {
+            return steps + 1 + 5 * (width - 1);
+        }
+
+        let neighbors = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+        for next in neighbors {
+            // The goal data can't be moved out of the way.
+            if nodes.contains(&next)
+                && !walls.contains(&next)
+                && next != (width, 0)
+                && seen.insert(next)
+            {
+                todo.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    unreachable!()
+}
@@ -0,0 +1,28 @@
+//! # Safe Cracking
+//!
+//! The program computes `a!` plus a constant using nested loops, which would take billions of
+//! instructions for part two. The [assembunny interpreter] recognizes the multiplication
+//! pattern and replaces it with a single step, while still supporting `tgl` rewriting the
+//! program as it runs.
+//!
+//! [assembunny interpreter]: crate::util::assembunny
+use crate::util::assembunny::*;
+
+pub fn parse(input: &str) -> Computer {
+    Computer::parse(input)
+}
+
+pub fn part1(input: &Computer) -> i64 {
+    execute(input, 7)
+}
+
+pub fn part2(input: &Computer) -> i64 {
+    execute(input, 12)
+}
+
+pub fn execute(input: &Computer, eggs: i64) -> i64 {
+    let mut computer = input.clone();
+    computer.registers[0] = eggs;
+    computer.run();
+    computer.registers[0]
+}
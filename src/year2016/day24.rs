@@ -0,0 +1,73 @@
+//! # Air Duct Spelunking
+//!
+//! A [breadth first search] from each numbered location finds the distances between every
+//! pair. With at most eight locations, trying every order to visit them in is quick.
+//!
+//! [breadth first search]: https://en.wikipedia.org/wiki/Breadth-first_search
+use crate::util::grid::*;
+use crate::util::point::*;
+use crate::util::slice::*;
+use std::collections::VecDeque;
+
+pub fn parse(input: &str) -> Vec<Vec<u32>> {
+    let grid = Grid::parse(input);
+    let mut locations = Vec::new();
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            if grid[point].is_ascii_digit() {
+                locations.push(((grid[point] - b'0') as usize, point));
+            }
+        }
+    }
+    locations.sort_unstable_by_key(|&(digit, _)| digit);
+
+    locations
+        .iter()
+        .map(|&(_, start)| {
+            let mut distance = grid.same_size_with(u32::MAX);
+            let mut todo = VecDeque::from([start]);
+            distance[start] = 0;
+
+            while let Some(point) = todo.pop_front() {
+                for next in ORTHOGONAL.map(|o| point + o) {
+                    if grid[next] != b'#' && distance[next] == u32::MAX {
+                        distance[next] = distance[point] + 1;
+                        todo.push_back(next);
+                    }
+                }
+            }
+
+            locations.iter().map(|&(_, end)| distance[end]).collect()
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Vec<u32>]) -> u32 {
+    shortest(input, false)
+}
+
+pub fn part2(input: &[Vec<u32>]) -> u32 {
+    shortest(input, true)
+}
+
+fn shortest(distance: &[Vec<u32>], back: bool) -> u32 {
+    let mut order: Vec<_> = (1..distance.len()).collect();
+    let mut result = u32::MAX;
+
+    order.permutations(|order| {
+        let mut total = 0;
+        let mut current = 0;
+        for &next in order {
+            total += distance[current][next];
+            current = next;
+        }
+        if back {
+            total += distance[current][0];
+        }
+        result = result.min(total);
+    });
+
+    result
+}
@@ -0,0 +1,25 @@
+//! # Clock Signal
+//!
+//! Tries each initial value of `a` in turn on the [assembunny interpreter], which pauses on
+//! each `out` instruction so that the signal can be checked as it is produced. The program
+//! loops forever, repeating the same signal, so checking a reasonable number of outputs is
+//! enough.
+//!
+//! This puzzle only has one part.
+//!
+//! [assembunny interpreter]: crate::util::assembunny
+use crate::util::assembunny::*;
+
+pub fn parse(input: &str) -> Computer {
+    Computer::parse(input)
+}
+
+pub fn part1(input: &Computer) -> i64 {
+    (1..)
+        .find(|&a| {
+            let mut computer = input.clone();
+            computer.registers[0] = a;
+            (0..100).all(|i| computer.run() == Some(i % 2))
+        })
+        .unwrap()
+}
@@ -0,0 +1,25 @@
+//! # Inverse Captcha
+//!
+//! Compares each digit with the digit a fixed offset ahead, wrapping around the end of the
+//! sequence. The first part uses an offset of one, the second half the length.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> &[u8] {
+    input.trim().as_bytes()
+}
+
+pub fn part1(input: &[u8]) -> u32 {
+    captcha(input, 1)
+}
+
+pub fn part2(input: &[u8]) -> u32 {
+    captcha(input, input.len() / 2)
+}
+
+fn captcha(input: &[u8], offset: usize) -> u32 {
+    let size = input.len();
+    (0..size)
+        .filter(|&i| input[i] == input[(i + offset) % size])
+        .map(|i| input[i].to_decimal() as u32)
+        .sum()
+}
@@ -0,0 +1,36 @@
+//! # Corruption Checksum
+//!
+//! Sorting each row makes both parts simpler. The difference is between the last and first
+//! numbers, and a divisor of any number can only appear before it.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<Vec<u32>> {
+    input
+        .lines()
+        .map(|line| {
+            let mut row: Vec<_> = line.iter_unsigned().collect();
+            row.sort_unstable();
+            row
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Vec<u32>]) -> u32 {
+    input.iter().map(|row| row[row.len() - 1] - row[0]).sum()
+}
+
+pub fn part2(input: &[Vec<u32>]) -> u32 {
+    input
+        .iter()
+        .map(|row| {
+            for (i, &large) in row.iter().enumerate() {
+                for &small in &row[..i] {
+                    if large.is_multiple_of(small) {
+                        return large / small;
+                    }
+                }
+            }
+            unreachable!()
+        })
+        .sum()
+}
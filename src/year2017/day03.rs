@@ -0,0 +1,49 @@
+//! # Spiral Memory
+//!
+//! The first part has a closed form. Square `n` is on ring `k`, the smallest `k` where
+//! `n <= (2k + 1)²`. Each side of the ring has `2k` squares, and the distance is `k`
+//! to reach the ring plus the distance along the side from its midpoint.
+//!
+//! The second part walks the spiral storing values in a map, turning left whenever the square
+//! to the left is still empty. Values grow exponentially so only a few dozen squares are
+//! written.
+use crate::util::hash::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> u32 {
+    input.unsigned()
+}
+
+pub fn part1(input: &u32) -> u32 {
+    let n = *input;
+    if n == 1 {
+        return 0;
+    }
+
+    let k = (n - 1).isqrt().div_ceil(2);
+    let side = 2 * k;
+    let offset = (n - (side - 1).pow(2)) % side;
+    k + offset.abs_diff(k)
+}
+
+pub fn part2(input: &u32) -> u32 {
+    let mut values = FastMap::build([(ORIGIN, 1)]);
+    let mut position = ORIGIN;
+    let mut direction = DOWN;
+
+    loop {
+        // Turn left if possible, otherwise keep going straight.
+        let left = direction.counter_clockwise();
+        if !values.contains_key(&(position + left)) {
+            direction = left;
+        }
+        position += direction;
+
+        let value = DIAGONAL.iter().filter_map(|&offset| values.get(&(position + offset))).sum();
+        if value > *input {
+            return value;
+        }
+        values.insert(position, value);
+    }
+}
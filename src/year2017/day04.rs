@@ -0,0 +1,27 @@
+//! # High-Entropy Passphrases
+//!
+//! Two words are anagrams if their letters sorted in order are the same, so the second part is
+//! the first part applied to words with sorted letters.
+use crate::util::hash::*;
+
+pub fn parse(input: &str) -> Vec<Vec<&str>> {
+    input.lines().map(|line| line.split_ascii_whitespace().collect()).collect()
+}
+
+pub fn part1(input: &[Vec<&str>]) -> usize {
+    input.iter().filter(|words| unique(words.iter().map(|word| word.as_bytes().to_vec()))).count()
+}
+
+pub fn part2(input: &[Vec<&str>]) -> usize {
+    let sorted = |word: &&str| {
+        let mut letters = word.as_bytes().to_vec();
+        letters.sort_unstable();
+        letters
+    };
+    input.iter().filter(|words| unique(words.iter().map(sorted))).count()
+}
+
+fn unique(mut words: impl Iterator<Item = Vec<u8>>) -> bool {
+    let mut seen = FastSet::new();
+    words.all(|word| seen.insert(word))
+}
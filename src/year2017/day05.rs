@@ -0,0 +1,32 @@
+//! # A Maze of Twisty Trampolines, All Alike
+//!
+//! Straightforward simulation of each jump.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<i32> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i32]) -> usize {
+    jumps(input, |offset| offset + 1)
+}
+
+pub fn part2(input: &[i32]) -> usize {
+    jumps(input, |offset| if offset >= 3 { offset - 1 } else { offset + 1 })
+}
+
+fn jumps(input: &[i32], update: impl Fn(i32) -> i32) -> usize {
+    let mut offsets = input.to_vec();
+    let mut index = 0;
+    let mut steps = 0;
+
+    while index < offsets.len() {
+        let offset = offsets[index];
+        offsets[index] = update(offset);
+        // Jumping before the start wraps to a huge index that also ends the loop.
+        index = index.wrapping_add_signed(offset as isize);
+        steps += 1;
+    }
+
+    steps
+}
@@ -0,0 +1,38 @@
+//! # Memory Reallocation
+//!
+//! Finds the loop in the configurations of banks with [`Cycle`]. The first repeat gives both
+//! answers, the number of cycles so far and the size of the loop.
+//!
+//! [`Cycle`]: crate::util::cycle::Cycle
+use crate::util::cycle::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> (usize, usize) {
+    let banks: Vec<u32> = input.iter_unsigned().collect();
+    let cycle = Cycle::detect(banks, |banks| redistribute(banks));
+    (cycle.start + cycle.length, cycle.length)
+}
+
+pub fn part1(input: &(usize, usize)) -> usize {
+    input.0
+}
+
+pub fn part2(input: &(usize, usize)) -> usize {
+    input.1
+}
+
+fn redistribute(banks: &[u32]) -> Vec<u32> {
+    let mut next = banks.to_vec();
+    let size = next.len();
+
+    // `max_by_key` returns the last maximum, so iterating in reverse breaks ties in favor of
+    // the lowest index.
+    let (start, &blocks) =
+        banks.iter().enumerate().rev().max_by_key(|&(_, &blocks)| blocks).unwrap();
+    next[start] = 0;
+    for i in 1..=blocks as usize {
+        next[(start + i) % size] += 1;
+    }
+
+    next
+}
@@ -0,0 +1,59 @@
+//! # Recursive Circus
+//!
+//! The bottom program is the only one that isn't held by any other.
+//!
+//! A depth first search computes the total weight of each tower from the top down. The first
+//! tower found with children of different weights is the deepest imbalance, so the odd child
+//! out must be the program with the wrong weight. Programs with wrong weights above it would
+//! have unbalanced one of its children instead. The search stops early by returning the
+//! corrected weight as an `Err`.
+use crate::util::hash::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> (String, i32) {
+    let lines: Vec<Vec<_>> = input
+        .lines()
+        .map(|line| line.split([' ', '(', ')', ',', '-', '>']).filter(|s| !s.is_empty()).collect())
+        .collect();
+    let indices: FastMap<_, _> =
+        lines.iter().enumerate().map(|(i, tokens)| (tokens[0], i)).collect();
+
+    let weights: Vec<i32> = lines.iter().map(|tokens| tokens[1].signed()).collect();
+    let children: Vec<Vec<usize>> =
+        lines.iter().map(|tokens| tokens[2..].iter().map(|name| indices[name]).collect()).collect();
+
+    let mut held = vec![false; lines.len()];
+    children.iter().flatten().for_each(|&child| held[child] = true);
+    let root = held.iter().position(|&h| !h).unwrap();
+
+    let corrected = total(&weights, &children, root).unwrap_err();
+    (lines[root][0].to_string(), corrected)
+}
+
+pub fn part1(input: &(String, i32)) -> String {
+    input.0.clone()
+}
+
+pub fn part2(input: &(String, i32)) -> i32 {
+    input.1
+}
+
+fn total(weights: &[i32], children: &[Vec<usize>], node: usize) -> Result<i32, i32> {
+    let totals: Vec<_> = children[node]
+        .iter()
+        .map(|&child| total(weights, children, child))
+        .collect::<Result<_, _>>()?;
+
+    // With only two children it would be ambiguous which one is wrong, so assume there are at
+    // least three whenever they differ.
+    let unique = |t: &i32| totals.iter().filter(|&other| other == t).count() == 1;
+
+    match totals.iter().position(unique) {
+        Some(odd) if totals.len() > 2 => {
+            let expected = totals[(odd + 1) % totals.len()];
+            let child = children[node][odd];
+            Err(weights[child] + expected - totals[odd])
+        }
+        _ => Ok(weights[node] + totals.iter().sum::<i32>()),
+    }
+}
@@ -0,0 +1,49 @@
+//! # I Heard You Like Registers
+//!
+//! Runs every instruction once while tracking the highest value ever held, then finds the
+//! largest register at the end.
+use crate::util::hash::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> (i32, i32) {
+    let mut registers: FastMap<&str, i32> = FastMap::new();
+    let mut highest = 0;
+
+    for line in input.lines() {
+        let [target, operation, amount, _, source, comparison, value] =
+            line.split_ascii_whitespace().collect::<Vec<_>>()[..]
+        else {
+            unreachable!()
+        };
+
+        let first = registers.get(source).copied().unwrap_or(0);
+        let second: i32 = value.signed();
+        let condition = match comparison {
+            "==" => first == second,
+            "!=" => first != second,
+            "<" => first < second,
+            "<=" => first <= second,
+            ">" => first > second,
+            ">=" => first >= second,
+            _ => unreachable!(),
+        };
+
+        if condition {
+            let amount: i32 = amount.signed();
+            let register = registers.entry(target).or_insert(0);
+            *register += if operation == "inc" { amount } else { -amount };
+            highest = highest.max(*register);
+        }
+    }
+
+    let largest = registers.values().copied().max().unwrap_or(0).max(0);
+    (largest, highest)
+}
+
+pub fn part1(input: &(i32, i32)) -> i32 {
+    input.0
+}
+
+pub fn part2(input: &(i32, i32)) -> i32 {
+    input.1
+}
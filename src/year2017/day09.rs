@@ -0,0 +1,38 @@
+//! # Stream Processing
+//!
+//! A single pass over the stream tracks the current group depth and whether we're inside
+//! garbage. Cancelled characters are skipped along with the `!` that cancels them.
+pub fn parse(input: &str) -> (u32, u32) {
+    let mut bytes = input.trim().bytes();
+    let mut depth = 0;
+    let mut score = 0;
+    let mut garbage = false;
+    let mut count = 0;
+
+    while let Some(b) = bytes.next() {
+        match (garbage, b) {
+            (_, b'!') => {
+                bytes.next();
+            }
+            (true, b'>') => garbage = false,
+            (true, _) => count += 1,
+            (false, b'<') => garbage = true,
+            (false, b'{') => {
+                depth += 1;
+                score += depth;
+            }
+            (false, b'}') => depth -= 1,
+            _ => (),
+        }
+    }
+
+    (score, count)
+}
+
+pub fn part1(input: &(u32, u32)) -> u32 {
+    input.0
+}
+
+pub fn part2(input: &(u32, u32)) -> u32 {
+    input.1
+}
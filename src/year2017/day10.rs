@@ -0,0 +1,28 @@
+//! # Knot Hash
+//!
+//! Both parts use the shared [knot hash] module. The first part ties a single round of knots
+//! using the input as a list of numbers, the second computes the full hash of the input as
+//! ASCII bytes.
+//!
+//! [knot hash]: crate::util::knot
+use crate::util::knot::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> &str {
+    input.trim()
+}
+
+pub fn part1(input: &str) -> usize {
+    check(input, 256)
+}
+
+pub fn part2(input: &str) -> String {
+    hex(&hash(input))
+}
+
+/// Product of the first two numbers after one round of knots in a list of `size` elements.
+pub fn check(input: &str, size: usize) -> usize {
+    let lengths: Vec<_> = input.iter_unsigned().collect();
+    let list = sparse(size, &lengths, 1);
+    list[0] as usize * list[1] as usize
+}
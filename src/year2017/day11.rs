@@ -0,0 +1,39 @@
+//! # Hex Ed
+//!
+//! Hexagons are tracked using [axial coordinates](https://www.redblobgames.com/grids/hexagons/)
+//! where each step changes `q`, `r` or both by one. The distance from the origin is then half
+//! the sum of `|q|`, `|r|` and `|q + r|`.
+pub fn parse(input: &str) -> (i32, i32) {
+    let mut q: i32 = 0;
+    let mut r: i32 = 0;
+    let mut furthest = 0;
+
+    for step in input.trim().split(',') {
+        let (dq, dr) = match step {
+            "n" => (0, -1),
+            "s" => (0, 1),
+            "ne" => (1, -1),
+            "sw" => (-1, 1),
+            "nw" => (-1, 0),
+            "se" => (1, 0),
+            _ => unreachable!(),
+        };
+        q += dq;
+        r += dr;
+        furthest = furthest.max(distance(q, r));
+    }
+
+    (distance(q, r), furthest)
+}
+
+pub fn part1(input: &(i32, i32)) -> i32 {
+    input.0
+}
+
+pub fn part2(input: &(i32, i32)) -> i32 {
+    input.1
+}
+
+fn distance(q: i32, r: i32) -> i32 {
+    (q.abs() + r.abs() + (q + r).abs()) / 2
+}
@@ -0,0 +1,44 @@
+//! # Digital Plumber
+//!
+//! A depth first search from each program not yet visited finds the size of every connected
+//! group. Starting from program 0 puts its group first.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<usize> {
+    let pipes: Vec<Vec<usize>> =
+        input.lines().map(|line| line.iter_unsigned().skip(1).collect()).collect();
+    let mut visited = vec![false; pipes.len()];
+    let mut groups = Vec::new();
+
+    for start in 0..pipes.len() {
+        if visited[start] {
+            continue;
+        }
+
+        let mut todo = vec![start];
+        let mut size = 0;
+        visited[start] = true;
+
+        while let Some(program) = todo.pop() {
+            size += 1;
+            for &next in &pipes[program] {
+                if !visited[next] {
+                    visited[next] = true;
+                    todo.push(next);
+                }
+            }
+        }
+
+        groups.push(size);
+    }
+
+    groups
+}
+
+pub fn part1(input: &[usize]) -> usize {
+    input[0]
+}
+
+pub fn part2(input: &[usize]) -> usize {
+    input.len()
+}
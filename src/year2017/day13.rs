@@ -0,0 +1,36 @@
+//! # Packet Scanners
+//!
+//! A scanner with range `r` is back at the top every `2(r - 1)` picoseconds, so the packet is
+//! caught at depth `d` when `delay + d` is a multiple of this period.
+//!
+//! The second part tries each delay in turn. Checking the scanners with the shortest periods
+//! first rules out most delays after only a comparison or two.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+/// Depth, range and period of each scanner, sorted by period.
+pub fn parse(input: &str) -> Vec<(u32, u32, u32)> {
+    let mut scanners: Vec<_> = input
+        .iter_unsigned()
+        .chunk::<2>()
+        .map(|[depth, range]| (depth, range, 2 * (range - 1)))
+        .collect();
+    scanners.sort_unstable_by_key(|&(_, _, period)| period);
+    scanners
+}
+
+pub fn part1(input: &[(u32, u32, u32)]) -> u32 {
+    input
+        .iter()
+        .filter(|&&(depth, _, period)| depth.is_multiple_of(period))
+        .map(|(depth, range, _)| depth * range)
+        .sum()
+}
+
+pub fn part2(input: &[(u32, u32, u32)]) -> u32 {
+    (0..)
+        .find(|delay| {
+            input.iter().all(|&(depth, _, period)| !(delay + depth).is_multiple_of(period))
+        })
+        .unwrap()
+}
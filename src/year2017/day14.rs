@@ -0,0 +1,51 @@
+//! # Disk Defragmentation
+//!
+//! Each row of the disk is the [knot hash] of the key followed by the row number. Regions are
+//! counted by flood filling from each used square that hasn't been visited yet.
+//!
+//! [knot hash]: crate::util::knot
+use crate::util::grid::*;
+use crate::util::knot::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<bool> {
+    let key = input.trim();
+    let bytes = (0..128)
+        .flat_map(|row| hash(&format!("{key}-{row}")))
+        .flat_map(|byte| (0..8).rev().map(move |bit| byte & (1 << bit) != 0))
+        .collect();
+    Grid { width: 128, height: 128, bytes }
+}
+
+pub fn part1(input: &Grid<bool>) -> usize {
+    input.bytes.iter().filter(|&&used| used).count()
+}
+
+pub fn part2(input: &Grid<bool>) -> usize {
+    let mut used = input.clone();
+    let mut regions = 0;
+
+    for y in 0..used.height {
+        for x in 0..used.width {
+            let start = Point::new(x, y);
+            if !used[start] {
+                continue;
+            }
+
+            regions += 1;
+            used[start] = false;
+            let mut todo = vec![start];
+
+            while let Some(point) = todo.pop() {
+                for next in ORTHOGONAL.map(|o| point + o) {
+                    if used.contains(next) && used[next] {
+                        used[next] = false;
+                        todo.push(next);
+                    }
+                }
+            }
+        }
+    }
+
+    regions
+}
@@ -0,0 +1,49 @@
+//! # Dueling Generators
+//!
+//! Brute force comparison of each pair. The modulus `2³¹ - 1` is a
+//! [Mersenne prime](https://en.wikipedia.org/wiki/Mersenne_prime) so the remainder can be
+//! computed with shifts and additions instead of a much slower division.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+const MODULUS: u64 = 0x7fffffff;
+
+pub fn parse(input: &str) -> [u64; 2] {
+    input.iter_unsigned().chunk::<2>().next().unwrap()
+}
+
+pub fn part1(input: &[u64; 2]) -> usize {
+    judge(input, 40_000_000, 1, 1)
+}
+
+pub fn part2(input: &[u64; 2]) -> usize {
+    judge(input, 5_000_000, 4, 8)
+}
+
+/// Counts how many of the first `pairs` values (that are multiples of `a_multiple` and
+/// `b_multiple` respectively) match in their lowest 16 bits.
+pub fn judge(input: &[u64; 2], pairs: usize, a_multiple: u64, b_multiple: u64) -> usize {
+    let [mut a, mut b] = *input;
+    let mut count = 0;
+
+    for _ in 0..pairs {
+        a = next(a, 16807, a_multiple);
+        b = next(b, 48271, b_multiple);
+        count += (a & 0xffff == b & 0xffff) as usize;
+    }
+
+    count
+}
+
+fn next(mut value: u64, factor: u64, multiple: u64) -> u64 {
+    loop {
+        let product = value * factor;
+        value = (product & MODULUS) + (product >> 31);
+        if value >= MODULUS {
+            value -= MODULUS;
+        }
+        if value.is_multiple_of(multiple) {
+            return value;
+        }
+    }
+}
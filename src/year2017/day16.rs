@@ -0,0 +1,85 @@
+//! # Permutation Promenade
+//!
+//! Spins and exchanges move programs by position regardless of their names, while partners
+//! swap programs by name regardless of their positions. The two kinds of move commute, so a
+//! whole dance is equivalent to one permutation of positions followed by one renaming of
+//! programs.
+//!
+//! Repeating the dance a billion times then only needs the two permutations raised to that
+//! power, computed with [exponentiation by squaring](https://en.wikipedia.org/wiki/Exponentiation_by_squaring).
+use crate::util::parse::*;
+
+pub enum Move {
+    Spin(usize),
+    Exchange(usize, usize),
+    Partner(usize, usize),
+}
+
+pub fn parse(input: &str) -> Vec<Move> {
+    input
+        .trim()
+        .split(',')
+        .map(|step| {
+            let bytes = step.as_bytes();
+            match bytes[0] {
+                b's' => Move::Spin(step.unsigned()),
+                b'x' => {
+                    let (a, b) = step.split_once('/').unwrap();
+                    Move::Exchange(a.unsigned(), b.unsigned())
+                }
+                b'p' => Move::Partner((bytes[1] - b'a') as usize, (bytes[3] - b'a') as usize),
+                _ => unreachable!(),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Move]) -> String {
+    dance(input, 16, 1)
+}
+
+pub fn part2(input: &[Move]) -> String {
+    dance(input, 16, 1_000_000_000)
+}
+
+/// Order of `size` programs after dancing `times` times.
+pub fn dance(input: &[Move], size: usize, times: u64) -> String {
+    // Which original position ends up at each position, and which name each name becomes.
+    let mut positions: Vec<_> = (0..size).collect();
+    let mut names: Vec<_> = (0..size).collect();
+
+    for step in input {
+        match *step {
+            Move::Spin(n) => positions.rotate_right(n),
+            Move::Exchange(a, b) => positions.swap(a, b),
+            Move::Partner(a, b) => {
+                let first = names.iter().position(|&n| n == a).unwrap();
+                let second = names.iter().position(|&n| n == b).unwrap();
+                names.swap(first, second);
+            }
+        }
+    }
+
+    let positions = power(&positions, times);
+    let names = power(&names, times);
+    positions.iter().map(|&p| (b'a' + names[p] as u8) as char).collect()
+}
+
+fn compose(first: &[usize], second: &[usize]) -> Vec<usize> {
+    second.iter().map(|&i| first[i]).collect()
+}
+
+fn power(permutation: &[usize], mut exponent: u64) -> Vec<usize> {
+    let mut base = permutation.to_vec();
+    let mut result: Vec<_> = (0..permutation.len()).collect();
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = compose(&result, &base);
+        }
+        base = compose(&base, &base);
+        exponent >>= 1;
+    }
+
+    result
+}
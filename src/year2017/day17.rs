@@ -0,0 +1,51 @@
+//! # Spinlock
+//!
+//! The first part inserts values into a `Vec`, which is fast enough for 2017 insertions.
+//!
+//! For the second part, zero always stays at the start of the buffer, so only insertions
+//! directly after it matter and there is no need to store the buffer at all. Most steps don't
+//! wrap around the end of the buffer, in which case the position simply advances by
+//! `step + 1`. The number of such steps in a row can be computed directly, skipping ahead to
+//! the next wrap, which is the only time the value after zero can change.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> usize {
+    input.unsigned()
+}
+
+pub fn part1(input: &usize) -> usize {
+    let step = *input;
+    let mut buffer = vec![0];
+    let mut position = 0;
+
+    for value in 1..=2017 {
+        position = (position + step) % buffer.len() + 1;
+        buffer.insert(position, value);
+    }
+
+    buffer[(position + 1) % buffer.len()]
+}
+
+pub fn part2(input: &usize) -> usize {
+    let step = *input;
+    let mut position = 0;
+    let mut size = 1;
+    let mut result = 0;
+
+    while size <= 50_000_000 {
+        position = (position + step) % size + 1;
+        if position == 1 {
+            result = size;
+        }
+        size += 1;
+
+        // Insertions that fit before the end of the buffer without wrapping.
+        if position + step < size {
+            let skip = (size - 1 - position - step) / step + 1;
+            position += skip * (step + 1);
+            size += skip;
+        }
+    }
+
+    result
+}
@@ -0,0 +1,53 @@
+//! # Duet
+//!
+//! Both parts run on the shared [duet interpreter], which pauses on every `snd` and `rcv`.
+//!
+//! In the first part `snd` plays a sound and `rcv` recovers the last sound played if its
+//! register is not zero. A `rcv` of zero is skipped by feeding back the value it already holds.
+//!
+//! In the second part two copies of the program send messages to each other. Each program runs
+//! until it blocks waiting for input, with every value it sends queued for the other. The
+//! programs deadlock once both are blocked with nothing sent in between.
+//!
+//! [duet interpreter]: crate::util::duet
+use crate::util::duet::*;
+
+pub fn parse(input: &str) -> Computer {
+    Computer::parse(input)
+}
+
+pub fn part1(input: &Computer) -> i64 {
+    let mut computer = input.clone();
+    let mut sound = 0;
+
+    loop {
+        match computer.run() {
+            State::Send(value) => sound = value,
+            State::Receive(register) if computer.registers[register] != 0 => return sound,
+            State::Receive(register) => computer.input(computer.registers[register]),
+            State::Halted => unreachable!(),
+        }
+    }
+}
+
+pub fn part2(input: &Computer) -> usize {
+    let mut programs = [input.clone(), input.clone()];
+    programs[1].registers[(b'p' - b'a') as usize] = 1;
+    let mut sent = [0, 0];
+
+    loop {
+        let mut progress = false;
+
+        for id in 0..2 {
+            while let State::Send(value) = programs[id].run() {
+                programs[1 - id].input(value);
+                sent[id] += 1;
+                progress = true;
+            }
+        }
+
+        if !progress {
+            return sent[1];
+        }
+    }
+}
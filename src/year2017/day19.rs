@@ -0,0 +1,53 @@
+//! # A Series of Tubes
+//!
+//! Follows the path one step at a time, collecting letters along the way. At each `+` corner
+//! the packet turns left or right, whichever direction isn't empty. The path ends when the
+//! packet runs off the end of a line.
+//!
+//! Lines may have trailing spaces trimmed so the grid is padded with spaces to the width of
+//! the longest line.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> (String, usize) {
+    let width = input.lines().map(str::len).max().unwrap();
+    let mut bytes = Vec::new();
+    for line in input.lines() {
+        bytes.extend_from_slice(line.as_bytes());
+        bytes.resize(bytes.len().next_multiple_of(width), b' ');
+    }
+    let grid = Grid { width: width as i32, height: (bytes.len() / width) as i32, bytes };
+
+    let empty = |point: Point| !grid.contains(point) || grid[point] == b' ';
+    let mut position = Point::new(grid.bytes.iter().position(|&b| b == b'|').unwrap() as i32, 0);
+    let mut direction = DOWN;
+    let mut letters = String::new();
+    let mut steps = 0;
+
+    while !empty(position) {
+        match grid[position] {
+            b'+' => {
+                direction = if empty(position + direction.clockwise()) {
+                    direction.counter_clockwise()
+                } else {
+                    direction.clockwise()
+                };
+            }
+            b @ b'A'..=b'Z' => letters.push(b as char),
+            _ => (),
+        }
+
+        position += direction;
+        steps += 1;
+    }
+
+    (letters, steps)
+}
+
+pub fn part1(input: &(String, usize)) -> String {
+    input.0.clone()
+}
+
+pub fn part2(input: &(String, usize)) -> usize {
+    input.1
+}
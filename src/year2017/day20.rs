@@ -0,0 +1,73 @@
+//! # Particle Swarm
+//!
+//! Position after `t` ticks has the closed form `p + vt + at(t + 1) / 2`, as velocity is
+//! updated before position on each tick. In the long term the particle with the smallest
+//! acceleration stays closest, with ties broken by velocity then position. Evaluating the
+//! closed form at a very large time handles all of this at once.
+//!
+//! The second part simulates the particles tick by tick, removing any that share a position.
+//! Collisions in practice all happen early on, so the simulation stops once a generous number
+//! of ticks pass without any.
+use crate::util::hash::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+type Vector = [i64; 3];
+
+#[derive(Clone, Copy)]
+pub struct Particle {
+    position: Vector,
+    velocity: Vector,
+    acceleration: Vector,
+}
+
+impl Particle {
+    fn at(&self, t: i64) -> i64 {
+        (0..3)
+            .map(|i| {
+                (self.position[i] + self.velocity[i] * t + self.acceleration[i] * t * (t + 1) / 2)
+                    .abs()
+            })
+            .sum()
+    }
+
+    fn tick(&mut self) {
+        for i in 0..3 {
+            self.velocity[i] += self.acceleration[i];
+            self.position[i] += self.velocity[i];
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Particle> {
+    input
+        .iter_signed()
+        .chunk::<3>()
+        .chunk::<3>()
+        .map(|[position, velocity, acceleration]| Particle { position, velocity, acceleration })
+        .collect()
+}
+
+pub fn part1(input: &[Particle]) -> usize {
+    (0..input.len()).min_by_key(|&i| input[i].at(1_000_000)).unwrap()
+}
+
+pub fn part2(input: &[Particle]) -> usize {
+    let mut particles = input.to_vec();
+    let mut quiet = 0;
+
+    while quiet < 100 {
+        particles.iter_mut().for_each(Particle::tick);
+
+        let mut counts = FastMap::with_capacity(particles.len());
+        for particle in &particles {
+            *counts.entry(particle.position).or_insert(0) += 1;
+        }
+
+        let before = particles.len();
+        particles.retain(|particle| counts[&particle.position] == 1);
+        quiet = if particles.len() == before { quiet + 1 } else { 0 };
+    }
+
+    particles.len()
+}
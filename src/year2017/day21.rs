@@ -0,0 +1,118 @@
+//! # Fractal Art
+//!
+//! Patterns are stored as a flat `Vec<bool>` in reading order, their size implied by the
+//! length. Each rule is inserted into a map once for every distinct rotation and reflection of
+//! its input so that lookups need no further transformation.
+//!
+//! After three iterations each 3x3 square becomes a 9x9 square that divides into nine 3x3
+//! squares. These evolve independently of each other from then on, so instead of the whole
+//! image we only need to track how many of each distinct 3x3 pattern there are, expanding every
+//! pattern three iterations at a time.
+use crate::util::hash::*;
+
+type Pattern = Vec<bool>;
+
+pub fn parse(input: &str) -> FastMap<Pattern, Pattern> {
+    let pattern =
+        |s: &str| -> Pattern { s.bytes().filter(|&b| b != b'/').map(|b| b == b'#').collect() };
+    let mut rules = FastMap::new();
+
+    for line in input.lines() {
+        let (from, to) = line.split_once(" => ").unwrap();
+        let mut from = pattern(from);
+        let to = pattern(to);
+
+        for _ in 0..2 {
+            for _ in 0..4 {
+                rules.insert(from.clone(), to.clone());
+                from = rotate(&from);
+            }
+            from = flip(&from);
+        }
+    }
+
+    rules
+}
+
+pub fn part1(input: &FastMap<Pattern, Pattern>) -> usize {
+    pixels(input, 5)
+}
+
+pub fn part2(input: &FastMap<Pattern, Pattern>) -> usize {
+    pixels(input, 18)
+}
+
+/// Number of pixels that are on after `iterations` iterations.
+pub fn pixels(input: &FastMap<Pattern, Pattern>, iterations: usize) -> usize {
+    let start = vec![false, true, false, false, false, true, true, true, true];
+    let mut counts = FastMap::build([(start, 1)]);
+    let mut remaining = iterations;
+
+    while remaining >= 3 {
+        let mut next = FastMap::new();
+
+        for (pattern, count) in counts {
+            let image = enhance(input, pattern, 3);
+            for square in split(&image, 3) {
+                *next.entry(square).or_insert(0) += count;
+            }
+        }
+
+        counts = next;
+        remaining -= 3;
+    }
+
+    counts
+        .into_iter()
+        .map(|(pattern, count)| {
+            count * enhance(input, pattern, remaining).iter().filter(|&&on| on).count()
+        })
+        .sum()
+}
+
+fn enhance(rules: &FastMap<Pattern, Pattern>, mut image: Pattern, iterations: usize) -> Pattern {
+    for _ in 0..iterations {
+        let size = image.len().isqrt();
+        let block = if size.is_multiple_of(2) { 2 } else { 3 };
+        let squares: Vec<_> =
+            split(&image, block).into_iter().map(|square| &rules[&square]).collect();
+        image = join(&squares, size / block);
+    }
+    image
+}
+
+/// Divides an image into squares of size `block`, in reading order.
+fn split(image: &[bool], block: usize) -> Vec<Pattern> {
+    let size = image.len().isqrt();
+    let per_row = size / block;
+
+    (0..per_row * per_row)
+        .map(|index| {
+            let (bx, by) = ((index % per_row) * block, (index / per_row) * block);
+            (0..block * block).map(|i| image[(by + i / block) * size + bx + i % block]).collect()
+        })
+        .collect()
+}
+
+/// Inverse of [`split`], combining a `per_row` by `per_row` arrangement of squares.
+fn join(squares: &[&Pattern], per_row: usize) -> Pattern {
+    let block = squares[0].len().isqrt();
+    let size = per_row * block;
+
+    (0..size * size)
+        .map(|index| {
+            let (x, y) = (index % size, index / size);
+            squares[(y / block) * per_row + x / block][(y % block) * block + x % block]
+        })
+        .collect()
+}
+
+fn rotate(pattern: &[bool]) -> Pattern {
+    let size = pattern.len().isqrt();
+    (0..pattern.len()).map(|i| pattern[(size - 1 - i % size) * size + i / size]).collect()
+}
+
+fn flip(pattern: &[bool]) -> Pattern {
+    let size = pattern.len().isqrt();
+    (0..pattern.len()).map(|i| pattern[(i / size) * size + size - 1 - i % size]).collect()
+}
@@ -0,0 +1,77 @@
+//! # Sporifica Virus
+//!
+//! The grid is infinite in theory, but the virus carrier stays within a few hundred nodes of
+//! the center in practice. Node states are stored in a dense [`Grid`] that is much faster than
+//! a hash map for the ten million bursts of the second part. If the carrier ever reaches the
+//! edge then the grid is enlarged to three times its size, keeping the existing nodes in the
+//! middle.
+//!
+//! States are numbered in the order clean, weakened, infected, flagged so that both parts
+//! advance a node's state by adding a constant, skipping the intermediate states in the first
+//! part.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+const CLEAN: u8 = 0;
+const WEAKENED: u8 = 1;
+const INFECTED: u8 = 2;
+const FLAGGED: u8 = 3;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    let grid = Grid::parse(input);
+    let bytes = grid.bytes.iter().map(|&b| if b == b'#' { INFECTED } else { CLEAN }).collect();
+    Grid { width: grid.width, height: grid.height, bytes }
+}
+
+pub fn part1(input: &Grid<u8>) -> usize {
+    infections(input, 10_000, 2)
+}
+
+pub fn part2(input: &Grid<u8>) -> usize {
+    infections(input, 10_000_000, 1)
+}
+
+/// Number of bursts that cause an infection, with each burst advancing the state of the current
+/// node by `delta`.
+pub fn infections(input: &Grid<u8>, bursts: usize, delta: u8) -> usize {
+    let mut grid = input.clone();
+    let mut position = Point::new(grid.width / 2, grid.height / 2);
+    let mut direction = UP;
+    let mut count = 0;
+
+    for _ in 0..bursts {
+        if !grid.contains(position) {
+            position += Point::new(grid.width, grid.height);
+            grid = enlarge(&grid);
+        }
+
+        let state = grid[position];
+        direction = match state {
+            CLEAN => direction.counter_clockwise(),
+            WEAKENED => direction,
+            INFECTED => direction.clockwise(),
+            FLAGGED => direction * -1,
+            _ => unreachable!(),
+        };
+
+        let next = (state + delta) % 4;
+        grid[position] = next;
+        count += (next == INFECTED) as usize;
+        position += direction;
+    }
+
+    count
+}
+
+fn enlarge(grid: &Grid<u8>) -> Grid<u8> {
+    let mut larger = Grid::new(grid.width * 3, grid.height * 3, CLEAN);
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            larger[point + Point::new(grid.width, grid.height)] = grid[point];
+        }
+    }
+
+    larger
+}
@@ -0,0 +1,44 @@
+//! # Coprocessor Conflagration
+//!
+//! The first part runs the program on the shared [duet interpreter] counting `mul`
+//! instructions.
+//!
+//! Decompiling the program shows that the second part counts the numbers from `b` to `c`
+//! inclusive, in steps of 17, that are not prime. The inner loops test every possible pair of
+//! factors, so the program would take far too long to run directly. Instead the bounds are
+//! read from the constants in the first few instructions, and each number is tested with
+//! trial division.
+//!
+//! [duet interpreter]: crate::util::duet
+use crate::util::duet::*;
+use crate::util::parse::*;
+
+pub struct Input {
+    computer: Computer,
+    constants: Vec<i64>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let computer = Computer::parse(input);
+    let constants = input.lines().map(|line| line.iter_signed().next().unwrap_or(0)).collect();
+    Input { computer, constants }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let mut computer = input.computer.clone();
+    computer.run();
+    computer.multiplies
+}
+
+pub fn part2(input: &Input) -> usize {
+    let numbers = &input.constants;
+    let b = numbers[0] * numbers[4] - numbers[5];
+    let c = b - numbers[7];
+    let step = -numbers[numbers.len() - 2];
+
+    (b..=c).step_by(step as usize).filter(|&n| !prime(n)).count()
+}
+
+fn prime(n: i64) -> bool {
+    n >= 2 && (2..).take_while(|f| f * f <= n).all(|f| n % f != 0)
+}
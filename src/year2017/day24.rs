@@ -0,0 +1,43 @@
+//! # Electromagnetic Moat
+//!
+//! A depth first search tries every possible bridge, tracking the components already used
+//! with a bitmask. Each bridge found updates both answers at once, the strongest overall and
+//! the strongest of the longest, compared as a tuple of length then strength.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+struct Search {
+    components: Vec<[u32; 2]>,
+    strongest: u32,
+    longest: (u32, u32),
+}
+
+pub fn parse(input: &str) -> (u32, u32) {
+    let components = input.iter_unsigned().chunk::<2>().collect();
+    let mut search = Search { components, strongest: 0, longest: (0, 0) };
+    search.dfs(0, 0, 0, 0);
+    (search.strongest, search.longest.1)
+}
+
+pub fn part1(input: &(u32, u32)) -> u32 {
+    input.0
+}
+
+pub fn part2(input: &(u32, u32)) -> u32 {
+    input.1
+}
+
+impl Search {
+    fn dfs(&mut self, port: u32, used: u64, length: u32, strength: u32) {
+        self.strongest = self.strongest.max(strength);
+        self.longest = self.longest.max((length, strength));
+
+        for i in 0..self.components.len() {
+            let [a, b] = self.components[i];
+            if used & (1 << i) == 0 && (a == port || b == port) {
+                let other = if a == port { b } else { a };
+                self.dfs(other, used | (1 << i), length + 1, strength + a + b);
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! # The Halting Problem
+//!
+//! Simulates the Turing machine directly. The tape grows at either end as needed, stored in a
+//! [`VecDeque`] so that adding a slot to the left is cheap.
+//!
+//! This puzzle only has one part.
+use crate::util::parse::*;
+use std::collections::VecDeque;
+
+/// Value to write, direction to move and next state.
+type Action = (u8, isize, usize);
+
+pub struct Input {
+    start: usize,
+    steps: usize,
+    states: Vec<[Action; 2]>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let letter =
+        |line: &str| (line.trim_end_matches([':', '.']).bytes().last().unwrap() - b'A') as usize;
+    let blocks: Vec<Vec<_>> =
+        input.split("\n\n").map(|block| block.lines().map(str::trim).collect()).collect();
+
+    let start = letter(blocks[0][0]);
+    let steps = blocks[0][1].unsigned();
+    let states = blocks[1..]
+        .iter()
+        .map(|lines| {
+            let action = |offset: usize| {
+                let write = lines[offset].ends_with("1.") as u8;
+                let direction = if lines[offset + 1].ends_with("right.") { 1 } else { -1 };
+                (write, direction, letter(lines[offset + 2]))
+            };
+            [action(2), action(6)]
+        })
+        .collect();
+
+    Input { start, steps, states }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let mut tape = VecDeque::from([0]);
+    let mut cursor = 0;
+    let mut state = input.start;
+
+    for _ in 0..input.steps {
+        let (write, direction, next) = input.states[state][tape[cursor] as usize];
+        tape[cursor] = write;
+        state = next;
+
+        match cursor.checked_add_signed(direction) {
+            None => tape.push_front(0),
+            Some(index) if index == tape.len() => {
+                tape.push_back(0);
+                cursor = index;
+            }
+            Some(index) => cursor = index,
+        }
+    }
+
+    tape.iter().filter(|&&value| value == 1).count()
+}
@@ -0,0 +1,28 @@
+//! # Chronal Calibration
+//!
+//! The second part loops over the changes until a frequency repeats, remembering each one
+//! seen so far in a set.
+use crate::util::hash::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<i32> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i32]) -> i32 {
+    input.iter().sum()
+}
+
+pub fn part2(input: &[i32]) -> i32 {
+    let mut frequency = 0;
+    let mut seen = FastSet::build([0]);
+
+    for change in input.iter().cycle() {
+        frequency += change;
+        if !seen.insert(frequency) {
+            break;
+        }
+    }
+
+    frequency
+}
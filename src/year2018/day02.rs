@@ -0,0 +1,39 @@
+//! # Inventory Management System
+//!
+//! For the second part, two ids differ by exactly one character when they are identical after
+//! removing the character at that position. Inserting every id with each position blanked out
+//! into a set finds the pair in a single pass.
+use crate::util::hash::*;
+
+pub fn parse(input: &str) -> Vec<&[u8]> {
+    input.lines().map(str::as_bytes).collect()
+}
+
+pub fn part1(input: &[&[u8]]) -> usize {
+    let mut twos = 0;
+    let mut threes = 0;
+
+    for id in input {
+        let mut counts = [0; 26];
+        id.iter().for_each(|&b| counts[(b - b'a') as usize] += 1);
+        twos += counts.contains(&2) as usize;
+        threes += counts.contains(&3) as usize;
+    }
+
+    twos * threes
+}
+
+pub fn part2(input: &[&[u8]]) -> String {
+    let mut seen = FastSet::new();
+
+    for id in input {
+        for i in 0..id.len() {
+            let key = (i, &id[..i], &id[i + 1..]);
+            if !seen.insert(key) {
+                return [&id[..i], &id[i + 1..]].concat().iter().map(|&b| b as char).collect();
+            }
+        }
+    }
+
+    unreachable!()
+}
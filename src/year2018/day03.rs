@@ -0,0 +1,41 @@
+//! # No Matter How You Slice It
+//!
+//! Counts how many claims cover each square inch of fabric. The claim that doesn't overlap any
+//! other is the one whose squares all have a count of one.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub struct Input {
+    claims: Vec<[usize; 5]>,
+    fabric: Vec<u32>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let claims: Vec<_> = input.iter_unsigned().chunk::<5>().collect();
+    let mut fabric = vec![0; 1000 * 1000];
+
+    for &[_, x, y, width, height] in &claims {
+        for row in y..y + height {
+            fabric[row * 1000 + x..row * 1000 + x + width].iter_mut().for_each(|count| *count += 1);
+        }
+    }
+
+    Input { claims, fabric }
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.fabric.iter().filter(|&&count| count > 1).count()
+}
+
+pub fn part2(input: &Input) -> usize {
+    let [id, ..] = *input
+        .claims
+        .iter()
+        .find(|&&[_, x, y, width, height]| {
+            (y..y + height).all(|row| {
+                input.fabric[row * 1000 + x..row * 1000 + x + width].iter().all(|&count| count == 1)
+            })
+        })
+        .unwrap();
+    id
+}
@@ -0,0 +1,47 @@
+//! # Repose Record
+//!
+//! Timestamps sort lexically, so sorting the lines puts the records in chronological order.
+//! Each guard then gets a histogram counting how often they were asleep during each minute.
+use crate::util::hash::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> FastMap<usize, [u32; 60]> {
+    let mut lines: Vec<_> = input.lines().collect();
+    lines.sort_unstable();
+
+    let mut guards = FastMap::new();
+    let mut guard = 0;
+    let mut asleep = 0;
+
+    for line in lines {
+        // Year, month, day and hour come first.
+        let mut numbers = line.iter_unsigned().skip(4);
+        let minute = numbers.next().unwrap();
+
+        if line.ends_with("shift") {
+            guard = numbers.next().unwrap();
+        } else if line.ends_with("asleep") {
+            asleep = minute;
+        } else {
+            let minutes = guards.entry(guard).or_insert([0; 60]);
+            (asleep..minute).for_each(|m| minutes[m] += 1);
+        }
+    }
+
+    guards
+}
+
+pub fn part1(input: &FastMap<usize, [u32; 60]>) -> usize {
+    strategy(input, |minutes| minutes.iter().sum())
+}
+
+pub fn part2(input: &FastMap<usize, [u32; 60]>) -> usize {
+    strategy(input, |minutes| *minutes.iter().max().unwrap())
+}
+
+/// Picks the guard with the highest score then their most frequently asleep minute.
+fn strategy(input: &FastMap<usize, [u32; 60]>, score: impl Fn(&[u32; 60]) -> u32) -> usize {
+    let (id, minutes) = input.iter().max_by_key(|(_, minutes)| score(minutes)).unwrap();
+    let minute = (0..60).max_by_key(|&m| minutes[m]).unwrap();
+    id * minute
+}
@@ -0,0 +1,37 @@
+//! # Alchemical Reduction
+//!
+//! Reacting units with a stack takes a single pass. Each unit either annihilates the top of the
+//! stack, when they are the same letter in opposite cases, or is pushed onto it.
+//!
+//! Removing a unit type and reacting the full polymer gives the same result as removing it from
+//! the already reacted polymer, so the second part starts from the much shorter output of the
+//! first part.
+pub fn parse(input: &str) -> Vec<u8> {
+    react(input.trim().bytes())
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    input.len()
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    (b'a'..=b'z')
+        .map(|unit| react(input.iter().copied().filter(|b| b.to_ascii_lowercase() != unit)).len())
+        .min()
+        .unwrap()
+}
+
+fn react(polymer: impl Iterator<Item = u8>) -> Vec<u8> {
+    let mut stack: Vec<u8> = Vec::new();
+
+    for unit in polymer {
+        // Letters in opposite cases differ only in the bit with value 32.
+        if stack.last().is_some_and(|&top| top ^ unit == 32) {
+            stack.pop();
+        } else {
+            stack.push(unit);
+        }
+    }
+
+    stack
+}
@@ -0,0 +1,65 @@
+//! # Chronal Coordinates
+//!
+//! Brute force over every location within the bounding box of the coordinates. An area is
+//! infinite if it includes any location on the edge of the bounding box, as every location
+//! further out in the same direction is then also closest to the same coordinate.
+//!
+//! Similarly the safe region in the second part lies inside the bounding box, provided that the
+//! threshold divided by the number of coordinates is small compared to its size.
+use crate::util::iter::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Vec<Point> {
+    input.iter_signed().chunk::<2>().map(|[x, y]| Point::new(x, y)).collect()
+}
+
+pub fn part1(input: &[Point]) -> usize {
+    let (min, max) = bounds(input);
+    let mut areas = vec![0; input.len()];
+    let mut infinite = vec![false; input.len()];
+
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            let point = Point::new(x, y);
+            let distances: Vec<_> =
+                input.iter().map(|&coordinate| point.manhattan(coordinate)).collect();
+            let closest = *distances.iter().min().unwrap();
+
+            let mut nearest = (0..input.len()).filter(|&i| distances[i] == closest);
+            if let (Some(index), None) = (nearest.next(), nearest.next()) {
+                areas[index] += 1;
+                infinite[index] |= x == min.x || x == max.x || y == min.y || y == max.y;
+            }
+        }
+    }
+
+    (0..input.len()).filter(|&i| !infinite[i]).map(|i| areas[i]).max().unwrap()
+}
+
+pub fn part2(input: &[Point]) -> usize {
+    region(input, 10_000)
+}
+
+/// Number of locations with a total distance to all coordinates less than `threshold`.
+pub fn region(input: &[Point], threshold: i32) -> usize {
+    let (min, max) = bounds(input);
+    (min.y..=max.y)
+        .flat_map(|y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+        .filter(|&point| {
+            input.iter().map(|&coordinate| point.manhattan(coordinate)).sum::<i32>() < threshold
+        })
+        .count()
+}
+
+fn bounds(input: &[Point]) -> (Point, Point) {
+    let min = Point::new(
+        input.iter().map(|p| p.x).min().unwrap(),
+        input.iter().map(|p| p.y).min().unwrap(),
+    );
+    let max = Point::new(
+        input.iter().map(|p| p.x).max().unwrap(),
+        input.iter().map(|p| p.y).max().unwrap(),
+    );
+    (min, max)
+}
@@ -0,0 +1,87 @@
+//! # The Sum of Its Parts
+//!
+//! [Topological sort](https://en.wikipedia.org/wiki/Topological_sorting) using
+//! [Kahn's algorithm](https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm),
+//! choosing the alphabetically first available step each time. Steps are stored as a bitmask
+//! of their prerequisites, so the available steps are those whose mask is a subset of the
+//! completed steps.
+//!
+//! The second part simulates the workers, advancing time straight to the next step finishing.
+pub struct Input {
+    /// Bitmask of all steps mentioned in the instructions.
+    steps: u32,
+    /// Bitmask of the prerequisites of each step.
+    requires: [u32; 26],
+}
+
+pub fn parse(input: &str) -> Input {
+    let mut steps = 0;
+    let mut requires = [0; 26];
+
+    for line in input.lines() {
+        let bytes = line.as_bytes();
+        let (before, after) = ((bytes[5] - b'A') as usize, (bytes[36] - b'A') as usize);
+        steps |= (1 << before) | (1 << after);
+        requires[after] |= 1 << before;
+    }
+
+    Input { steps, requires }
+}
+
+pub fn part1(input: &Input) -> String {
+    let mut done = 0;
+    let mut order = String::new();
+
+    while done != input.steps {
+        let step = available(input, done, 0).trailing_zeros();
+        done |= 1 << step;
+        order.push((b'A' + step as u8) as char);
+    }
+
+    order
+}
+
+pub fn part2(input: &Input) -> u32 {
+    time(input, 5, 60)
+}
+
+/// Total time for `workers` workers to finish every step, where each step takes `base`
+/// seconds plus its position in the alphabet.
+pub fn time(input: &Input, workers: usize, base: u32) -> u32 {
+    let mut done = 0;
+    let mut started = 0;
+    let mut now = 0;
+    // Finish time and step for each busy worker.
+    let mut busy: Vec<(u32, u32)> = Vec::new();
+
+    while done != input.steps {
+        while busy.len() < workers {
+            let ready = available(input, done, started);
+            if ready == 0 {
+                break;
+            }
+            let step = ready.trailing_zeros();
+            started |= 1 << step;
+            busy.push((now + base + step + 1, step));
+        }
+
+        now = busy.iter().map(|&(finish, _)| finish).min().unwrap();
+        busy.retain(|&(finish, step)| {
+            if finish == now {
+                done |= 1 << step;
+            }
+            finish > now
+        });
+    }
+
+    now
+}
+
+/// Steps not yet done or started whose prerequisites are all done.
+fn available(input: &Input, done: u32, started: u32) -> u32 {
+    (0..26)
+        .filter(|&step| {
+            input.steps & !done & !started & (1 << step) != 0 && input.requires[step] & !done == 0
+        })
+        .fold(0, |mask, step| mask | (1 << step))
+}
@@ -0,0 +1,43 @@
+//! # Memory Maneuver
+//!
+//! Recursively parses the tree in one pass, computing both the sum of metadata and the value of
+//! each node as it goes.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> (usize, usize) {
+    let mut numbers = input.iter_unsigned();
+    node(&mut numbers)
+}
+
+pub fn part1(input: &(usize, usize)) -> usize {
+    input.0
+}
+
+pub fn part2(input: &(usize, usize)) -> usize {
+    input.1
+}
+
+/// Sum of all metadata and value of the node.
+fn node(numbers: &mut impl Iterator<Item = usize>) -> (usize, usize) {
+    let children = numbers.next().unwrap();
+    let entries = numbers.next().unwrap();
+
+    let mut total = 0;
+    let mut values = Vec::with_capacity(children);
+    for _ in 0..children {
+        let (sum, value) = node(numbers);
+        total += sum;
+        values.push(value);
+    }
+
+    let metadata: Vec<_> = numbers.take(entries).collect();
+    total += metadata.iter().sum::<usize>();
+
+    let value = if children == 0 {
+        metadata.iter().sum()
+    } else {
+        metadata.iter().filter_map(|&i| values.get(i.wrapping_sub(1))).sum()
+    };
+
+    (total, value)
+}
@@ -0,0 +1,56 @@
+//! # Marble Mania
+//!
+//! The circle is a [doubly linked list](https://en.wikipedia.org/wiki/Doubly_linked_list)
+//! stored as two arrays of the next and previous marble, indexed by marble number. Both
+//! inserting and removing a marble are then constant time.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> [usize; 2] {
+    input.iter_unsigned().chunk::<2>().next().unwrap()
+}
+
+pub fn part1(input: &[usize; 2]) -> u64 {
+    let [players, last] = *input;
+    high_score(players, last)
+}
+
+pub fn part2(input: &[usize; 2]) -> u64 {
+    let [players, last] = *input;
+    high_score(players, last * 100)
+}
+
+pub fn high_score(players: usize, last: usize) -> u64 {
+    let mut next = vec![0; last + 1];
+    let mut prev = vec![0; last + 1];
+    let mut scores = vec![0; players];
+    let mut current = 0;
+
+    for marble in 1..=last {
+        if marble.is_multiple_of(23) {
+            let mut removed = current;
+            for _ in 0..7 {
+                removed = prev[removed];
+            }
+
+            let (before, after) = (prev[removed], next[removed]);
+            next[before] = after;
+            prev[after] = before;
+
+            scores[marble % players] += (marble + removed) as u64;
+            current = after;
+        } else {
+            let before = next[current];
+            let after = next[before];
+
+            next[before] = marble;
+            prev[marble] = before;
+            next[marble] = after;
+            prev[after] = marble;
+
+            current = marble;
+        }
+    }
+
+    scores.into_iter().max().unwrap()
+}
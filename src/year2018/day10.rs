@@ -0,0 +1,45 @@
+//! # The Stars Align
+//!
+//! The message appears when the points are closest together. Their bounding box shrinks
+//! steadily until then and grows afterwards, so the simulation runs until the height of the
+//! box stops decreasing.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> (String, i32) {
+    let points: Vec<_> = input.iter_signed::<i32>().chunk::<4>().collect();
+    let height = |time: i32| {
+        let ys = points.iter().map(|&[_, y, _, dy]| y + dy * time);
+        ys.clone().max().unwrap() - ys.min().unwrap()
+    };
+
+    let mut time = 0;
+    while height(time + 1) < height(time) {
+        time += 1;
+    }
+
+    let stars: Vec<_> =
+        points.iter().map(|&[x, y, dx, dy]| (x + dx * time, y + dy * time)).collect();
+    let (min_x, max_x) =
+        (stars.iter().map(|s| s.0).min().unwrap(), stars.iter().map(|s| s.0).max().unwrap());
+    let (min_y, max_y) =
+        (stars.iter().map(|s| s.1).min().unwrap(), stars.iter().map(|s| s.1).max().unwrap());
+
+    let mut message = String::new();
+    for y in min_y..=max_y {
+        message.push('\n');
+        for x in min_x..=max_x {
+            message.push(if stars.contains(&(x, y)) { '#' } else { '.' });
+        }
+    }
+
+    (message, time)
+}
+
+pub fn part1(input: &(String, i32)) -> String {
+    input.0.clone()
+}
+
+pub fn part2(input: &(String, i32)) -> i32 {
+    input.1
+}
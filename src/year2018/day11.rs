@@ -0,0 +1,54 @@
+//! # Chronal Charge
+//!
+//! A [summed-area table](https://en.wikipedia.org/wiki/Summed-area_table) gives the total
+//! power of any square in constant time, so every size of square can be checked quickly.
+use crate::util::parse::*;
+
+const SIZE: usize = 300;
+
+pub fn parse(input: &str) -> Vec<i32> {
+    let serial: i32 = input.signed();
+    let mut sat = vec![0; (SIZE + 1) * (SIZE + 1)];
+
+    for y in 1..=SIZE {
+        for x in 1..=SIZE {
+            let rack = x as i32 + 10;
+            let power = ((rack * y as i32 + serial) * rack / 100) % 10 - 5;
+            let index = y * (SIZE + 1) + x;
+            sat[index] = power + sat[index - 1] + sat[index - SIZE - 1] - sat[index - SIZE - 2];
+        }
+    }
+
+    sat
+}
+
+pub fn part1(input: &[i32]) -> String {
+    let (x, y, _) = best(input, 3..=3);
+    format!("{x},{y}")
+}
+
+pub fn part2(input: &[i32]) -> String {
+    let (x, y, size) = best(input, 1..=SIZE);
+    format!("{x},{y},{size}")
+}
+
+/// Top left corner and size of the square with the most power.
+fn best(sat: &[i32], sizes: impl Iterator<Item = usize>) -> (usize, usize, usize) {
+    let mut result = (i32::MIN, 0, 0, 0);
+
+    for size in sizes {
+        for y in size..=SIZE {
+            for x in size..=SIZE {
+                let (top, left) = (y - size, x - size);
+                let stride = SIZE + 1;
+                let power = sat[y * stride + x] - sat[top * stride + x] - sat[y * stride + left]
+                    + sat[top * stride + left];
+                if power > result.0 {
+                    result = (power, left + 1, top + 1, size);
+                }
+            }
+        }
+    }
+
+    (result.1, result.2, result.3)
+}
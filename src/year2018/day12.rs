@@ -0,0 +1,75 @@
+//! # Subterranean Sustainability
+//!
+//! Each generation is computed from a sliding window of five pots, encoded as a 5 bit index
+//! into a table of rules. Empty pots are trimmed from both ends, tracking the position of the
+//! first pot.
+//!
+//! After a while the pattern of plants stops changing and simply shifts along by the same
+//! amount each generation. The second part finds this point with [`Cycle`], comparing only the
+//! pattern and ignoring its position, then extrapolates the position to fifty billion
+//! generations.
+//!
+//! [`Cycle`]: crate::util::cycle::Cycle
+use crate::util::cycle::*;
+
+pub struct Input {
+    rules: [bool; 32],
+    state: Vec<bool>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let mut lines = input.lines();
+    let state = lines.next().unwrap()[15..].bytes().map(|b| b == b'#').collect();
+    let mut rules = [false; 32];
+
+    for line in lines.skip(1) {
+        let bytes = line.as_bytes();
+        let index = bytes[..5].iter().fold(0, |acc, &b| (acc << 1) | (b == b'#') as usize);
+        rules[index] = bytes[9] == b'#';
+    }
+
+    Input { rules, state }
+}
+
+pub fn part1(input: &Input) -> i64 {
+    let mut state = input.state.clone();
+    let mut offset = 0;
+
+    for _ in 0..20 {
+        (state, offset) = generation(&input.rules, &state, offset);
+    }
+
+    sum(&state, offset)
+}
+
+pub fn part2(input: &Input) -> i64 {
+    let target = 50_000_000_000;
+    let step = |(state, offset): &(Vec<bool>, i64)| generation(&input.rules, state, *offset);
+    let cycle = Cycle::detect_by((input.state.clone(), 0), step, |(state, _)| state.clone());
+
+    // Each loop moves the same pattern by the same amount.
+    let (_, first) = cycle.history[cycle.start];
+    let (_, last) = cycle.history[cycle.start + cycle.length];
+    let (state, offset) = cycle.nth(target);
+    sum(state, offset + cycle.repeats(target) as i64 * (last - first))
+}
+
+/// Next generation and the position of its first pot, with empty pots trimmed from each end.
+fn generation(rules: &[bool; 32], state: &[bool], offset: i64) -> (Vec<bool>, i64) {
+    let mut next = Vec::with_capacity(state.len() + 4);
+    let mut window = 0;
+
+    // Each pot can affect the pots up to two away on either side.
+    for pot in state.iter().chain(&[false; 4]) {
+        window = ((window << 1) | *pot as usize) & 31;
+        next.push(rules[window]);
+    }
+
+    let start = next.iter().position(|&p| p).unwrap();
+    let end = next.iter().rposition(|&p| p).unwrap();
+    (next[start..=end].to_vec(), offset - 2 + start as i64)
+}
+
+fn sum(state: &[bool], offset: i64) -> i64 {
+    (0..state.len()).filter(|&i| state[i]).map(|i| offset + i as i64).sum()
+}
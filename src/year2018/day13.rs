@@ -0,0 +1,86 @@
+//! # Mine Cart Madness
+//!
+//! Simulates the carts tick by tick, moving them in reading order and checking for a collision
+//! after each move. Both parts are found by the same simulation, the first crash for part one
+//! then the last cart left after removing every crash for part two.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+#[derive(Clone, Copy)]
+struct Cart {
+    position: Point,
+    direction: Point,
+    turns: u32,
+    crashed: bool,
+}
+
+pub fn parse(input: &str) -> (String, String) {
+    let width = input.lines().map(str::len).max().unwrap();
+    let mut bytes = Vec::new();
+    for line in input.lines() {
+        bytes.extend_from_slice(line.as_bytes());
+        bytes.resize(bytes.len().next_multiple_of(width), b' ');
+    }
+    let grid = Grid { width: width as i32, height: (bytes.len() / width) as i32, bytes };
+
+    let mut carts: Vec<_> = (0..grid.height)
+        .flat_map(|y| (0..grid.width).map(move |x| Point::new(x, y)))
+        .filter(|&position| matches!(grid[position], b'^' | b'v' | b'<' | b'>'))
+        .map(|position| Cart {
+            position,
+            direction: Point::from(grid[position]),
+            turns: 0,
+            crashed: false,
+        })
+        .collect();
+
+    let mut first = None;
+
+    while carts.len() > 1 {
+        carts.sort_unstable_by_key(|cart| (cart.position.y, cart.position.x));
+
+        for i in 0..carts.len() {
+            if carts[i].crashed {
+                continue;
+            }
+
+            let cart = &mut carts[i];
+            cart.position += cart.direction;
+            cart.direction = match grid[cart.position] {
+                b'/' => Point::new(-cart.direction.y, -cart.direction.x),
+                b'\\' => Point::new(cart.direction.y, cart.direction.x),
+                b'+' => {
+                    cart.turns += 1;
+                    match cart.turns % 3 {
+                        1 => cart.direction.counter_clockwise(),
+                        2 => cart.direction,
+                        _ => cart.direction.clockwise(),
+                    }
+                }
+                _ => cart.direction,
+            };
+
+            let position = cart.position;
+            if let Some(j) = (0..carts.len())
+                .find(|&j| j != i && !carts[j].crashed && carts[j].position == position)
+            {
+                first.get_or_insert(position);
+                carts[i].crashed = true;
+                carts[j].crashed = true;
+            }
+        }
+
+        carts.retain(|cart| !cart.crashed);
+    }
+
+    let format = |point: Point| format!("{},{}", point.x, point.y);
+    (format(first.unwrap()), carts.first().map(|cart| format(cart.position)).unwrap_or_default())
+}
+
+pub fn part1(input: &(String, String)) -> String {
+    input.0.clone()
+}
+
+pub fn part2(input: &(String, String)) -> String {
+    input.1.clone()
+}
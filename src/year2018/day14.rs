@@ -0,0 +1,61 @@
+//! # Chocolate Charts
+//!
+//! Appends recipes to a `Vec` of digits, which grows to around twenty million entries for the
+//! second part. Each step adds one or two digits, so the last two positions are both checked
+//! for a match with the target sequence.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> &str {
+    input.trim()
+}
+
+pub fn part1(input: &str) -> String {
+    let count: usize = input.unsigned();
+    let mut recipes = Recipes::new();
+
+    while recipes.scores.len() < count + 10 {
+        recipes.step();
+    }
+
+    recipes.scores[count..count + 10].iter().map(|&d| (b'0' + d) as char).collect()
+}
+
+pub fn part2(input: &str) -> usize {
+    let target: Vec<_> = input.bytes().map(|b| b.to_decimal()).collect();
+    let mut recipes = Recipes::new();
+
+    loop {
+        recipes.step();
+        let scores = &recipes.scores;
+
+        for end in [scores.len() - 1, scores.len()] {
+            if end >= target.len() && scores[end - target.len()..end] == target[..] {
+                return end - target.len();
+            }
+        }
+    }
+}
+
+struct Recipes {
+    scores: Vec<u8>,
+    first: usize,
+    second: usize,
+}
+
+impl Recipes {
+    fn new() -> Self {
+        Recipes { scores: vec![3, 7], first: 0, second: 1 }
+    }
+
+    fn step(&mut self) {
+        let sum = self.scores[self.first] + self.scores[self.second];
+        if sum >= 10 {
+            self.scores.push(1);
+        }
+        self.scores.push(sum % 10);
+
+        let size = self.scores.len();
+        self.first = (self.first + 1 + self.scores[self.first] as usize) % size;
+        self.second = (self.second + 1 + self.scores[self.second] as usize) % size;
+    }
+}
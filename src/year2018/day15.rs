@@ -0,0 +1,151 @@
+//! # Beverage Bandits
+//!
+//! Simulates the battle following the rules exactly, as any deviation in tie breaking changes
+//! the outcome.
+//!
+//! To move, a [breadth first search](https://en.wikipedia.org/wiki/Breadth-first_search) from
+//! the unit finds the nearest square in range of an enemy, breaking ties in reading order. A
+//! second search from that square back towards the unit picks the first step, so that ties
+//! between equally short paths are also broken in reading order. Checking neighbors in reading
+//! order (up, left, right, down) means each search visits squares at the same distance in the
+//! right order without any sorting.
+//!
+//! The second part increases the elves' attack power until they win without a single loss,
+//! stopping each battle as soon as an elf dies.
+use crate::util::grid::*;
+use crate::util::point::*;
+use std::collections::VecDeque;
+
+const READING: [Point; 4] = [UP, LEFT, RIGHT, DOWN];
+
+#[derive(Clone, Copy)]
+struct Unit {
+    position: Point,
+    elf: bool,
+    health: i32,
+    attack: i32,
+}
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(input: &Grid<u8>) -> i32 {
+    fight(input, 3, false).unwrap()
+}
+
+pub fn part2(input: &Grid<u8>) -> i32 {
+    (4..).find_map(|attack| fight(input, attack, true)).unwrap()
+}
+
+/// Outcome of the battle, or `None` if an elf dies when `no_losses` is set.
+fn fight(input: &Grid<u8>, elf_attack: i32, no_losses: bool) -> Option<i32> {
+    let mut grid = input.clone();
+    let mut units: Vec<_> = (0..grid.height)
+        .flat_map(|y| (0..grid.width).map(move |x| Point::new(x, y)))
+        .filter(|&p| matches!(grid[p], b'E' | b'G'))
+        .map(|position| {
+            let elf = grid[position] == b'E';
+            Unit { position, elf, health: 200, attack: if elf { elf_attack } else { 3 } }
+        })
+        .collect();
+
+    for round in 0.. {
+        units.sort_unstable_by_key(|unit| (unit.position.y, unit.position.x));
+
+        for i in 0..units.len() {
+            if units[i].health <= 0 {
+                continue;
+            }
+
+            let Unit { elf, attack, .. } = units[i];
+            let enemy = if elf { b'G' } else { b'E' };
+            if !units.iter().any(|other| other.health > 0 && other.elf != elf) {
+                let health: i32 =
+                    units.iter().filter(|unit| unit.health > 0).map(|unit| unit.health).sum();
+                return Some(round * health);
+            }
+
+            // Move unless already next to an enemy.
+            let position = units[i].position;
+            if !READING.iter().any(|&offset| grid[position + offset] == enemy) {
+                if let Some(next) = step(&grid, position, enemy) {
+                    grid[position] = b'.';
+                    grid[next] = grid_unit(elf);
+                    units[i].position = next;
+                }
+            }
+
+            // Attack the adjacent enemy with the least health, ties in reading order.
+            let position = units[i].position;
+            let target = READING
+                .iter()
+                .map(|&offset| position + offset)
+                .filter_map(|p| {
+                    units
+                        .iter()
+                        .position(|unit| unit.health > 0 && unit.elf != elf && unit.position == p)
+                })
+                .min_by_key(|&j| units[j].health);
+
+            if let Some(j) = target {
+                units[j].health -= attack;
+                if units[j].health <= 0 {
+                    if units[j].elf && no_losses {
+                        return None;
+                    }
+                    grid[units[j].position] = b'.';
+                }
+            }
+        }
+
+        units.retain(|unit| unit.health > 0);
+    }
+
+    unreachable!()
+}
+
+fn grid_unit(elf: bool) -> u8 {
+    if elf {
+        b'E'
+    } else {
+        b'G'
+    }
+}
+
+/// First step towards the nearest square in range of an enemy, if any are reachable.
+fn step(grid: &Grid<u8>, start: Point, enemy: u8) -> Option<Point> {
+    let in_range = |p: Point| READING.iter().any(|&offset| grid[p + offset] == enemy);
+    let target = bfs(grid, start).into_iter().find(|&(p, _)| in_range(p))?.0;
+
+    // The first step is the neighbor of the start closest to the target, ties in reading order.
+    let distances = bfs(grid, target);
+    READING
+        .iter()
+        .map(|&offset| start + offset)
+        .filter_map(|p| distances.iter().find(|&&(q, _)| q == p).copied())
+        .min_by_key(|&(p, distance)| (distance, p.y, p.x))
+        .map(|(p, _)| p)
+}
+
+/// Squares reachable from `start` through open squares, sorted by distance then reading order.
+fn bfs(grid: &Grid<u8>, start: Point) -> Vec<(Point, u32)> {
+    let mut seen = grid.same_size_with(false);
+    let mut todo = VecDeque::from([(start, 0)]);
+    let mut reached = vec![(start, 0)];
+    seen[start] = true;
+
+    while let Some((point, distance)) = todo.pop_front() {
+        for offset in READING {
+            let next = point + offset;
+            if grid[next] == b'.' && !seen[next] {
+                seen[next] = true;
+                todo.push_back((next, distance + 1));
+                reached.push((next, distance + 1));
+            }
+        }
+    }
+
+    reached.sort_unstable_by_key(|&(p, distance)| (distance, p.y, p.x));
+    reached
+}
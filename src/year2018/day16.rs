@@ -0,0 +1,72 @@
+//! # Chronal Classification
+//!
+//! Each sample is checked against all sixteen opcodes from the shared [elfcode] module,
+//! producing a bitmask of the opcodes that behave the same way. Intersecting the masks of every
+//! sample with the same number narrows down the candidates for each number.
+//!
+//! Some number then has only one candidate left. Removing that opcode from every other number
+//! leaves another number with a single candidate, and so on until all are known.
+//!
+//! [elfcode]: crate::util::elfcode
+use crate::util::elfcode::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub struct Input {
+    /// Opcode number and bitmask of the opcodes that match each sample.
+    samples: Vec<(usize, u16)>,
+    program: Vec<[usize; 4]>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let (first, second) = input.split_once("\n\n\n\n").unwrap_or((input, ""));
+
+    let numbers: Vec<_> = first.iter_unsigned().collect();
+    let samples = numbers
+        .chunks_exact(12)
+        .map(|numbers| {
+            let before = &numbers[0..4];
+            let [number, a, b, c] = numbers[4..8].try_into().unwrap();
+            let after = &numbers[8..12];
+
+            let matches = Opcode::ALL.iter().enumerate().fold(0, |mask, (i, opcode)| {
+                let mut registers = before.to_vec();
+                opcode.execute(a, b, c, &mut registers);
+                if registers == after {
+                    mask | (1 << i)
+                } else {
+                    mask
+                }
+            });
+
+            (number, matches)
+        })
+        .collect();
+
+    let program = second.iter_unsigned().chunk::<4>().collect();
+    Input { samples, program }
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.samples.iter().filter(|(_, matches)| matches.count_ones() >= 3).count()
+}
+
+pub fn part2(input: &Input) -> usize {
+    let mut candidates = [u16::MAX; 16];
+    for &(number, matches) in &input.samples {
+        candidates[number] &= matches;
+    }
+
+    let mut opcodes = [Opcode::Addr; 16];
+    while let Some(number) = candidates.iter().position(|mask| mask.count_ones() == 1) {
+        let mask = candidates[number];
+        opcodes[number] = Opcode::ALL[mask.trailing_zeros() as usize];
+        candidates.iter_mut().for_each(|candidate| *candidate &= !mask);
+    }
+
+    let mut registers = [0; 4];
+    for &[number, a, b, c] in &input.program {
+        opcodes[number].execute(a, b, c, &mut registers);
+    }
+    registers[0]
+}
@@ -0,0 +1,104 @@
+//! # Reservoir Research
+//!
+//! Water is simulated with a recursive depth first fill. Water falls until it hits clay or
+//! settled water, then spreads sideways in both directions. If both sides end at a wall then
+//! the whole row settles, otherwise the water spills over the edge and keeps falling. Flowing
+//! water (`|`) and settled water (`~`) are tracked separately so that streams that merge
+//! don't fill the same area twice.
+use crate::util::grid::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> (usize, usize) {
+    let veins: Vec<_> = input
+        .lines()
+        .map(|line| {
+            let [a, b, c] = line.iter_signed().chunk::<3>().next().unwrap();
+            if line.starts_with('x') {
+                (a, a, b, c)
+            } else {
+                (b, c, a, a)
+            }
+        })
+        .collect();
+
+    // Leave a column of sand on either side for water spilling over the outermost clay.
+    let min_x = veins.iter().map(|v| v.0).min().unwrap() - 1;
+    let max_x = veins.iter().map(|v| v.1).max().unwrap() + 1;
+    let min_y = veins.iter().map(|v| v.2).min().unwrap();
+    let max_y = veins.iter().map(|v| v.3).max().unwrap();
+
+    let mut grid = Grid::new(max_x - min_x + 1, max_y + 1, b'.');
+    for &(x1, x2, y1, y2) in &veins {
+        for y in y1..=y2 {
+            for x in x1..=x2 {
+                grid[Point::new(x - min_x, y)] = b'#';
+            }
+        }
+    }
+
+    fill(&mut grid, Point::new(500 - min_x, 1));
+
+    let rows = &grid.bytes[(min_y * grid.width) as usize..];
+    let settled = rows.iter().filter(|&&b| b == b'~').count();
+    let flowing = rows.iter().filter(|&&b| b == b'|').count();
+    (settled + flowing, settled)
+}
+
+pub fn part1(input: &(usize, usize)) -> usize {
+    input.0
+}
+
+pub fn part2(input: &(usize, usize)) -> usize {
+    input.1
+}
+
+/// Returns `true` if water at this point is held up, either by clay or settled water.
+fn fill(grid: &mut Grid<u8>, point: Point) -> bool {
+    if !grid.contains(point) {
+        return false;
+    }
+    match grid[point] {
+        b'#' | b'~' => return true,
+        b'|' => return false,
+        _ => (),
+    }
+
+    grid[point] = b'|';
+    if !fill(grid, point + DOWN) {
+        return false;
+    }
+
+    let left = spread(grid, point, LEFT);
+    let right = spread(grid, point, RIGHT);
+
+    if left && right {
+        grid[point] = b'~';
+        for direction in [LEFT, RIGHT] {
+            let mut next = point + direction;
+            while grid[next] == b'|' {
+                grid[next] = b'~';
+                next += direction;
+            }
+        }
+    }
+
+    left && right
+}
+
+/// Spreads water sideways, returning `true` if it reaches a wall without spilling.
+fn spread(grid: &mut Grid<u8>, point: Point, direction: Point) -> bool {
+    let mut next = point + direction;
+
+    loop {
+        if grid[next] == b'#' {
+            return true;
+        }
+        grid[next] = b'|';
+        if !fill(grid, next + DOWN) {
+            return false;
+        }
+        next += direction;
+    }
+}
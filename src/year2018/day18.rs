@@ -0,0 +1,52 @@
+//! # Settlers of The North Pole
+//!
+//! Simulates the lumber collection area as a cellular automaton. For the second part, the area
+//! eventually falls into a repeating cycle. [`Cycle`] remembers the minute each state was first
+//! seen, so that once a state repeats the state after a billion minutes can be found directly.
+//!
+//! [`Cycle`]: crate::util::cycle::Cycle
+use crate::util::cycle::*;
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(input: &Grid<u8>) -> usize {
+    let mut grid = input.clone();
+    for _ in 0..10 {
+        grid = step(&grid);
+    }
+    value(&grid)
+}
+
+pub fn part2(input: &Grid<u8>) -> usize {
+    let cycle = Cycle::detect(input.clone(), step);
+    value(cycle.nth(1_000_000_000))
+}
+
+fn step(grid: &Grid<u8>) -> Grid<u8> {
+    let mut next = grid.clone();
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            let count = |acre| grid.diagonal(point).filter(|&next| grid[next] == acre).count();
+
+            next[point] = match grid[point] {
+                b'.' if count(b'|') >= 3 => b'|',
+                b'|' if count(b'#') >= 3 => b'#',
+                b'#' if count(b'#') == 0 || count(b'|') == 0 => b'.',
+                acre => acre,
+            };
+        }
+    }
+
+    next
+}
+
+fn value(grid: &Grid<u8>) -> usize {
+    let count = |acre| grid.bytes.iter().filter(|&&b| b == acre).count();
+    count(b'|') * count(b'#')
+}
@@ -0,0 +1,38 @@
+//! # Go With The Flow
+//!
+//! The first part runs the program directly on the shared [elfcode] device.
+//!
+//! Decompiling the program shows that it first computes a number in a register, then uses two
+//! nested loops to add up every divisor of that number into register 0. Setting register 0 to
+//! one at the start makes the number over ten million, far too large for the quadratic loops.
+//! Instead the program is run only until it jumps back to the second instruction, which starts
+//! the loops, then the divisors of the largest register are summed directly.
+//!
+//! [elfcode]: crate::util::elfcode
+use crate::util::elfcode::*;
+
+pub fn parse(input: &str) -> Program {
+    Program::parse(input)
+}
+
+pub fn part1(input: &Program) -> usize {
+    let mut registers = [0; 6];
+    input.run(&mut registers, |_, _| false);
+    registers[0]
+}
+
+pub fn part2(input: &Program) -> usize {
+    let mut registers = [1, 0, 0, 0, 0, 0];
+    let mut started = false;
+    input.run(&mut registers, |ip, _| {
+        let stop = started && ip == 1;
+        started = true;
+        stop
+    });
+
+    let n = *registers.iter().max().unwrap();
+    (1..=n.isqrt())
+        .filter(|&d| n.is_multiple_of(d))
+        .map(|d| if d * d == n { d } else { d + n / d })
+        .sum()
+}
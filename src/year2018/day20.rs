@@ -0,0 +1,52 @@
+//! # A Regular Map
+//!
+//! Walks the regex keeping a stack of positions. An opening bracket saves the current position,
+//! a pipe returns to the saved position and a closing bracket discards it. Each step records the
+//! shortest known distance to the new room, which is correct for the puzzle inputs since every
+//! branch either returns to its starting room or ends in a dead end.
+use crate::util::hash::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> (u32, usize) {
+    let mut position = ORIGIN;
+    let mut stack = Vec::new();
+    let mut distance = FastMap::build([(ORIGIN, 0)]);
+
+    for b in input.trim().bytes() {
+        let direction = match b {
+            b'(' => {
+                stack.push(position);
+                continue;
+            }
+            b'|' => {
+                position = *stack.last().unwrap();
+                continue;
+            }
+            b')' => {
+                position = stack.pop().unwrap();
+                continue;
+            }
+            b'N' => UP,
+            b'S' => DOWN,
+            b'E' => RIGHT,
+            b'W' => LEFT,
+            _ => continue,
+        };
+
+        let next = distance[&position] + 1;
+        position += direction;
+        distance.entry(position).and_modify(|d: &mut u32| *d = (*d).min(next)).or_insert(next);
+    }
+
+    let furthest = *distance.values().max().unwrap();
+    let far = distance.values().filter(|&&d| d >= 1000).count();
+    (furthest, far)
+}
+
+pub fn part1(input: &(u32, usize)) -> u32 {
+    input.0
+}
+
+pub fn part2(input: &(u32, usize)) -> usize {
+    input.1
+}
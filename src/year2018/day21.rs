@@ -0,0 +1,60 @@
+//! # Chronal Conversion
+//!
+//! Decompiling the program shows that it repeatedly hashes register 3 and halts only when the
+//! hash equals register 0. Each time the comparison is reached, the new hash is:
+//!
+//! ```none
+//!     r4 = r3 | 65536
+//!     r3 = seed
+//!     loop {
+//!         r3 = (((r3 + (r4 & 255)) & 0xffffff) * multiplier) & 0xffffff
+//!         if r4 < 256 { break }
+//!         r4 /= 256
+//!     }
+//! ```
+//!
+//! The seed and multiplier differ between inputs, so they're read from the parsed [`Program`].
+//! The first part is the first hash compared. The hashes eventually repeat, so the second part
+//! is the last new hash seen before the first repeat.
+use crate::util::elfcode::*;
+use crate::util::hash::*;
+
+pub struct Input {
+    seed: usize,
+    multiplier: usize,
+}
+
+pub fn parse(input: &str) -> Input {
+    let program = Program::parse(input);
+    Input { seed: program.instructions[7].a, multiplier: program.instructions[11].b }
+}
+
+pub fn part1(input: &Input) -> usize {
+    next(input, 0)
+}
+
+pub fn part2(input: &Input) -> usize {
+    let mut seen = FastSet::new();
+    let mut previous = 0;
+    let mut r3 = next(input, 0);
+
+    while seen.insert(r3) {
+        previous = r3;
+        r3 = next(input, r3);
+    }
+
+    previous
+}
+
+fn next(input: &Input, r3: usize) -> usize {
+    let mut r4 = r3 | 65536;
+    let mut r3 = input.seed;
+
+    loop {
+        r3 = (((r3 + (r4 & 255)) & 0xffffff) * input.multiplier) & 0xffffff;
+        if r4 < 256 {
+            return r3;
+        }
+        r4 /= 256;
+    }
+}
@@ -0,0 +1,93 @@
+//! # Mode Maze
+//!
+//! Erosion levels are computed row by row with dynamic programming, then reduced to region
+//! types. Numbering the tools neither as 0, torch as 1 and climbing gear as 2 means that a tool
+//! is allowed in a region exactly when it differs from the region type.
+//!
+//! The second part is [Dijkstra's algorithm] over states of position and tool. The cave extends
+//! infinitely but the fastest route never strays far beyond the target, so a margin of 100 is
+//! plenty.
+//!
+//! [Dijkstra's algorithm]: https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+use crate::util::grid::*;
+use crate::util::heap::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+const TORCH: usize = 1;
+const MARGIN: i32 = 100;
+
+pub struct Input {
+    target: Point,
+    cave: Grid<u8>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let [depth, x, y]: [usize; 3] = input.iter_unsigned().chunk::<3>().next().unwrap();
+    let target = Point::new(x as i32, y as i32);
+    let mut cave = Grid::new(target.x + MARGIN, target.y + MARGIN, 0);
+    let mut erosion = cave.same_size_with(0_usize);
+
+    for y in 0..cave.height {
+        for x in 0..cave.width {
+            let point = Point::new(x, y);
+            let geologic = if point == target {
+                0
+            } else if y == 0 {
+                x as usize * 16807
+            } else if x == 0 {
+                y as usize * 48271
+            } else {
+                erosion[point + LEFT] * erosion[point + UP]
+            };
+
+            erosion[point] = (geologic + depth) % 20183;
+            cave[point] = (erosion[point] % 3) as u8;
+        }
+    }
+
+    Input { target, cave }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let Input { target, cave } = input;
+    (0..=target.y)
+        .flat_map(|y| (0..=target.x).map(move |x| Point::new(x, y)))
+        .map(|point| cave[point] as usize)
+        .sum()
+}
+
+pub fn part2(input: &Input) -> u32 {
+    let Input { target, cave } = input;
+    let mut todo = MinHeap::new();
+    let mut cost = cave.same_size_with([u32::MAX; 3]);
+
+    todo.push(0, (ORIGIN, TORCH));
+    cost[ORIGIN][TORCH] = 0;
+
+    while let Some((time, (point, tool))) = todo.pop() {
+        if point == *target && tool == TORCH {
+            return time;
+        }
+        if time > cost[point][tool] {
+            continue;
+        }
+
+        // Switch to the only other tool allowed in this region.
+        let other = 3 - tool - cave[point] as usize;
+        if time + 7 < cost[point][other] {
+            cost[point][other] = time + 7;
+            todo.push(time + 7, (point, other));
+        }
+
+        for next in ORTHOGONAL.map(|o| point + o) {
+            if cave.contains(next) && tool != cave[next] as usize && time + 1 < cost[next][tool] {
+                cost[next][tool] = time + 1;
+                todo.push(time + 1, (next, tool));
+            }
+        }
+    }
+
+    unreachable!()
+}
@@ -0,0 +1,87 @@
+//! # Experimental Emergency Teleportation
+//!
+//! The second part searches the space with a priority queue of cubes, similar to an
+//! [octree]. Starting with a single cube containing every nanobot, each cube is split into eight
+//! smaller cubes. The number of nanobots in range of any point of a cube is an upper bound for
+//! every point inside, and the distance from the origin to the closest point of a cube is a lower
+//! bound. Popping cubes with the most nanobots first, then the closest, means that the first cube
+//! shrunk to a single point is the answer.
+//!
+//! [octree]: https://en.wikipedia.org/wiki/Octree
+use crate::util::heap::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub struct Nanobot {
+    position: [i64; 3],
+    radius: i64,
+}
+
+struct Cube {
+    min: [i64; 3],
+    size: i64,
+}
+
+impl Cube {
+    /// Manhattan distance from `position` to the nearest point inside the cube.
+    fn distance(&self, position: [i64; 3]) -> i64 {
+        (0..3).map(|i| axis(position[i], self.min[i], self.min[i] + self.size - 1)).sum()
+    }
+
+    fn in_range(&self, bots: &[Nanobot]) -> usize {
+        bots.iter().filter(|bot| self.distance(bot.position) <= bot.radius).count()
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Nanobot> {
+    input
+        .iter_signed()
+        .chunk::<4>()
+        .map(|[x, y, z, radius]| Nanobot { position: [x, y, z], radius })
+        .collect()
+}
+
+pub fn part1(input: &[Nanobot]) -> usize {
+    let strongest = input.iter().max_by_key(|bot| bot.radius).unwrap();
+    let cube = Cube { min: strongest.position, size: 1 };
+    input.iter().filter(|bot| cube.distance(bot.position) <= strongest.radius).count()
+}
+
+pub fn part2(input: &[Nanobot]) -> i64 {
+    let extent = input.iter().flat_map(|bot| bot.position).map(i64::abs).max().unwrap();
+    let mut size = 1;
+    while size <= extent {
+        size *= 2;
+    }
+
+    let mut todo = MinHeap::new();
+    let start = Cube { min: [-size; 3], size: 2 * size };
+    todo.push((0, 0), start);
+
+    while let Some(((_, distance), cube)) = todo.pop() {
+        if cube.size == 1 {
+            return distance;
+        }
+
+        let half = cube.size / 2;
+        for corner in 0..8 {
+            let offset = |i: usize| if corner & (1 << i) == 0 { 0 } else { half };
+            let min = [cube.min[0] + offset(0), cube.min[1] + offset(1), cube.min[2] + offset(2)];
+            let next = Cube { min, size: half };
+            todo.push((input.len() - next.in_range(input), next.distance([0; 3])), next);
+        }
+    }
+
+    unreachable!()
+}
+
+/// Distance from `n` to the closest value in the inclusive range `lo..=hi`.
+fn axis(n: i64, lo: i64, hi: i64) -> i64 {
+    if n < lo {
+        lo - n
+    } else if n > hi {
+        n - hi
+    } else {
+        0
+    }
+}
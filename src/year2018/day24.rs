@@ -0,0 +1,152 @@
+//! # Immune System Simulator 20XX
+//!
+//! Simulates the battle following the rules closely. Weaknesses and immunities are stored as
+//! bitmasks of the five damage types, so that working out the damage multiplier is a quick
+//! bitwise AND.
+//!
+//! For the second part, the boost is increased one at a time until the immune system wins.
+//! Some boosts result in a stalemate where the remaining groups can't kill a single unit of each
+//! other, so a fight is abandoned if a round passes with no units lost.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+const TYPES: [&str; 5] = ["bludgeoning", "cold", "fire", "radiation", "slashing"];
+
+#[derive(Clone, Copy)]
+pub struct Group {
+    army: usize,
+    units: u32,
+    hit_points: u32,
+    damage: u32,
+    kind: u32,
+    initiative: u32,
+    weak: u32,
+    immune: u32,
+}
+
+impl Group {
+    fn effective_power(&self) -> u32 {
+        self.units * self.damage
+    }
+
+    fn damage_to(&self, other: &Group) -> u32 {
+        if other.immune & self.kind != 0 {
+            0
+        } else if other.weak & self.kind != 0 {
+            2 * self.effective_power()
+        } else {
+            self.effective_power()
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Group> {
+    let mask = |text: &str, prefix: &str| {
+        text.split("; ")
+            .filter_map(|part| part.strip_prefix(prefix))
+            .flat_map(|list| list.split(", "))
+            .map(kind)
+            .fold(0, |acc, bit| acc | bit)
+    };
+
+    input
+        .split("\n\n")
+        .enumerate()
+        .flat_map(|(army, block)| block.lines().skip(1).map(move |line| (army, line)))
+        .map(|(army, line)| {
+            let [units, hit_points, damage, initiative] =
+                line.iter_unsigned().chunk::<4>().next().unwrap();
+            let modifiers =
+                line.split_once('(').map_or("", |(_, rest)| rest.split_once(')').unwrap().0);
+            let (before, _) = line.split_once(" damage").unwrap();
+            let attack = before.rsplit_once(' ').unwrap().1;
+
+            Group {
+                army,
+                units,
+                hit_points,
+                damage,
+                kind: kind(attack),
+                initiative,
+                weak: mask(modifiers, "weak to "),
+                immune: mask(modifiers, "immune to "),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Group]) -> u32 {
+    fight(input, 0).1
+}
+
+pub fn part2(input: &[Group]) -> u32 {
+    (1..).map(|boost| fight(input, boost)).find(|&(winner, _)| winner == Some(0)).unwrap().1
+}
+
+/// Returns the winning army, or `None` for a stalemate, along with its remaining units.
+pub fn fight(input: &[Group], boost: u32) -> (Option<usize>, u32) {
+    let mut groups = input.to_vec();
+    for group in groups.iter_mut().filter(|group| group.army == 0) {
+        group.damage += boost;
+    }
+
+    loop {
+        groups.retain(|group| group.units > 0);
+        let remaining =
+            |army| groups.iter().filter(|group| group.army == army).map(|group| group.units).sum();
+        let (immune, infection): (u32, u32) = (remaining(0), remaining(1));
+        if immune == 0 || infection == 0 {
+            let winner = if immune > 0 { 0 } else { 1 };
+            return (Some(winner), immune + infection);
+        }
+
+        // Target selection
+        let mut order: Vec<_> = (0..groups.len()).collect();
+        order.sort_unstable_by_key(|&i| (groups[i].effective_power(), groups[i].initiative));
+
+        let mut targets = vec![None; groups.len()];
+        let mut chosen = vec![false; groups.len()];
+
+        for &attacker in order.iter().rev() {
+            let target = (0..groups.len())
+                .filter(|&i| !chosen[i] && groups[i].army != groups[attacker].army)
+                .filter(|&i| groups[attacker].damage_to(&groups[i]) > 0)
+                .max_by_key(|&i| {
+                    let defender = &groups[i];
+                    (
+                        groups[attacker].damage_to(defender),
+                        defender.effective_power(),
+                        defender.initiative,
+                    )
+                });
+
+            if let Some(target) = target {
+                chosen[target] = true;
+                targets[attacker] = Some(target);
+            }
+        }
+
+        // Attacking
+        order.sort_unstable_by_key(|&i| groups[i].initiative);
+        let mut killed = 0;
+
+        for &attacker in order.iter().rev() {
+            if let Some(target) = targets[attacker] {
+                if groups[attacker].units > 0 {
+                    let damage = groups[attacker].damage_to(&groups[target]);
+                    let units = (damage / groups[target].hit_points).min(groups[target].units);
+                    groups[target].units -= units;
+                    killed += units;
+                }
+            }
+        }
+
+        if killed == 0 {
+            return (None, immune + infection);
+        }
+    }
+}
+
+fn kind(name: &str) -> u32 {
+    1 << TYPES.iter().position(|&t| t == name).unwrap()
+}
@@ -0,0 +1,39 @@
+//! # Four-Dimensional Adventure
+//!
+//! Joins every pair of points within distance 3 using a [disjoint-set] with path compression,
+//! then counts the number of distinct sets.
+//!
+//! This puzzle only has one part.
+//!
+//! [disjoint-set]: https://en.wikipedia.org/wiki/Disjoint-set_data_structure
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<[i32; 4]> {
+    input.iter_signed().chunk::<4>().collect()
+}
+
+pub fn part1(input: &[[i32; 4]]) -> usize {
+    let mut parent: Vec<_> = (0..input.len()).collect();
+
+    for i in 0..input.len() {
+        for j in (i + 1)..input.len() {
+            let distance: i32 = (0..4).map(|k| (input[i][k] - input[j][k]).abs()).sum();
+            if distance <= 3 {
+                let a = find(&mut parent, i);
+                let b = find(&mut parent, j);
+                parent[a] = b;
+            }
+        }
+    }
+
+    (0..input.len()).filter(|&i| find(&mut parent, i) == i).count()
+}
+
+fn find(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
@@ -0,0 +1,34 @@
+//! # The Tyranny of the Rocket Equation
+//!
+//! Part two repeatedly applies the fuel calculation until the extra fuel needed is zero or
+//! negative, which [`saturating_sub`] clamps to zero.
+//!
+//! [`saturating_sub`]: u32::saturating_sub
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<u32> {
+    input.iter_unsigned().collect()
+}
+
+pub fn part1(input: &[u32]) -> u32 {
+    input.iter().map(|&mass| fuel(mass)).sum()
+}
+
+pub fn part2(input: &[u32]) -> u32 {
+    input
+        .iter()
+        .map(|&mass| {
+            let mut total = 0;
+            let mut extra = fuel(mass);
+            while extra > 0 {
+                total += extra;
+                extra = fuel(extra);
+            }
+            total
+        })
+        .sum()
+}
+
+fn fuel(mass: u32) -> u32 {
+    (mass / 3).saturating_sub(2)
+}
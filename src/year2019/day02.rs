@@ -0,0 +1,37 @@
+//! # 1202 Program Alarm
+//!
+//! Instead of trying all 10,000 combinations of noun and verb, part two relies on the output
+//! being a linear function of both, as the program only ever adds and multiplies them by
+//! constants. Three runs find the base value and the effect of each input, then the answer is
+//! solved for directly.
+use crate::util::intcode::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i64]) -> i64 {
+    execute(input, 12, 2)
+}
+
+pub fn part2(input: &[i64]) -> i64 {
+    let target = 19690720;
+    let base = execute(input, 0, 0);
+    let noun = execute(input, 1, 0) - base;
+    let verb = execute(input, 0, 1) - base;
+
+    let first = (target - base) / noun;
+    let second = (target - base - first * noun) / verb;
+    100 * first + second
+}
+
+/// Value left at address 0 after running the program with `noun` and `verb` at addresses 1
+/// and 2.
+pub fn execute(input: &[i64], noun: i64, verb: i64) -> i64 {
+    let mut computer = Computer::new(input);
+    computer.write(1, noun);
+    computer.write(2, verb);
+    computer.run_to_end();
+    computer.read(0)
+}
@@ -0,0 +1,49 @@
+//! # Crossed Wires
+//!
+//! Walks the first wire one step at a time, recording the number of steps taken to reach each
+//! point for the first time. Walking the second wire then finds every intersection along with
+//! the combined number of steps to reach it.
+use crate::util::hash::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+/// Every intersection as its distance from the origin and the combined steps of both wires.
+pub fn parse(input: &str) -> Vec<(i32, u32)> {
+    let (first, second) = input.trim().split_once('\n').unwrap();
+    let mut seen = FastMap::new();
+    walk(first, |point, steps| {
+        seen.entry(point).or_insert(steps);
+    });
+
+    let mut intersections = Vec::new();
+    walk(second, |point, steps| {
+        if let Some(&other) = seen.get(&point) {
+            intersections.push((point.manhattan(ORIGIN), steps + other));
+        }
+    });
+    intersections
+}
+
+pub fn part1(input: &[(i32, u32)]) -> i32 {
+    input.iter().map(|&(distance, _)| distance).min().unwrap()
+}
+
+pub fn part2(input: &[(i32, u32)]) -> u32 {
+    input.iter().map(|&(_, steps)| steps).min().unwrap()
+}
+
+fn walk(wire: &str, mut visit: impl FnMut(Point, u32)) {
+    let mut position = ORIGIN;
+    let mut steps = 0;
+
+    for segment in wire.trim().split(',') {
+        let direction = Point::from(segment.as_bytes()[0]);
+        let length: u32 = segment.unsigned();
+
+        for _ in 0..length {
+            position += direction;
+            steps += 1;
+            visit(position, steps);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! # Secure Container
+//!
+//! Digits never decrease, so instead of checking every number in the range only non-decreasing
+//! sequences of six digits are generated, of which there are just 5,005. Runs of equal digits
+//! are then contiguous, so the rules only need the length of each run.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<[u32; 2]> {
+    let [start, end] = input.iter_unsigned().chunk::<2>().next().unwrap();
+    let mut digits = [0; 6];
+    let mut result = Vec::new();
+    generate(&mut digits, 0, 1, &mut |digits| {
+        let number = digits.iter().fold(0, |acc, &d| 10 * acc + d);
+        if (start..=end).contains(&number) {
+            result.push(runs(digits));
+        }
+    });
+    result
+}
+
+/// Passwords with a run of at least two equal digits.
+pub fn part1(input: &[[u32; 2]]) -> usize {
+    input.iter().filter(|[longest, _]| *longest >= 2).count()
+}
+
+/// Passwords with a run of exactly two equal digits.
+pub fn part2(input: &[[u32; 2]]) -> usize {
+    input.iter().filter(|[_, pair]| *pair > 0).count()
+}
+
+fn generate(digits: &mut [u32; 6], index: usize, lowest: u32, visit: &mut impl FnMut(&[u32; 6])) {
+    if index == 6 {
+        visit(digits);
+        return;
+    }
+    for digit in lowest..10 {
+        digits[index] = digit;
+        generate(digits, index + 1, digit, visit);
+    }
+}
+
+/// Longest run of equal digits and the number of runs of exactly two.
+fn runs(digits: &[u32; 6]) -> [u32; 2] {
+    let mut longest = 0;
+    let mut pairs = 0;
+    let mut length = 0;
+
+    for (i, &digit) in digits.iter().enumerate() {
+        length += 1;
+        if i == 5 || digits[i + 1] != digit {
+            longest = longest.max(length);
+            pairs += (length == 2) as u32;
+            length = 0;
+        }
+    }
+
+    [longest, pairs]
+}
@@ -0,0 +1,26 @@
+//! # Sunny with a Chance of Asteroids
+//!
+//! Runs the diagnostic program on the [`Computer`], which implements all the new opcodes and
+//! parameter modes. Every output apart from the last is a test result that should be zero.
+//!
+//! [`Computer`]: crate::util::intcode::Computer
+use crate::util::intcode::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i64]) -> i64 {
+    diagnostic(input, 1)
+}
+
+pub fn part2(input: &[i64]) -> i64 {
+    diagnostic(input, 5)
+}
+
+fn diagnostic(input: &[i64], system: i64) -> i64 {
+    let mut computer = Computer::new(input);
+    computer.input(system);
+    *computer.run_to_end().last().unwrap()
+}
@@ -0,0 +1,76 @@
+//! # Universal Orbit Map
+//!
+//! Each object orbits exactly one other, forming a tree rooted at `COM`. Names are mapped to
+//! indices, then the depth of each object is computed with memoization so that the total is
+//! linear in the number of objects.
+//!
+//! For part two the ancestors of `YOU` are marked with their distance. Walking up from `SAN`
+//! until reaching a marked ancestor finds the closest common ancestor.
+use crate::util::hash::*;
+
+pub struct Input {
+    parent: Vec<usize>,
+    indices: FastMap<String, usize>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let mut indices = FastMap::new();
+    let mut index = |name: &str| {
+        let size = indices.len();
+        *indices.entry(name.to_string()).or_insert(size)
+    };
+
+    let edges: Vec<_> = input
+        .lines()
+        .map(|line| {
+            let (center, object) = line.split_once(')').unwrap();
+            (index(center), index(object))
+        })
+        .collect();
+
+    let mut parent = vec![usize::MAX; indices.len()];
+    for (center, object) in edges {
+        parent[object] = center;
+    }
+
+    Input { parent, indices }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let mut depth = vec![None; input.parent.len()];
+    (0..input.parent.len()).map(|object| depth_of(&input.parent, &mut depth, object)).sum()
+}
+
+pub fn part2(input: &Input) -> usize {
+    let ancestors = |name| {
+        let mut object = input.parent[input.indices[name]];
+        std::iter::from_fn(move || {
+            let current = object;
+            (current != usize::MAX).then(|| {
+                object = input.parent[current];
+                current
+            })
+        })
+    };
+
+    let mut distance = FastMap::new();
+    for (steps, object) in ancestors("YOU").enumerate() {
+        distance.insert(object, steps);
+    }
+    ancestors("SAN")
+        .enumerate()
+        .find_map(|(steps, object)| Some(steps + distance.get(&object)?))
+        .unwrap()
+}
+
+fn depth_of(parent: &[usize], depth: &mut [Option<usize>], object: usize) -> usize {
+    if let Some(result) = depth[object] {
+        return result;
+    }
+    let result = match parent[object] {
+        usize::MAX => 0,
+        center => depth_of(parent, depth, center) + 1,
+    };
+    depth[object] = Some(result);
+    result
+}
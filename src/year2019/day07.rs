@@ -0,0 +1,50 @@
+//! # Amplification Circuit
+//!
+//! Tries every permutation of phase settings. In part two the amplifiers form a feedback loop,
+//! which is simple to simulate as each [`Computer`] pauses after producing an output, ready to
+//! be resumed when its next input arrives.
+//!
+//! [`Computer`]: crate::util::intcode::Computer
+use crate::util::intcode::*;
+use crate::util::parse::*;
+use crate::util::slice::*;
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i64]) -> i64 {
+    best(input, [0, 1, 2, 3, 4])
+}
+
+pub fn part2(input: &[i64]) -> i64 {
+    best(input, [5, 6, 7, 8, 9])
+}
+
+fn best(input: &[i64], mut phases: [i64; 5]) -> i64 {
+    let mut result = 0;
+    phases.permutations(|phases| result = result.max(signal(input, phases)));
+    result
+}
+
+fn signal(input: &[i64], phases: &[i64]) -> i64 {
+    let mut amplifiers: Vec<_> = phases
+        .iter()
+        .map(|&phase| {
+            let mut computer = Computer::new(input);
+            computer.input(phase);
+            computer
+        })
+        .collect();
+    let mut value = 0;
+
+    loop {
+        for amplifier in &mut amplifiers {
+            amplifier.input(value);
+            match amplifier.run() {
+                State::Output(next) => value = next,
+                _ => return value,
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! # Space Image Format
+//!
+//! Layers are chunks of the input. Part two renders the decoded image as text so that the
+//! letters can be read.
+const WIDTH: usize = 25;
+const HEIGHT: usize = 6;
+
+pub fn parse(input: &str) -> Vec<&[u8]> {
+    input.trim().as_bytes().chunks(WIDTH * HEIGHT).collect()
+}
+
+pub fn part1(input: &[&[u8]]) -> usize {
+    let count = |layer: &[u8], digit| layer.iter().filter(|&&b| b == digit).count();
+    let layer = input.iter().min_by_key(|layer| count(layer, b'0')).unwrap();
+    count(layer, b'1') * count(layer, b'2')
+}
+
+pub fn part2(input: &[&[u8]]) -> String {
+    let mut result = String::new();
+
+    for y in 0..HEIGHT {
+        result.push('\n');
+        for x in 0..WIDTH {
+            let pixel =
+                input.iter().map(|layer| layer[y * WIDTH + x]).find(|&b| b != b'2').unwrap();
+            result.push(if pixel == b'1' { '#' } else { '.' });
+        }
+    }
+
+    result
+}
@@ -0,0 +1,26 @@
+//! # Sensor Boost
+//!
+//! Adds relative mode parameters and memory beyond the end of the program to the
+//! [`Computer`].
+//!
+//! [`Computer`]: crate::util::intcode::Computer
+use crate::util::intcode::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i64]) -> i64 {
+    boost(input, 1)
+}
+
+pub fn part2(input: &[i64]) -> i64 {
+    boost(input, 2)
+}
+
+fn boost(input: &[i64], mode: i64) -> i64 {
+    let mut computer = Computer::new(input);
+    computer.input(mode);
+    *computer.run_to_end().last().unwrap()
+}
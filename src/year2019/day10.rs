@@ -0,0 +1,93 @@
+//! # Monitoring Station
+//!
+//! Two asteroids are in the same line of sight from a station when their offsets reduce to
+//! the same direction after dividing by the [greatest common divisor] of both components. The
+//! number of asteroids visible is the number of distinct directions.
+//!
+//! The laser sweeps clockwise vaporizing one asteroid per direction on each rotation, so an
+//! asteroid's turn is decided first by how many others are closer in the same direction and
+//! then by the angle of that direction.
+//!
+//! [greatest common divisor]: crate::util::math::IntegerMathOps::gcd
+use crate::util::hash::*;
+use crate::util::math::*;
+
+pub struct Input {
+    station: (i32, i32),
+    visible: usize,
+    /// Offsets from the best station to every other asteroid.
+    offsets: Vec<(i32, i32)>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let asteroids: Vec<_> = input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.bytes()
+                .enumerate()
+                .filter(|&(_, b)| b == b'#')
+                .map(move |(x, _)| (x as i32, y as i32))
+        })
+        .collect();
+
+    let offsets = |&(x, y): &(i32, i32)| -> Vec<_> {
+        asteroids
+            .iter()
+            .filter(|&&other| other != (x, y))
+            .map(|&(ox, oy)| (ox - x, oy - y))
+            .collect()
+    };
+    let visible = |offsets: &[(i32, i32)]| {
+        let directions: FastSet<_> = offsets.iter().map(|&offset| direction(offset)).collect();
+        directions.len()
+    };
+
+    let station = *asteroids.iter().max_by_key(|station| visible(&offsets(station))).unwrap();
+    let offsets = offsets(&station);
+    Input { station, visible: visible(&offsets), offsets }
+}
+
+pub fn part1(input: &Input) -> usize {
+    input.visible
+}
+
+pub fn part2(input: &Input) -> i32 {
+    vaporized(input, 200)
+}
+
+/// Coordinates of the `n`th asteroid to be vaporized as `100 * x + y`.
+pub fn vaporized(input: &Input, n: usize) -> i32 {
+    let mut offsets = input.offsets.clone();
+    offsets.sort_unstable_by_key(|&(dx, dy)| dx.abs() + dy.abs());
+
+    let mut closer = FastMap::new();
+    let mut order: Vec<_> = offsets
+        .iter()
+        .map(|&offset| {
+            let rank = closer.entry(direction(offset)).or_insert(0);
+            *rank += 1;
+            (*rank, angle(offset), offset)
+        })
+        .collect();
+    order.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+    let (_, _, (dx, dy)) = order[n - 1];
+    let (x, y) = input.station;
+    100 * (x + dx) + (y + dy)
+}
+
+fn direction((dx, dy): (i32, i32)) -> (i32, i32) {
+    let divisor = dx.abs().gcd(dy.abs());
+    (dx / divisor, dy / divisor)
+}
+
+/// Clockwise angle from straight up, in the range `[0, 2π)`.
+fn angle((dx, dy): (i32, i32)) -> f64 {
+    let angle = (dx as f64).atan2(-dy as f64);
+    if angle < 0.0 {
+        angle + std::f64::consts::TAU
+    } else {
+        angle
+    }
+}
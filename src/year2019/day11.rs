@@ -0,0 +1,62 @@
+//! # Space Police
+//!
+//! The robot's brain is a [`Computer`] that alternates between reading the color of the
+//! current panel and producing two outputs, the color to paint followed by the direction to
+//! turn. Panels are stored in a [`SparseGrid`] as the hull's size is unknown in advance.
+//!
+//! [`Computer`]: crate::util::intcode::Computer
+//! [`SparseGrid`]: crate::util::sparse::SparseGrid
+use crate::util::intcode::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+use crate::util::sparse::*;
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i64]) -> usize {
+    paint(input, false).len()
+}
+
+pub fn part2(input: &[i64]) -> String {
+    let hull = paint(input, true);
+
+    // Only white panels are part of the letters, so leave out black panels from the bounds.
+    let mut white = SparseGrid::new(false);
+    hull.iter().for_each(|(point, &color)| white.set(point, color == Some(true)));
+
+    let letters = white.render(|&white| if white { '#' } else { '.' });
+    letters.lines().map(|line| format!("\n{line}")).collect()
+}
+
+/// Every panel painted at least once and whether it ended up white. Panels that were never
+/// painted are `None`, so that panels painted black are still counted.
+fn paint(input: &[i64], start: bool) -> SparseGrid<Option<bool>> {
+    let mut computer = Computer::new(input);
+    let mut hull = SparseGrid::new(None);
+    let mut position = ORIGIN;
+    let mut direction = UP;
+
+    if start {
+        hull.set(position, Some(true));
+    }
+
+    loop {
+        let current = hull[position].unwrap_or(false);
+        computer.input(current as i64);
+
+        let State::Output(color) = computer.run() else {
+            break;
+        };
+        let State::Output(turn) = computer.run() else {
+            unreachable!();
+        };
+
+        hull.set(position, Some(color == 1));
+        direction = if turn == 0 { direction.counter_clockwise() } else { direction.clockwise() };
+        position += direction;
+    }
+
+    hull
+}
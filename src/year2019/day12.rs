@@ -0,0 +1,65 @@
+//! # The N-Body Problem
+//!
+//! Each axis is independent, the position and velocity on one axis never depend on another.
+//! The simulation is reversible, so each axis must eventually return to its initial state and
+//! the first repeat of the whole system is the [least common multiple] of the period of each
+//! axis.
+//!
+//! [least common multiple]: crate::util::math::IntegerMathOps::lcm
+use crate::util::iter::*;
+use crate::util::math::*;
+use crate::util::parse::*;
+
+type Axis = [(i32, i32); 4];
+
+pub fn parse(input: &str) -> [Axis; 3] {
+    let moons: Vec<_> = input.iter_signed().chunk::<3>().collect();
+    std::array::from_fn(|axis| std::array::from_fn(|moon| (moons[moon][axis], 0)))
+}
+
+pub fn part1(input: &[Axis; 3]) -> i32 {
+    energy(input, 1000)
+}
+
+pub fn part2(input: &[Axis; 3]) -> u64 {
+    input
+        .iter()
+        .map(|&initial| {
+            let mut axis = initial;
+            let mut steps = 1;
+            step(&mut axis);
+            while axis != initial {
+                step(&mut axis);
+                steps += 1;
+            }
+            steps
+        })
+        .fold(1, |acc, period| acc.lcm(period))
+}
+
+/// Total energy in the system after simulating `steps` time steps.
+pub fn energy(input: &[Axis; 3], steps: usize) -> i32 {
+    let mut axes = *input;
+    for _ in 0..steps {
+        axes.iter_mut().for_each(step);
+    }
+
+    (0..4)
+        .map(|moon| {
+            let potential: i32 = axes.iter().map(|axis| axis[moon].0.abs()).sum();
+            let kinetic: i32 = axes.iter().map(|axis| axis[moon].1.abs()).sum();
+            potential * kinetic
+        })
+        .sum()
+}
+
+fn step(axis: &mut Axis) {
+    for i in 0..4 {
+        for j in 0..4 {
+            axis[i].1 += (axis[j].0 - axis[i].0).signum();
+        }
+    }
+    for (position, velocity) in axis.iter_mut() {
+        *position += *velocity;
+    }
+}
@@ -0,0 +1,45 @@
+//! # Care Package
+//!
+//! Instead of playing skillfully, the paddle simply follows the ball horizontally, which is
+//! enough to never miss. Outputs come in triples of `x`, `y` and tile id, with the special
+//! position `(-1, 0)` updating the score.
+use crate::util::intcode::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i64]) -> usize {
+    let mut computer = Computer::new(input);
+    computer.run_to_end().chunks_exact(3).filter(|tile| tile[2] == 2).count()
+}
+
+pub fn part2(input: &[i64]) -> i64 {
+    let mut computer = Computer::new(input);
+    computer.write(0, 2);
+
+    let mut output = Vec::with_capacity(3);
+    let mut paddle = 0;
+    let mut ball = 0;
+    let mut score = 0;
+
+    loop {
+        match computer.run() {
+            State::Output(value) => {
+                output.push(value);
+                if let [x, y, tile] = output[..] {
+                    output.clear();
+                    match (x, y, tile) {
+                        (-1, 0, _) => score = tile,
+                        (_, _, 3) => paddle = x,
+                        (_, _, 4) => ball = x,
+                        _ => (),
+                    }
+                }
+            }
+            State::Input => computer.input((ball - paddle).signum()),
+            State::Halted => break score,
+        }
+    }
+}
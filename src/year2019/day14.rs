@@ -0,0 +1,119 @@
+//! # Space Stoichiometry
+//!
+//! Chemicals are sorted [topologically] so that a chemical is only processed once every
+//! reaction consuming it has been, at which point the total amount needed is known. Rounding
+//! up to a whole number of reactions at that point wastes the fewest leftovers.
+//!
+//! The ore needed is monotonic in the amount of fuel so part two
+//! [binary searches](https://en.wikipedia.org/wiki/Binary_search) for the most fuel possible.
+//!
+//! [topologically]: https://en.wikipedia.org/wiki/Topological_sorting
+use crate::util::hash::*;
+
+pub struct Reaction {
+    amount: u64,
+    inputs: Vec<(u64, usize)>,
+}
+
+pub struct Input {
+    /// Indexed by chemical, `ORE` has no reaction.
+    reactions: Vec<Option<Reaction>>,
+    /// Chemicals in topological order starting with `FUEL`.
+    order: Vec<usize>,
+    ore: usize,
+    fuel: usize,
+}
+
+pub fn parse(input: &str) -> Input {
+    let mut indices = FastMap::new();
+    let mut index = |name: &str| {
+        let size = indices.len();
+        *indices.entry(name.to_string()).or_insert(size)
+    };
+    let mut term = |term: &str| {
+        let (amount, name) = term.trim().split_once(' ').unwrap();
+        (amount.parse().unwrap(), index(name))
+    };
+
+    let parsed: Vec<_> = input
+        .lines()
+        .map(|line| {
+            let (inputs, output) = line.split_once(" => ").unwrap();
+            let (amount, chemical) = term(output);
+            let inputs = inputs.split(", ").map(&mut term).collect();
+            (chemical, Reaction { amount, inputs })
+        })
+        .collect();
+
+    let ore = index("ORE");
+    let fuel = index("FUEL");
+    let mut reactions: Vec<_> = (0..indices.len()).map(|_| None).collect();
+    for (chemical, reaction) in parsed {
+        reactions[chemical] = Some(reaction);
+    }
+
+    let mut order = Vec::new();
+    let mut visited = vec![false; reactions.len()];
+    visit(&reactions, &mut visited, &mut order, fuel);
+    order.reverse();
+
+    Input { reactions, order, ore, fuel }
+}
+
+pub fn part1(input: &Input) -> u64 {
+    ore(input, 1)
+}
+
+pub fn part2(input: &Input) -> u64 {
+    let available = 1_000_000_000_000;
+    let mut lower = 1;
+    let mut upper = 2;
+
+    while ore(input, upper) <= available {
+        upper *= 2;
+    }
+    while upper - lower > 1 {
+        let middle = (lower + upper) / 2;
+        if ore(input, middle) <= available {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+
+    lower
+}
+
+fn visit(
+    reactions: &[Option<Reaction>],
+    visited: &mut [bool],
+    order: &mut Vec<usize>,
+    chemical: usize,
+) {
+    if visited[chemical] {
+        return;
+    }
+    visited[chemical] = true;
+    if let Some(reaction) = &reactions[chemical] {
+        for &(_, next) in &reaction.inputs {
+            visit(reactions, visited, order, next);
+        }
+    }
+    order.push(chemical);
+}
+
+fn ore(input: &Input, fuel: u64) -> u64 {
+    let mut needed = vec![0; input.reactions.len()];
+    needed[input.fuel] = fuel;
+
+    for &chemical in &input.order {
+        if let Some(reaction) = &input.reactions[chemical] {
+            let times = needed[chemical].div_ceil(reaction.amount);
+            for &(amount, next) in &reaction.inputs {
+                needed[next] += times * amount;
+            }
+        }
+    }
+
+    needed[input.ore]
+}
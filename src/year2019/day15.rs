@@ -0,0 +1,90 @@
+//! # Oxygen System
+//!
+//! The droid explores the whole area with a [depth first search], backtracking after each
+//! branch by moving in the opposite direction. Once the map is known, a
+//! [breadth first search] from the start finds the oxygen system and a second from the oxygen
+//! system finds how long it takes to fill every location.
+//!
+//! [depth first search]: https://en.wikipedia.org/wiki/Depth-first_search
+//! [breadth first search]: https://en.wikipedia.org/wiki/Breadth-first_search
+use crate::util::hash::*;
+use crate::util::intcode::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+use std::collections::VecDeque;
+
+/// Movement commands in the order north, south, west and east.
+const MOVES: [(i64, Point, i64); 4] = [(1, UP, 2), (2, DOWN, 1), (3, LEFT, 4), (4, RIGHT, 3)];
+
+pub fn parse(input: &str) -> [u32; 2] {
+    let code: Vec<_> = input.iter_signed().collect();
+    let mut computer = Computer::new(&code);
+    let mut open = FastSet::build([ORIGIN]);
+    let mut walls = FastSet::new();
+    let mut oxygen = ORIGIN;
+
+    explore(&mut computer, ORIGIN, &mut open, &mut walls, &mut oxygen);
+
+    let from_start = bfs(&open, ORIGIN);
+    let from_oxygen = bfs(&open, oxygen);
+    [from_start[&oxygen], *from_oxygen.values().max().unwrap()]
+}
+
+pub fn part1(input: &[u32; 2]) -> u32 {
+    input[0]
+}
+
+pub fn part2(input: &[u32; 2]) -> u32 {
+    input[1]
+}
+
+fn explore(
+    computer: &mut Computer,
+    position: Point,
+    open: &mut FastSet<Point>,
+    walls: &mut FastSet<Point>,
+    oxygen: &mut Point,
+) {
+    for (command, direction, reverse) in MOVES {
+        let next = position + direction;
+        if open.contains(&next) || walls.contains(&next) {
+            continue;
+        }
+
+        computer.input(command);
+        let State::Output(status) = computer.run() else {
+            unreachable!();
+        };
+
+        if status == 0 {
+            walls.insert(next);
+            continue;
+        }
+        if status == 2 {
+            *oxygen = next;
+        }
+
+        open.insert(next);
+        explore(computer, next, open, walls, oxygen);
+
+        computer.input(reverse);
+        computer.run();
+    }
+}
+
+fn bfs(open: &FastSet<Point>, start: Point) -> FastMap<Point, u32> {
+    let mut distance = FastMap::build([(start, 0)]);
+    let mut todo = VecDeque::from([start]);
+
+    while let Some(point) = todo.pop_front() {
+        let steps = distance[&point];
+        for next in ORTHOGONAL.map(|o| point + o) {
+            if open.contains(&next) && !distance.contains_key(&next) {
+                distance.insert(next, steps + 1);
+                todo.push_back(next);
+            }
+        }
+    }
+
+    distance
+}
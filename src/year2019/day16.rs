@@ -0,0 +1,64 @@
+//! # Flawed Frequency Transmission
+//!
+//! The `i`th output digit (counting from one) multiplies runs of `i` input digits alternately
+//! by 0, 1, 0 and -1. Using [prefix sums] each run is added in constant time, so a phase takes
+//! `n / 1 + n / 2 + ... + n / n`, that is `O(n log n)`, operations instead of `O(n²)`.
+//!
+//! In part two the message offset is in the second half of the signal. From there on the
+//! pattern is all ones, so each digit is the sum of itself and every digit after it, which a
+//! running total from the end computes in linear time.
+//!
+//! [prefix sums]: https://en.wikipedia.org/wiki/Prefix_sum
+pub fn parse(input: &str) -> Vec<i32> {
+    input.trim().bytes().map(|b| (b - b'0') as i32).collect()
+}
+
+pub fn part1(input: &[i32]) -> String {
+    let mut digits = input.to_vec();
+    let mut prefix = vec![0; digits.len() + 1];
+    let size = digits.len();
+
+    for _ in 0..100 {
+        for i in 0..size {
+            prefix[i + 1] = prefix[i] + digits[i];
+        }
+
+        for (i, digit) in digits.iter_mut().enumerate() {
+            let n = i + 1;
+            let run = |start: usize| prefix[(start + n).min(size)] - prefix[start.min(size)];
+            let mut total = 0;
+            let mut start = i;
+
+            while start < size {
+                total += run(start) - run(start + 2 * n);
+                start += 4 * n;
+            }
+
+            *digit = total.abs() % 10;
+        }
+    }
+
+    to_string(&digits[..8])
+}
+
+pub fn part2(input: &[i32]) -> String {
+    let offset = input[..7].iter().fold(0, |acc, &d| 10 * acc + d as usize);
+    let size = 10_000 * input.len();
+    assert!(offset >= size / 2, "Offset must be in the second half of the signal");
+
+    let mut digits: Vec<_> = (offset..size).map(|i| input[i % input.len()]).collect();
+
+    for _ in 0..100 {
+        let mut total = 0;
+        for digit in digits.iter_mut().rev() {
+            total = (total + *digit) % 10;
+            *digit = total;
+        }
+    }
+
+    to_string(&digits[..8])
+}
+
+fn to_string(digits: &[i32]) -> String {
+    digits.iter().map(|&d| (b'0' + d as u8) as char).collect()
+}
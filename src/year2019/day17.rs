@@ -0,0 +1,133 @@
+//! # Set and Forget
+//!
+//! The camera output is parsed into a [`Grid`]. For part two the robot's path is found by
+//! always moving forward as far as possible, then turning towards the only direction that
+//! continues the scaffold. This path is then split into the main routine and three movement
+//! functions with a backtracking search that tries each possible length for each new function.
+//!
+//! [`Grid`]: crate::util::grid
+use crate::util::grid::*;
+use crate::util::intcode::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+pub struct Input {
+    code: Vec<i64>,
+    map: String,
+}
+
+pub fn parse(input: &str) -> Input {
+    let code: Vec<_> = input.iter_signed().collect();
+    let map = Computer::new(&code).run_ascii();
+    Input { code, map }
+}
+
+pub fn part1(input: &Input) -> i32 {
+    alignment(&input.map)
+}
+
+pub fn part2(input: &Input) -> i64 {
+    let path = path(&input.map);
+    let mut routine = Vec::new();
+    let mut functions = Vec::new();
+    assert!(compress(&path, &mut routine, &mut functions), "No valid movement functions");
+
+    let main: Vec<_> = routine.iter().map(|&f| ["A", "B", "C"][f]).collect();
+    let mut computer = Computer::new(&input.code);
+    computer.write(0, 2);
+    computer.input_ascii(&format!("{}\n", main.join(",")));
+    for index in 0..3 {
+        let function = functions.get(index).map_or(String::new(), |function| function.join(","));
+        computer.input_ascii(&format!("{function}\n"));
+    }
+    computer.input_ascii("n\n");
+
+    *computer.run_to_end().last().unwrap()
+}
+
+/// Sum of the products of the coordinates of every scaffold intersection.
+pub fn alignment(map: &str) -> i32 {
+    let grid = Grid::parse(map.trim());
+    let scaffold = |point: Point| grid.contains(point) && grid[point] != b'.';
+    let mut result = 0;
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            if scaffold(point) && ORTHOGONAL.iter().all(|&o| scaffold(point + o)) {
+                result += x * y;
+            }
+        }
+    }
+
+    result
+}
+
+/// Movement commands such as `R,8` covering the whole scaffold.
+fn path(map: &str) -> Vec<String> {
+    let grid = Grid::parse(map.trim());
+    let scaffold = |point: Point| grid.contains(point) && grid[point] == b'#';
+
+    let index = grid.bytes.iter().position(|&b| b"^v<>".contains(&b)).unwrap() as i32;
+    let mut position = Point::new(index % grid.width, index / grid.width);
+    let mut direction = Point::from(grid[position]);
+    let mut path = Vec::new();
+
+    loop {
+        let (turn, next) = if scaffold(position + direction.counter_clockwise()) {
+            ('L', direction.counter_clockwise())
+        } else if scaffold(position + direction.clockwise()) {
+            ('R', direction.clockwise())
+        } else {
+            break path;
+        };
+
+        direction = next;
+        let mut steps = 0;
+        while scaffold(position + direction) {
+            position += direction;
+            steps += 1;
+        }
+        path.push(format!("{turn},{steps}"));
+    }
+}
+
+/// Splits `path` into at most three functions whose calls fit within the 20 character limit.
+fn compress<'a>(
+    path: &'a [String],
+    routine: &mut Vec<usize>,
+    functions: &mut Vec<&'a [String]>,
+) -> bool {
+    let fits = |commands: &[String]| commands.iter().map(|c| c.len() + 1).sum::<usize>() <= 21;
+
+    if path.is_empty() {
+        return true;
+    }
+    if routine.len() == 10 {
+        return false;
+    }
+
+    for index in 0..functions.len() {
+        if path.starts_with(functions[index]) {
+            routine.push(index);
+            if compress(&path[functions[index].len()..], routine, functions) {
+                return true;
+            }
+            routine.pop();
+        }
+    }
+
+    if functions.len() < 3 {
+        for size in (1..=path.len()).take_while(|&size| fits(&path[..size])) {
+            functions.push(&path[..size]);
+            routine.push(functions.len() - 1);
+            if compress(&path[size..], routine, functions) {
+                return true;
+            }
+            routine.pop();
+            functions.pop();
+        }
+    }
+
+    false
+}
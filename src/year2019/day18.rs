@@ -0,0 +1,135 @@
+//! # Many-Worlds Interpretation
+//!
+//! A [breadth first search] from each robot and each key finds the distance to every other
+//! key, along with the doors blocking the way and any keys picked up along the route. Both
+//! sets are stored as bitmasks.
+//!
+//! [Dijkstra's algorithm] then searches over states made from the location of each robot and
+//! the keys collected so far. A move to a key is only allowed once every door on the way is
+//! unlocked and any key on the way has already been collected, as collecting that key first
+//! is the same route split into two moves.
+//!
+//! [breadth first search]: https://en.wikipedia.org/wiki/Breadth-first_search
+//! [Dijkstra's algorithm]: https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+use crate::util::grid::*;
+use crate::util::hash::*;
+use crate::util::heap::*;
+use crate::util::point::*;
+use std::collections::VecDeque;
+
+/// Distance to a key, with the doors and other keys on the way as bitmasks.
+struct Route {
+    key: usize,
+    distance: u32,
+    doors: u32,
+    keys: u32,
+}
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(input: &Grid<u8>) -> u32 {
+    search(input)
+}
+
+pub fn part2(input: &Grid<u8>) -> u32 {
+    let mut grid = input.clone();
+    let robots: Vec<_> = (0..grid.bytes.len()).filter(|&i| grid.bytes[i] == b'@').collect();
+
+    // Split the vault into four unless the map has already been updated.
+    if let [index] = robots[..] {
+        let center = Point::new(index as i32 % grid.width, index as i32 / grid.width);
+        for (dy, row) in (-1..=1).zip([b"@#@", b"###", b"@#@"]) {
+            for (dx, &b) in (-1..=1).zip(row) {
+                grid[center + Point::new(dx, dy)] = b;
+            }
+        }
+    }
+
+    search(&grid)
+}
+
+fn search(grid: &Grid<u8>) -> u32 {
+    let mut starts = Vec::new();
+    let mut keys = [None; 26];
+    let mut all = 0;
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            match grid[point] {
+                b'@' => starts.push(point),
+                b @ b'a'..=b'z' => {
+                    keys[(b - b'a') as usize] = Some(point);
+                    all |= 1 << (b - b'a');
+                }
+                _ => (),
+            }
+        }
+    }
+
+    // Nodes are the robots followed by the keys.
+    let robots = starts.len();
+    let routes: Vec<_> = starts
+        .iter()
+        .map(|&start| bfs(grid, start))
+        .chain(keys.iter().map(|key| key.map_or(Vec::new(), |start| bfs(grid, start))))
+        .collect();
+
+    let mut todo = MinHeap::new();
+    let mut seen = FastSet::new();
+    let positions: Vec<_> = (0..robots).collect();
+    todo.push(0, (positions, 0_u32));
+
+    while let Some((distance, (positions, collected))) = todo.pop() {
+        if collected == all {
+            return distance;
+        }
+        if !seen.insert((positions.clone(), collected)) {
+            continue;
+        }
+
+        for (robot, &node) in positions.iter().enumerate() {
+            for route in &routes[node] {
+                let bit = 1 << route.key;
+                if collected & bit != 0 || (route.doors | route.keys) & !collected != 0 {
+                    continue;
+                }
+
+                let mut next = positions.clone();
+                next[robot] = robots + route.key;
+                todo.push(distance + route.distance, (next, collected | bit));
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+fn bfs(grid: &Grid<u8>, start: Point) -> Vec<Route> {
+    let mut routes = Vec::new();
+    let mut visited = grid.same_size_with(false);
+    let mut todo = VecDeque::from([(start, 0, 0, 0)]);
+    visited[start] = true;
+
+    while let Some((point, distance, mut doors, mut keys)) = todo.pop_front() {
+        match grid[point] {
+            b @ b'A'..=b'Z' => doors |= 1 << (b - b'A'),
+            b @ b'a'..=b'z' if point != start => {
+                routes.push(Route { key: (b - b'a') as usize, distance, doors, keys });
+                keys |= 1 << (b - b'a');
+            }
+            _ => (),
+        }
+
+        for next in ORTHOGONAL.map(|o| point + o) {
+            if grid[next] != b'#' && !visited[next] {
+                visited[next] = true;
+                todo.push_back((next, distance + 1, doors, keys));
+            }
+        }
+    }
+
+    routes
+}
@@ -0,0 +1,38 @@
+//! # Tractor Beam
+//!
+//! Each query runs a fresh clone of the drone program. For part two the bottom left corner of
+//! the square follows the left edge of the beam, which only ever moves right as `y` increases.
+//! The square fits as soon as the opposite top right corner is also in the beam.
+use crate::util::intcode::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Computer {
+    let code: Vec<i64> = input.iter_signed().collect();
+    Computer::new(&code)
+}
+
+pub fn part1(input: &Computer) -> usize {
+    (0..50).flat_map(|y| (0..50).map(move |x| (x, y))).filter(|&(x, y)| beam(input, x, y)).count()
+}
+
+pub fn part2(input: &Computer) -> i64 {
+    let mut x = 0;
+    let mut y = 99;
+
+    loop {
+        while !beam(input, x, y) {
+            x += 1;
+        }
+        if beam(input, x + 99, y - 99) {
+            break 10_000 * x + (y - 99);
+        }
+        y += 1;
+    }
+}
+
+fn beam(input: &Computer, x: i64, y: i64) -> bool {
+    let mut computer = input.clone();
+    computer.input(x);
+    computer.input(y);
+    computer.run() == State::Output(1)
+}
@@ -0,0 +1,153 @@
+//! # Donut Maze
+//!
+//! The maze is first simplified to a graph between portal tiles, using a
+//! [breadth first search] from each portal to find the walking distance to every other.
+//! [Dijkstra's algorithm] then finds the shortest route, where stepping through a portal costs
+//! one extra step.
+//!
+//! In part two each state also tracks the level. Inner portals go one level deeper and outer
+//! portals one level back up, being walls on the outermost level. Levels deeper than the number
+//! of portals are never needed, as a shortest route would have to repeat a portal on the way
+//! down and back up.
+//!
+//! [breadth first search]: https://en.wikipedia.org/wiki/Breadth-first_search
+//! [Dijkstra's algorithm]: https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+use crate::util::grid::*;
+use crate::util::hash::*;
+use crate::util::heap::*;
+use crate::util::point::*;
+use std::collections::VecDeque;
+
+struct Portal {
+    label: [u8; 2],
+    outer: bool,
+    /// Index of the portal with the same label on the other side, if any.
+    pair: Option<usize>,
+    edges: Vec<(usize, u32)>,
+}
+
+pub struct Input {
+    portals: Vec<Portal>,
+    start: usize,
+    end: usize,
+}
+
+pub fn parse(input: &str) -> Input {
+    // Lines may have had their trailing spaces trimmed, so pad to the widest.
+    let width = input.lines().map(str::len).max().unwrap();
+    let padded: Vec<_> = input.lines().map(|line| format!("{line:width$}")).collect();
+    let grid = Grid::parse(&padded.join("\n"));
+    let letter = |point: Point| grid.contains(point) && grid[point].is_ascii_uppercase();
+
+    let mut points = Vec::new();
+    let mut portals = Vec::new();
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let first = Point::new(x, y);
+            if !letter(first) {
+                continue;
+            }
+
+            for step in [RIGHT, DOWN] {
+                let second = first + step;
+                if !letter(second) {
+                    continue;
+                }
+
+                let label = [grid[first], grid[second]];
+                let tile = [first - step, second + step]
+                    .into_iter()
+                    .find(|&tile| grid.contains(tile) && grid[tile] == b'.')
+                    .unwrap();
+                let outer = tile.x == 2
+                    || tile.y == 2
+                    || tile.x == grid.width - 3
+                    || tile.y == grid.height - 3;
+
+                points.push(tile);
+                portals.push(Portal { label, outer, pair: None, edges: Vec::new() });
+            }
+        }
+    }
+
+    for i in 0..portals.len() {
+        portals[i].pair =
+            (0..portals.len()).find(|&j| j != i && portals[j].label == portals[i].label);
+    }
+
+    let indices: FastMap<_, _> = points.iter().enumerate().map(|(i, &point)| (point, i)).collect();
+    for (i, &start) in points.iter().enumerate() {
+        portals[i].edges = bfs(&grid, &indices, start);
+    }
+
+    let find = |label| portals.iter().position(|portal| &portal.label == label).unwrap();
+    let start = find(b"AA");
+    let end = find(b"ZZ");
+    Input { portals, start, end }
+}
+
+pub fn part1(input: &Input) -> u32 {
+    shortest(input, false)
+}
+
+pub fn part2(input: &Input) -> u32 {
+    shortest(input, true)
+}
+
+fn shortest(input: &Input, recursive: bool) -> u32 {
+    let limit = input.portals.len();
+    let mut todo = MinHeap::new();
+    let mut seen = FastSet::new();
+    todo.push(0, (input.start, 0));
+
+    while let Some((distance, (index, level))) = todo.pop() {
+        if index == input.end && level == 0 {
+            return distance;
+        }
+        if !seen.insert((index, level)) {
+            continue;
+        }
+
+        let portal = &input.portals[index];
+        for &(next, steps) in &portal.edges {
+            todo.push(distance + steps, (next, level));
+        }
+
+        if let Some(pair) = portal.pair {
+            let next = match (recursive, portal.outer) {
+                (false, _) => level,
+                (true, true) if level == 0 => continue,
+                (true, true) => level - 1,
+                (true, false) if level == limit => continue,
+                (true, false) => level + 1,
+            };
+            todo.push(distance + 1, (pair, next));
+        }
+    }
+
+    unreachable!()
+}
+
+/// Walking distance from `start` to every other reachable portal tile.
+fn bfs(grid: &Grid<u8>, indices: &FastMap<Point, usize>, start: Point) -> Vec<(usize, u32)> {
+    let mut edges = Vec::new();
+    let mut visited = FastSet::build([start]);
+    let mut todo = VecDeque::from([(start, 0)]);
+
+    while let Some((point, distance)) = todo.pop_front() {
+        if point != start {
+            if let Some(&index) = indices.get(&point) {
+                edges.push((index, distance));
+            }
+        }
+
+        for next in ORTHOGONAL.map(|o| point + o) {
+            if grid[next] == b'.' && visited.insert(next) {
+                todo.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    edges
+}
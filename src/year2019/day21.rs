@@ -0,0 +1,53 @@
+//! # Springdroid Adventure
+//!
+//! The droid must jump when there is a hole in any of the next three tiles, as long as it can
+//! land on the fourth, that is `J = (!A | !B | !C) & D`.
+//!
+//! When running, landing isn't enough as the droid might then be trapped. After landing on
+//! `D` it must be able to either take one more step to `E` or jump again straight away to
+//! `H`, giving `J = (!A | !B | !C) & D & (E | H)`.
+use crate::util::intcode::*;
+use crate::util::parse::*;
+
+const WALK: &str = "\
+NOT A J
+NOT B T
+OR T J
+NOT C T
+OR T J
+AND D J
+WALK
+";
+
+const RUN: &str = "\
+NOT A J
+NOT B T
+OR T J
+NOT C T
+OR T J
+AND D J
+NOT E T
+NOT T T
+OR H T
+AND T J
+RUN
+";
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i64]) -> i64 {
+    survey(input, WALK)
+}
+
+pub fn part2(input: &[i64]) -> i64 {
+    survey(input, RUN)
+}
+
+/// Hull damage reported as a single non-ASCII output after the droid makes it across.
+fn survey(input: &[i64], script: &str) -> i64 {
+    let mut computer = Computer::new(input);
+    computer.input_ascii(script);
+    computer.run_to_end().into_iter().find(|&value| value > 127).expect("Droid fell into space")
+}
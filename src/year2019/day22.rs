@@ -0,0 +1,63 @@
+//! # Slam Shuffle
+//!
+//! Every technique maps a card's position `p` to a new position `ap + b` modulo the deck size,
+//! so any sequence of techniques composes into a single linear function.
+//!
+//! Applying the whole shuffle `k` times gives `aᵏp + b(aᵏ - 1) / (a - 1)`, where
+//! [modular exponentiation] computes `aᵏ` and division uses the [modular inverse], as the deck
+//! size is prime. Inverting the result finds which card ends up at a given position.
+//!
+//! [modular exponentiation]: crate::util::math::IntegerMathOps::mod_pow
+//! [modular inverse]: crate::util::math::SignedMathOps::mod_inv
+use crate::util::math::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<(i128, i128)> {
+    input
+        .lines()
+        .map(|line| {
+            if line == "deal into new stack" {
+                (-1, -1)
+            } else if line.starts_with("cut") {
+                (1, -line.signed::<i128>())
+            } else {
+                (line.signed(), 0)
+            }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[(i128, i128)]) -> i128 {
+    let size = 10007;
+    let (a, b) = shuffle(input, size);
+    (a * 2019 + b) % size
+}
+
+pub fn part2(input: &[(i128, i128)]) -> i128 {
+    let size = 119315717514047;
+    let times = 101741582076661;
+
+    let (a, b) = shuffle(input, size);
+    let ak = a.mod_pow(times, size);
+    let bk = b * (ak - 1) % size * (a - 1).rem_euclid(size).mod_inv(size).unwrap() % size;
+
+    (2020 - bk).rem_euclid(size) * ak.mod_inv(size).unwrap() % size
+}
+
+/// Order of the cards after shuffling a deck of `size` cards once.
+pub fn deck(input: &[(i128, i128)], size: i128) -> Vec<i128> {
+    let (a, b) = shuffle(input, size);
+    let mut cards = vec![0; size as usize];
+    for card in 0..size {
+        cards[((a * card + b) % size) as usize] = card;
+    }
+    cards
+}
+
+/// Combines the techniques into a single `(a, b)` with both coefficients reduced to
+/// `0..size`.
+fn shuffle(input: &[(i128, i128)], size: i128) -> (i128, i128) {
+    input
+        .iter()
+        .fold((1, 0), |(a, b), &(c, d)| ((c * a).rem_euclid(size), (c * b + d).rem_euclid(size)))
+}
@@ -0,0 +1,75 @@
+//! # Category Six
+//!
+//! Simulates the network one round at a time. Each round every [`Computer`] runs until it
+//! blocks waiting for input, receiving `-1` if its queue is empty. Outputs are collected into
+//! packets of three values and delivered to the destination's input queue.
+//!
+//! The network is idle when a whole round passes with every queue empty and no packets sent,
+//! at which point the NAT delivers its last packet to address 0.
+//!
+//! [`Computer`]: crate::util::intcode::Computer
+use crate::util::intcode::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> [i64; 2] {
+    let code: Vec<_> = input.iter_signed().collect();
+    let mut computers: Vec<_> = (0..50)
+        .map(|address| {
+            let mut computer = Computer::new(&code);
+            computer.input(address);
+            computer
+        })
+        .collect();
+    let mut queues = vec![Vec::new(); 50];
+
+    let mut first = None;
+    let mut nat = None;
+    let mut previous = None;
+
+    loop {
+        let mut active = false;
+
+        for (address, computer) in computers.iter_mut().enumerate() {
+            if queues[address].is_empty() {
+                computer.input(-1);
+            } else {
+                active = true;
+                queues[address].drain(..).for_each(|value| computer.input(value));
+            }
+
+            let mut output = Vec::new();
+            while let State::Output(value) = computer.run() {
+                output.push(value);
+            }
+
+            for packet in output.chunks_exact(3) {
+                active = true;
+                let [destination, x, y] = packet[..] else { unreachable!() };
+
+                if destination == 255 {
+                    first.get_or_insert(y);
+                    nat = Some((x, y));
+                } else {
+                    queues[destination as usize].extend([x, y]);
+                }
+            }
+        }
+
+        if !active {
+            let (x, y) = nat.unwrap();
+            if previous == Some(y) {
+                break [first.unwrap(), y];
+            }
+            previous = Some(y);
+            queues[0].extend([x, y]);
+        }
+    }
+}
+
+pub fn part1(input: &[i64; 2]) -> i64 {
+    input[0]
+}
+
+pub fn part2(input: &[i64; 2]) -> i64 {
+    input[1]
+}
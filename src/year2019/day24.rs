@@ -0,0 +1,122 @@
+//! # Planet of Discord
+//!
+//! Each 5x5 layout fits in the lower 25 bits of an integer, with bit `5y + x` set for a bug at
+//! `(x, y)`. Conveniently this bitmask is also the biodiversity rating.
+//!
+//! For part two, each level is a bitmask in a vector that grows by one level in each direction
+//! every minute, which is as far as bugs can spread. The neighbors of each tile, including
+//! those on adjacent levels, are precomputed once.
+use crate::util::hash::*;
+
+/// Neighbors as `(level offset, tile)` pairs.
+type Neighbors = Vec<Vec<(usize, usize)>>;
+
+pub fn parse(input: &str) -> u32 {
+    input
+        .bytes()
+        .filter(|&b| b != b'\n')
+        .enumerate()
+        .fold(0, |acc, (i, b)| acc | (((b == b'#') as u32) << i))
+}
+
+pub fn part1(input: &u32) -> u32 {
+    let neighbors: Neighbors = (0..25)
+        .map(|tile| {
+            let (x, y) = (tile % 5, tile / 5);
+            let mut result = Vec::new();
+            if x > 0 {
+                result.push((1, tile - 1));
+            }
+            if x < 4 {
+                result.push((1, tile + 1));
+            }
+            if y > 0 {
+                result.push((1, tile - 5));
+            }
+            if y < 4 {
+                result.push((1, tile + 5));
+            }
+            result
+        })
+        .collect();
+
+    let mut layout = *input;
+    let mut seen = FastSet::new();
+
+    while seen.insert(layout) {
+        layout = step(&[0, layout, 0], 1, &neighbors);
+    }
+
+    layout
+}
+
+pub fn part2(input: &u32) -> u32 {
+    bugs(input, 200)
+}
+
+/// Total number of bugs on all recursive levels after `minutes`.
+pub fn bugs(input: &u32, minutes: usize) -> u32 {
+    let neighbors = recursive();
+    let mut levels = vec![*input];
+
+    for _ in 0..minutes {
+        let mut padded = vec![0, 0];
+        padded.extend(&levels);
+        padded.extend([0, 0]);
+        levels = (1..padded.len() - 1).map(|level| step(&padded, level, &neighbors)).collect();
+    }
+
+    levels.iter().map(|level| level.count_ones()).sum()
+}
+
+/// Next layout of `levels[level]`, where `levels[level - 1]` is the enclosing outer level and
+/// `levels[level + 1]` the inner level in the center tile.
+fn step(levels: &[u32], level: usize, neighbors: &Neighbors) -> u32 {
+    let current = levels[level];
+    let mut next = 0;
+
+    for (tile, adjacent) in neighbors.iter().enumerate() {
+        let count: u32 =
+            adjacent.iter().map(|&(offset, other)| (levels[level + offset - 1] >> other) & 1).sum();
+        let bug = current & (1 << tile) != 0;
+        if count == 1 || (!bug && count == 2) {
+            next |= 1 << tile;
+        }
+    }
+
+    next
+}
+
+fn recursive() -> Neighbors {
+    (0..25)
+        .map(|tile| {
+            let mut result = Vec::new();
+            if tile == 12 {
+                return result;
+            }
+            let (x, y) = (tile % 5, tile / 5);
+
+            for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let (nx, ny) = (x + dx, y + dy);
+
+                if !(0..5).contains(&nx) || !(0..5).contains(&ny) {
+                    // Outer level, the tile next to the center in the same direction.
+                    result.push((0, (12 + dx + 5 * dy) as usize));
+                } else if (nx, ny) == (2, 2) {
+                    // Inner level, the whole edge facing this tile.
+                    let edge: Vec<_> = match (dx, dy) {
+                        (1, 0) => (0..5).map(|i| 5 * i).collect(),
+                        (-1, 0) => (0..5).map(|i| 5 * i + 4).collect(),
+                        (0, 1) => (0..5).collect(),
+                        _ => (20..25).collect(),
+                    };
+                    result.extend(edge.into_iter().map(|other| (2, other)));
+                } else {
+                    result.push((1, (ny * 5 + nx) as usize));
+                }
+            }
+
+            result
+        })
+        .collect()
+}
@@ -0,0 +1,141 @@
+//! # Cryostasis
+//!
+//! Plays the text adventure automatically. A [depth first search] explores every room,
+//! picking up every item apart from the few known to end the game, and remembers the route to
+//! the security checkpoint along with the direction of the pressure sensitive floor.
+//!
+//! At the checkpoint every subset of items is tried in [Gray code] order, so that each attempt
+//! only needs to take or drop a single item, until the droid has the right weight.
+//!
+//! This puzzle only has one part.
+//!
+//! [depth first search]: https://en.wikipedia.org/wiki/Depth-first_search
+//! [Gray code]: https://en.wikipedia.org/wiki/Gray_code
+use crate::util::intcode::*;
+use crate::util::parse::*;
+
+const DANGEROUS: [&str; 5] =
+    ["escape pod", "giant electromagnet", "infinite loop", "molten lava", "photons"];
+
+struct Room {
+    name: String,
+    doors: Vec<String>,
+    items: Vec<String>,
+}
+
+#[derive(Default)]
+struct Explorer {
+    items: Vec<String>,
+    checkpoint: Vec<String>,
+    floor: String,
+}
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input.iter_signed().collect()
+}
+
+pub fn part1(input: &[i64]) -> u64 {
+    let mut computer = Computer::new(input);
+    let mut explorer = Explorer::default();
+    let room = describe(&computer.run_ascii());
+    explore(&mut computer, &mut explorer, &room, None, &mut Vec::new());
+
+    for direction in &explorer.checkpoint {
+        command(&mut computer, direction);
+    }
+
+    // The droid starts holding every item. Consecutive Gray codes differ in one bit, the
+    // lowest set bit of the step number, so each step drops or takes a single item.
+    let items = &explorer.items;
+    let mut holding = (1_u32 << items.len()) - 1;
+
+    for step in 1_u32.. {
+        let output = command(&mut computer, &explorer.floor);
+        if !output.contains("Alert!") {
+            return output.split_ascii_whitespace().find_map(|word| word.parse().ok()).unwrap();
+        }
+
+        let bit = step.trailing_zeros() as usize;
+        assert!(bit < items.len(), "No combination of items has the right weight");
+        let verb = if holding & (1 << bit) != 0 { "drop" } else { "take" };
+        command(&mut computer, &format!("{verb} {}", items[bit]));
+        holding ^= 1 << bit;
+    }
+
+    unreachable!()
+}
+
+fn explore(
+    computer: &mut Computer,
+    explorer: &mut Explorer,
+    room: &Room,
+    from: Option<&str>,
+    path: &mut Vec<String>,
+) {
+    for item in &room.items {
+        if !DANGEROUS.contains(&item.as_str()) {
+            command(computer, &format!("take {item}"));
+            explorer.items.push(item.clone());
+        }
+    }
+
+    if room.name == "Security Checkpoint" {
+        explorer.checkpoint = path.clone();
+        explorer.floor =
+            room.doors.iter().find(|&door| Some(door.as_str()) != from).unwrap().clone();
+        return;
+    }
+
+    for door in &room.doors {
+        if Some(door.as_str()) == from {
+            continue;
+        }
+
+        let next = describe(&command(computer, door));
+        let back = opposite(door);
+        path.push(door.clone());
+        explore(computer, explorer, &next, Some(back), path);
+        path.pop();
+        command(computer, back);
+    }
+}
+
+fn command(computer: &mut Computer, command: &str) -> String {
+    computer.input_ascii(&format!("{command}\n"));
+    computer.run_ascii()
+}
+
+fn describe(output: &str) -> Room {
+    let mut name = String::new();
+    let mut doors = Vec::new();
+    let mut items = Vec::new();
+    let mut section = None;
+
+    for line in output.lines() {
+        if let Some(title) = line.strip_prefix("== ") {
+            name = title.trim_end_matches(" ==").to_string();
+        } else if line.starts_with("Doors here lead") {
+            section = Some(&mut doors);
+        } else if line.starts_with("Items here") {
+            section = Some(&mut items);
+        } else if let Some(entry) = line.strip_prefix("- ") {
+            if let Some(list) = section.as_mut() {
+                list.push(entry.to_string());
+            }
+        } else {
+            section = None;
+        }
+    }
+
+    Room { name, doors, items }
+}
+
+fn opposite(direction: &str) -> &'static str {
+    match direction {
+        "north" => "south",
+        "south" => "north",
+        "east" => "west",
+        "west" => "east",
+        _ => unreachable!(),
+    }
+}
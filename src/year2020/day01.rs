@@ -0,0 +1,37 @@
+//! # Report Repair
+//!
+//! Every expense is at most 2020, so a lookup table of 2021 booleans answers whether the
+//! complement of a number is present in constant time. The first part checks each number once
+//! and the second part each pair, giving linear and quadratic solutions.
+use crate::util::parse::*;
+
+pub struct Input {
+    expenses: Vec<usize>,
+    present: [bool; 2021],
+}
+
+pub fn parse(input: &str) -> Input {
+    let expenses: Vec<usize> = input.iter_unsigned().filter(|&n| n <= 2020).collect();
+    let mut present = [false; 2021];
+    expenses.iter().for_each(|&n| present[n] = true);
+    Input { expenses, present }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let Input { expenses, present } = input;
+    expenses.iter().find(|&&a| present[2020 - a]).map(|&a| a * (2020 - a)).unwrap()
+}
+
+pub fn part2(input: &Input) -> usize {
+    let Input { expenses, present } = input;
+
+    for (i, &a) in expenses.iter().enumerate() {
+        for &b in &expenses[i + 1..] {
+            if a + b < 2020 && present[2020 - a - b] {
+                return a * b * (2020 - a - b);
+            }
+        }
+    }
+
+    unreachable!()
+}
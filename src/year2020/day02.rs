@@ -0,0 +1,49 @@
+//! # Password Philosophy
+//!
+//! Parses each policy once, then both parts are a simple filter over the parsed lines.
+use crate::util::parse::*;
+
+pub struct Policy<'a> {
+    first: usize,
+    second: usize,
+    letter: u8,
+    password: &'a [u8],
+}
+
+pub fn parse(input: &str) -> Vec<Policy<'_>> {
+    input
+        .lines()
+        .map(|line| {
+            let (range, rest) = line.split_once(' ').unwrap();
+            let (first, second) = range.split_once('-').unwrap();
+            let (letter, password) = rest.split_once(": ").unwrap();
+            Policy {
+                first: first.unsigned(),
+                second: second.unsigned(),
+                letter: letter.as_bytes()[0],
+                password: password.as_bytes(),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Policy<'_>]) -> usize {
+    input
+        .iter()
+        .filter(|policy| {
+            let count = policy.password.iter().filter(|&&b| b == policy.letter).count();
+            (policy.first..=policy.second).contains(&count)
+        })
+        .count()
+}
+
+pub fn part2(input: &[Policy<'_>]) -> usize {
+    input
+        .iter()
+        .filter(|policy| {
+            let first = policy.password[policy.first - 1] == policy.letter;
+            let second = policy.password[policy.second - 1] == policy.letter;
+            first != second
+        })
+        .count()
+}
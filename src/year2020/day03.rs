@@ -0,0 +1,28 @@
+//! # Toboggan Trajectory
+//!
+//! The pattern repeats to the right, so the column wraps around using the width of the grid.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(input: &Grid<u8>) -> usize {
+    trees(input, 3, 1)
+}
+
+pub fn part2(input: &Grid<u8>) -> usize {
+    [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .into_iter()
+        .map(|(right, down)| trees(input, right, down))
+        .product()
+}
+
+fn trees(grid: &Grid<u8>, right: i32, down: i32) -> usize {
+    (0..grid.height)
+        .step_by(down as usize)
+        .enumerate()
+        .filter(|&(i, y)| grid[Point::new((i as i32 * right) % grid.width, y)] == b'#')
+        .count()
+}
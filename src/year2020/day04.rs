@@ -0,0 +1,59 @@
+//! # Passport Processing
+//!
+//! Passports are split into their fields up front, ignoring the optional `cid` field. The
+//! first part only needs to count the remaining seven fields, while the second part validates
+//! each field value with its own rule.
+use crate::util::parse::*;
+
+type Passport<'a> = Vec<(&'a str, &'a str)>;
+
+pub fn parse(input: &str) -> Vec<Passport<'_>> {
+    input
+        .split("\n\n")
+        .map(|block| {
+            block
+                .split_ascii_whitespace()
+                .map(|field| field.split_once(':').unwrap())
+                .filter(|&(key, _)| key != "cid")
+                .collect()
+        })
+        .filter(|passport: &Passport<'_>| passport.len() == 7)
+        .collect()
+}
+
+pub fn part1(input: &[Passport<'_>]) -> usize {
+    input.len()
+}
+
+pub fn part2(input: &[Passport<'_>]) -> usize {
+    input.iter().filter(|passport| passport.iter().all(|&(key, value)| valid(key, value))).count()
+}
+
+fn valid(key: &str, value: &str) -> bool {
+    let year = |min, max| value.len() == 4 && (min..=max).contains(&value.unsigned::<u32>());
+
+    match key {
+        "byr" => year(1920, 2002),
+        "iyr" => year(2010, 2020),
+        "eyr" => year(2020, 2030),
+        "hgt" => {
+            let digits = value.trim_end_matches(char::is_alphabetic);
+            if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return false;
+            }
+            match &value[digits.len()..] {
+                "cm" => (150..=193).contains(&digits.unsigned::<u32>()),
+                "in" => (59..=76).contains(&digits.unsigned::<u32>()),
+                _ => false,
+            }
+        }
+        "hcl" => {
+            value.len() == 7
+                && value.starts_with('#')
+                && value[1..].bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+        }
+        "ecl" => matches!(value, "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth"),
+        "pid" => value.len() == 9 && value.bytes().all(|b| b.is_ascii_digit()),
+        _ => false,
+    }
+}
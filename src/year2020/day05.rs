@@ -0,0 +1,24 @@
+//! # Binary Boarding
+//!
+//! The boarding pass is the seat ID written in binary, with `B` and `R` as one and `F` and `L`
+//! as zero, so there's no need to work out the row and column separately.
+//!
+//! The seats with IDs from the minimum to the maximum are all taken except ours, so the
+//! missing ID is the XOR of the full range with the XOR of every seen ID.
+pub fn parse(input: &str) -> Vec<u32> {
+    input
+        .lines()
+        .map(|line| line.bytes().fold(0, |acc, b| (acc << 1) | matches!(b, b'B' | b'R') as u32))
+        .collect()
+}
+
+pub fn part1(input: &[u32]) -> u32 {
+    *input.iter().max().unwrap()
+}
+
+pub fn part2(input: &[u32]) -> u32 {
+    let min = *input.iter().min().unwrap();
+    let max = *input.iter().max().unwrap();
+    let expected = (min..=max).fold(0, |acc, id| acc ^ id);
+    input.iter().fold(expected, |acc, id| acc ^ id)
+}
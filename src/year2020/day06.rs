@@ -0,0 +1,23 @@
+//! # Custom Customs
+//!
+//! Each person's answers are stored as a bitmask of the 26 questions. Anyone answering is the
+//! bitwise OR of a group's masks and everyone answering is the bitwise AND.
+pub fn parse(input: &str) -> Vec<Vec<u32>> {
+    input
+        .split("\n\n")
+        .map(|group| {
+            group
+                .lines()
+                .map(|line| line.bytes().fold(0, |acc, b| acc | (1 << (b - b'a'))))
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Vec<u32>]) -> u32 {
+    input.iter().map(|group| group.iter().fold(0, |acc, n| acc | n).count_ones()).sum()
+}
+
+pub fn part2(input: &[Vec<u32>]) -> u32 {
+    input.iter().map(|group| group.iter().fold(u32::MAX, |acc, n| acc & n).count_ones()).sum()
+}
@@ -0,0 +1,83 @@
+//! # Handy Haversacks
+//!
+//! Each bag color is assigned an index, then the rules become a graph of weighted edges from
+//! each bag to the bags it contains. Both parts are depth first searches with memoization, as
+//! many bags share the same contents.
+use crate::util::hash::*;
+use crate::util::parse::*;
+
+pub struct Input {
+    gold: usize,
+    contents: Vec<Vec<(usize, u32)>>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let mut indices = FastMap::new();
+    let mut index = |color| {
+        let size = indices.len();
+        *indices.entry(color).or_insert(size)
+    };
+
+    let rules: Vec<_> = input
+        .lines()
+        .map(|line| {
+            let (outer, inner) = line.split_once(" bags contain ").unwrap();
+            let outer = index(outer);
+            let bags: Vec<_> = inner
+                .split(", ")
+                .filter(|bag| !bag.starts_with("no"))
+                .map(|bag| {
+                    let (count, rest) = bag.split_once(' ').unwrap();
+                    let (color, _) = rest.rsplit_once(' ').unwrap();
+                    (index(color), count.unsigned())
+                })
+                .collect();
+            (outer, bags)
+        })
+        .collect();
+    let gold = index("shiny gold");
+
+    let mut contents = vec![Vec::new(); indices.len()];
+    for (outer, bags) in rules {
+        contents[outer] = bags;
+    }
+
+    Input { gold, contents }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let mut cache = vec![None; input.contents.len()];
+    (0..input.contents.len())
+        .filter(|&bag| bag != input.gold && holds_gold(input, &mut cache, bag))
+        .count()
+}
+
+pub fn part2(input: &Input) -> u32 {
+    let mut cache = vec![None; input.contents.len()];
+    count(input, &mut cache, input.gold) - 1
+}
+
+fn holds_gold(input: &Input, cache: &mut [Option<bool>], bag: usize) -> bool {
+    if let Some(result) = cache[bag] {
+        return result;
+    }
+
+    let result = bag == input.gold
+        || input.contents[bag].iter().any(|&(inner, _)| holds_gold(input, cache, inner));
+    cache[bag] = Some(result);
+    result
+}
+
+/// Total number of bags including the outermost bag itself.
+fn count(input: &Input, cache: &mut [Option<u32>], bag: usize) -> u32 {
+    if let Some(result) = cache[bag] {
+        return result;
+    }
+
+    let result = 1 + input.contents[bag]
+        .iter()
+        .map(|&(inner, n)| n * count(input, cache, inner))
+        .sum::<u32>();
+    cache[bag] = Some(result);
+    result
+}
@@ -0,0 +1,39 @@
+//! # Handheld Halting
+//!
+//! Runs the boot code on the shared [handheld] console. For the second part, each `jmp` or
+//! `nop` is swapped in turn until the program terminates. There are only a few hundred
+//! instructions, so brute force is fast enough.
+//!
+//! [handheld]: crate::util::handheld
+use crate::util::handheld::*;
+
+pub fn parse(input: &str) -> Vec<Instruction> {
+    crate::util::handheld::parse(input)
+}
+
+pub fn part1(input: &[Instruction]) -> i32 {
+    match run(input) {
+        State::Loop(accumulator) => accumulator,
+        State::Terminated(_) => unreachable!(),
+    }
+}
+
+pub fn part2(input: &[Instruction]) -> i32 {
+    let mut program = input.to_vec();
+
+    for i in 0..program.len() {
+        let original = program[i];
+        program[i] = match original {
+            Instruction::Acc(_) => continue,
+            Instruction::Jmp(n) => Instruction::Nop(n),
+            Instruction::Nop(n) => Instruction::Jmp(n),
+        };
+
+        if let State::Terminated(accumulator) = run(&program) {
+            return accumulator;
+        }
+        program[i] = original;
+    }
+
+    unreachable!()
+}
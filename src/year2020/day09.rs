@@ -0,0 +1,53 @@
+//! # Encoding Error
+//!
+//! The first part checks each number against every pair in the preceding window. The second
+//! part uses a sliding window over the prefix, growing on the right while the sum is too small
+//! and shrinking on the left while it's too large.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<u64> {
+    input.iter_unsigned().collect()
+}
+
+pub fn part1(input: &[u64]) -> u64 {
+    invalid(input, 25)
+}
+
+pub fn part2(input: &[u64]) -> u64 {
+    weakness(input, 25)
+}
+
+/// First number that isn't the sum of two of the `preamble` numbers before it.
+pub fn invalid(input: &[u64], preamble: usize) -> u64 {
+    input
+        .windows(preamble + 1)
+        .find(|window| {
+            let (previous, &[target]) = window.split_at(preamble) else { unreachable!() };
+            !previous
+                .iter()
+                .enumerate()
+                .any(|(i, a)| previous[i + 1..].iter().any(|b| a + b == target))
+        })
+        .map(|window| window[preamble])
+        .unwrap()
+}
+
+pub fn weakness(input: &[u64], preamble: usize) -> u64 {
+    let target = invalid(input, preamble);
+    let mut start = 0;
+    let mut sum = 0;
+
+    for end in 0..input.len() {
+        sum += input[end];
+        while sum > target {
+            sum -= input[start];
+            start += 1;
+        }
+        if sum == target && end > start {
+            let range = &input[start..=end];
+            return range.iter().min().unwrap() + range.iter().max().unwrap();
+        }
+    }
+
+    unreachable!()
+}
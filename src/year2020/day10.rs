@@ -0,0 +1,32 @@
+//! # Adapter Array
+//!
+//! Every adapter must be used in the first part, so the chain is simply the adapters in sorted
+//! order. The second part is dynamic programming, where the number of ways to reach each
+//! adapter is the sum of the ways to reach the adapters up to three jolts below it.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<usize> {
+    let mut adapters: Vec<_> = input.iter_unsigned().collect();
+    adapters.push(0);
+    adapters.sort_unstable();
+    adapters.push(adapters.last().unwrap() + 3);
+    adapters
+}
+
+pub fn part1(input: &[usize]) -> usize {
+    let mut differences = [0; 4];
+    input.windows(2).for_each(|w| differences[w[1] - w[0]] += 1);
+    differences[1] * differences[3]
+}
+
+pub fn part2(input: &[usize]) -> u64 {
+    let mut ways = vec![0; input.len()];
+    ways[0] = 1;
+
+    for i in 1..input.len() {
+        ways[i] =
+            (i.saturating_sub(3)..i).filter(|&j| input[i] - input[j] <= 3).map(|j| ways[j]).sum();
+    }
+
+    ways[input.len() - 1]
+}
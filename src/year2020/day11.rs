@@ -0,0 +1,64 @@
+//! # Seating System
+//!
+//! The floor never changes, so the seats that each seat can see are found once up front. In the
+//! first part these are the adjacent seats, and in the second part the first seat in each of
+//! the eight directions. Each round then only needs to look at a short list of indices.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(input: &Grid<u8>) -> usize {
+    simulate(input, 4, false)
+}
+
+pub fn part2(input: &Grid<u8>) -> usize {
+    simulate(input, 5, true)
+}
+
+fn simulate(grid: &Grid<u8>, tolerance: usize, far: bool) -> usize {
+    let index = |point: Point| (point.y * grid.width + point.x) as usize;
+    let mut seats = Vec::new();
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            if grid[point] != b'L' {
+                continue;
+            }
+
+            let visible: Vec<_> = DIAGONAL
+                .iter()
+                .filter_map(|&direction| {
+                    let mut next = point + direction;
+                    while far && grid.contains(next) && grid[next] == b'.' {
+                        next += direction;
+                    }
+                    (grid.contains(next) && grid[next] == b'L').then(|| index(next))
+                })
+                .collect();
+
+            seats.push((index(point), visible));
+        }
+    }
+
+    let mut occupied = vec![false; grid.bytes.len()];
+    let mut next = occupied.clone();
+
+    loop {
+        let mut changed = false;
+
+        for (seat, visible) in &seats {
+            let count = visible.iter().filter(|&&i| occupied[i]).count();
+            next[*seat] = if occupied[*seat] { count < tolerance } else { count == 0 };
+            changed |= next[*seat] != occupied[*seat];
+        }
+
+        if !changed {
+            return occupied.iter().filter(|&&b| b).count();
+        }
+        std::mem::swap(&mut occupied, &mut next);
+    }
+}
@@ -0,0 +1,53 @@
+//! # Rain Risk
+//!
+//! Both parts move the ship with a vector. In the first part the vector is the direction the
+//! ship faces, and in the second part it's the waypoint. The only difference is whether the
+//! compass directions move the ship or the vector. Turns are always multiples of 90 degrees, so
+//! they're applied as repeated quarter turns.
+use crate::util::parse::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Vec<(u8, i32)> {
+    input.lines().map(|line| (line.as_bytes()[0], line.signed())).collect()
+}
+
+pub fn part1(input: &[(u8, i32)]) -> i32 {
+    navigate(input, RIGHT, false)
+}
+
+pub fn part2(input: &[(u8, i32)]) -> i32 {
+    navigate(input, Point::new(10, -1), true)
+}
+
+fn navigate(input: &[(u8, i32)], mut vector: Point, waypoint: bool) -> i32 {
+    let mut ship = ORIGIN;
+
+    for &(action, value) in input {
+        let direction = match action {
+            b'N' => UP,
+            b'S' => DOWN,
+            b'E' => RIGHT,
+            b'W' => LEFT,
+            b'L' => {
+                (0..value / 90).for_each(|_| vector = vector.counter_clockwise());
+                continue;
+            }
+            b'R' => {
+                (0..value / 90).for_each(|_| vector = vector.clockwise());
+                continue;
+            }
+            _ => {
+                ship += vector * value;
+                continue;
+            }
+        };
+
+        if waypoint {
+            vector += direction * value;
+        } else {
+            ship += direction * value;
+        }
+    }
+
+    ship.manhattan(ORIGIN)
+}
@@ -0,0 +1,46 @@
+//! # Shuttle Search
+//!
+//! The second part is the [Chinese remainder theorem] as the bus IDs are all prime. Solved
+//! with sieving, finding a timestamp that satisfies the first bus, then stepping by the product
+//! of the IDs seen so far so that every earlier bus stays satisfied while searching for the
+//! next one.
+//!
+//! [Chinese remainder theorem]: https://en.wikipedia.org/wiki/Chinese_remainder_theorem
+use crate::util::parse::*;
+
+pub struct Input {
+    timestamp: u64,
+    buses: Vec<(u64, u64)>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let (timestamp, schedule) = input.split_once('\n').unwrap();
+    let buses = schedule
+        .trim()
+        .split(',')
+        .enumerate()
+        .filter(|&(_, id)| id != "x")
+        .map(|(offset, id)| (offset as u64, id.unsigned()))
+        .collect();
+    Input { timestamp: timestamp.unsigned(), buses }
+}
+
+pub fn part1(input: &Input) -> u64 {
+    let (wait, id) =
+        input.buses.iter().map(|&(_, id)| ((id - input.timestamp % id) % id, id)).min().unwrap();
+    wait * id
+}
+
+pub fn part2(input: &Input) -> u64 {
+    let mut timestamp = 0;
+    let mut step = 1;
+
+    for &(offset, id) in &input.buses {
+        while !(timestamp + offset).is_multiple_of(id) {
+            timestamp += step;
+        }
+        step *= id;
+    }
+
+    timestamp
+}
@@ -0,0 +1,72 @@
+//! # Docking Data
+//!
+//! Each mask is stored as two bitmasks, one of the bits forced to one and one of the floating
+//! `X` bits. In the second part every combination of floating bits is enumerated by counting
+//! down through the subsets of the floating mask with the `(subset - 1) & mask` trick.
+use crate::util::hash::*;
+use crate::util::parse::*;
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Mask { ones: u64, floating: u64 },
+    Mem { address: u64, value: u64 },
+}
+
+pub fn parse(input: &str) -> Vec<Instruction> {
+    input
+        .lines()
+        .map(|line| {
+            if let Some(mask) = line.strip_prefix("mask = ") {
+                let bits = |bit| mask.bytes().fold(0, |acc, b| (acc << 1) | (b == bit) as u64);
+                Instruction::Mask { ones: bits(b'1'), floating: bits(b'X') }
+            } else {
+                let mut numbers = line.iter_unsigned();
+                Instruction::Mem {
+                    address: numbers.next().unwrap(),
+                    value: numbers.next().unwrap(),
+                }
+            }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Instruction]) -> u64 {
+    let mut memory = FastMap::new();
+    let (mut ones, mut floating) = (0, 0);
+
+    for &instruction in input {
+        match instruction {
+            Instruction::Mask { ones: o, floating: f } => (ones, floating) = (o, f),
+            Instruction::Mem { address, value } => {
+                memory.insert(address, (value & floating) | ones);
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+pub fn part2(input: &[Instruction]) -> u64 {
+    let mut memory = FastMap::new();
+    let (mut ones, mut floating) = (0, 0);
+
+    for &instruction in input {
+        match instruction {
+            Instruction::Mask { ones: o, floating: f } => (ones, floating) = (o, f),
+            Instruction::Mem { address, value } => {
+                let base = (address | ones) & !floating;
+                let mut subset = floating;
+
+                loop {
+                    memory.insert(base | subset, value);
+                    if subset == 0 {
+                        break;
+                    }
+                    subset = (subset - 1) & floating;
+                }
+            }
+        }
+    }
+
+    memory.values().sum()
+}
@@ -0,0 +1,40 @@
+//! # Rambunctious Recitation
+//!
+//! The [Van Eck sequence] has no known shortcut, so the game is played directly. Numbers can
+//! never exceed the number of turns, so the turn each number was last spoken is stored in a flat
+//! array which is much faster than a hash map for thirty million turns.
+//!
+//! [Van Eck sequence]: https://oeis.org/A181391
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<u32> {
+    input.iter_unsigned().collect()
+}
+
+pub fn part1(input: &[u32]) -> u32 {
+    play(input, 2020)
+}
+
+pub fn part2(input: &[u32]) -> u32 {
+    play(input, 30_000_000)
+}
+
+/// Number spoken on the final turn.
+pub fn play(input: &[u32], turns: u32) -> u32 {
+    // Zero means never spoken, so turns are stored starting from one.
+    let mut last = vec![0; turns as usize];
+    let (&start, previous) = input.split_last().unwrap();
+
+    for (turn, &n) in previous.iter().enumerate() {
+        last[n as usize] = turn as u32 + 1;
+    }
+
+    (input.len() as u32..turns).fold(start, |spoken, turn| {
+        let seen = std::mem::replace(&mut last[spoken as usize], turn);
+        if seen == 0 {
+            0
+        } else {
+            turn - seen
+        }
+    })
+}
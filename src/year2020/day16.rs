@@ -0,0 +1,85 @@
+//! # Ticket Translation
+//!
+//! Each position starts with a bitmask of every field whose rules accept the values of all
+//! valid tickets at that position. Some position always has exactly one candidate, so that
+//! field is assigned and removed from every other position, repeating until all fields are known.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub struct Input<'a> {
+    names: Vec<&'a str>,
+    rules: Vec<[u32; 4]>,
+    ticket: Vec<u32>,
+    nearby: Vec<Vec<u32>>,
+}
+
+impl Input<'_> {
+    fn accepts(&self, rule: usize, value: u32) -> bool {
+        let [a, b, c, d] = self.rules[rule];
+        (a..=b).contains(&value) || (c..=d).contains(&value)
+    }
+
+    fn valid(&self, value: u32) -> bool {
+        (0..self.rules.len()).any(|rule| self.accepts(rule, value))
+    }
+}
+
+pub fn parse(input: &str) -> Input<'_> {
+    let [rules, ticket, nearby] = input.splitn(3, "\n\n").chunk::<3>().next().unwrap();
+    let (names, rules) = rules
+        .lines()
+        .map(|line| {
+            let (name, ranges) = line.split_once(": ").unwrap();
+            (name, ranges.iter_unsigned().chunk::<4>().next().unwrap())
+        })
+        .unzip();
+    let ticket = ticket.iter_unsigned().collect();
+    let nearby = nearby.lines().skip(1).map(|line| line.iter_unsigned().collect()).collect();
+
+    Input { names, rules, ticket, nearby }
+}
+
+pub fn part1(input: &Input<'_>) -> u32 {
+    input.nearby.iter().flatten().filter(|&&value| !input.valid(value)).sum()
+}
+
+pub fn part2(input: &Input<'_>) -> u64 {
+    decode(input)
+        .iter()
+        .filter(|(name, _)| name.starts_with("departure"))
+        .map(|&(_, value)| value as u64)
+        .product()
+}
+
+/// Pairs each field name with its value on your ticket.
+pub fn decode<'a>(input: &Input<'a>) -> Vec<(&'a str, u32)> {
+    let size = input.rules.len();
+    let valid: Vec<_> = input
+        .nearby
+        .iter()
+        .filter(|ticket| ticket.iter().all(|&value| input.valid(value)))
+        .collect();
+
+    let mut candidates: Vec<u32> = (0..size)
+        .map(|position| {
+            (0..size)
+                .filter(|&rule| valid.iter().all(|ticket| input.accepts(rule, ticket[position])))
+                .fold(0, |acc, rule| acc | (1 << rule))
+        })
+        .collect();
+
+    let mut fields = vec![0; size];
+
+    for _ in 0..size {
+        let position = candidates.iter().position(|mask| mask.count_ones() == 1).unwrap();
+        let mask = candidates[position];
+        fields[mask.trailing_zeros() as usize] = position;
+        candidates.iter_mut().for_each(|candidate| *candidate &= !mask);
+    }
+
+    fields
+        .iter()
+        .enumerate()
+        .map(|(rule, &position)| (input.names[rule], input.ticket[position]))
+        .collect()
+}
@@ -0,0 +1,81 @@
+//! # Conway Cubes
+//!
+//! After six cycles the active region can grow by at most six cubes in every direction, so
+//! the pocket dimension is stored as a dense flat array with enough padding that neighbors
+//! never fall outside. Both parts share the same code, with the fourth dimension given a
+//! size of one in the first part.
+//!
+//! Each cycle, every active cube adds one to the count of each neighbor, then the new state is
+//! decided from the counts.
+const CYCLES: usize = 6;
+
+pub struct Input {
+    width: usize,
+    height: usize,
+    active: Vec<(usize, usize)>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let lines: Vec<_> = input.lines().map(str::as_bytes).collect();
+    let active = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.iter().enumerate().filter(|&(_, &b)| b == b'#').map(move |(x, _)| (x, y))
+        })
+        .collect();
+    Input { width: lines[0].len(), height: lines.len(), active }
+}
+
+pub fn part1(input: &Input) -> usize {
+    simulate(input, false)
+}
+
+pub fn part2(input: &Input) -> usize {
+    simulate(input, true)
+}
+
+fn simulate(input: &Input, hyper: bool) -> usize {
+    // One extra cube of padding on each side keeps every neighbor in bounds.
+    let pad = CYCLES + 1;
+    let size = [
+        input.width + 2 * pad,
+        input.height + 2 * pad,
+        1 + 2 * pad,
+        if hyper { 1 + 2 * pad } else { 1 },
+    ];
+    let stride = [1, size[0], size[0] * size[1], size[0] * size[1] * size[2]];
+    let total = stride[3] * size[3];
+
+    let w = if hyper { pad } else { 0 };
+    let mut active = vec![false; total];
+    for &(x, y) in &input.active {
+        active[(x + pad) * stride[0] + (y + pad) * stride[1] + pad * stride[2] + w * stride[3]] =
+            true;
+    }
+
+    let dimensions = if hyper { 4 } else { 3 };
+    let mut offsets = vec![0_isize];
+    for &s in &stride[..dimensions] {
+        offsets = offsets.iter().flat_map(|&o| [o - s as isize, o, o + s as isize]).collect();
+    }
+    offsets.retain(|&o| o != 0);
+
+    let mut count = vec![0_u8; total];
+
+    for _ in 0..CYCLES {
+        count.fill(0);
+
+        for (i, _) in active.iter().enumerate().filter(|&(_, &a)| a) {
+            for &offset in &offsets {
+                count[i.wrapping_add_signed(offset)] += 1;
+            }
+        }
+
+        for (cube, &n) in active.iter_mut().zip(&count) {
+            *cube = n == 3 || (*cube && n == 2);
+        }
+    }
+
+    active.iter().filter(|&&a| a).count()
+}
@@ -0,0 +1,56 @@
+//! # Operation Order
+//!
+//! A small [recursive descent parser] evaluates each expression, with parentheses handled by
+//! recursing into a nested expression.
+//!
+//! In the second part addition binds tighter than multiplication. Multiplication is
+//! associative, so when a `*` is found the rest of the expression up to the closing parenthesis
+//! can be evaluated first, then multiplied by the total so far.
+//!
+//! [recursive descent parser]: https://en.wikipedia.org/wiki/Recursive_descent_parser
+pub fn parse(input: &str) -> Vec<Vec<u8>> {
+    input.lines().map(|line| line.bytes().filter(|&b| b != b' ').collect()).collect()
+}
+
+pub fn part1(input: &[Vec<u8>]) -> u64 {
+    input.iter().map(|line| evaluate(line, &mut 0, false)).sum()
+}
+
+pub fn part2(input: &[Vec<u8>]) -> u64 {
+    input.iter().map(|line| evaluate(line, &mut 0, true)).sum()
+}
+
+/// Evaluates until the end of the line or a closing parenthesis, which is left unconsumed.
+fn evaluate(line: &[u8], index: &mut usize, advanced: bool) -> u64 {
+    let mut total = value(line, index, advanced);
+
+    while let Some(&operator) = line.get(*index) {
+        if operator == b')' {
+            break;
+        }
+        *index += 1;
+
+        if operator == b'+' {
+            total += value(line, index, advanced);
+        } else if advanced {
+            total *= evaluate(line, index, advanced);
+        } else {
+            total *= value(line, index, advanced);
+        }
+    }
+
+    total
+}
+
+fn value(line: &[u8], index: &mut usize, advanced: bool) -> u64 {
+    let b = line[*index];
+    *index += 1;
+
+    if b == b'(' {
+        let result = evaluate(line, index, advanced);
+        *index += 1;
+        result
+    } else {
+        (b - b'0') as u64
+    }
+}
@@ -0,0 +1,78 @@
+//! # Monster Messages
+//!
+//! Matching a rule returns every position where a match starting at a given position could
+//! end. Keeping all the possibilities rather than only the first handles rules where an earlier
+//! alternative consumes too much, and also handles the looping rules of the second part for
+//! free. Every rule consumes at least one character before recursing, so the loops always
+//! terminate.
+use crate::util::parse::*;
+
+#[derive(Clone)]
+pub enum Rule {
+    Letter(u8),
+    Sequences(Vec<Vec<usize>>),
+}
+
+pub struct Input<'a> {
+    rules: Vec<Rule>,
+    messages: Vec<&'a [u8]>,
+}
+
+pub fn parse(input: &str) -> Input<'_> {
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+    let mut rules = Vec::new();
+
+    for line in prefix.lines() {
+        let (index, rule) = line.split_once(": ").unwrap();
+        let index: usize = index.unsigned();
+        let rule = match rule.strip_prefix('"') {
+            Some(letter) => Rule::Letter(letter.as_bytes()[0]),
+            None => Rule::Sequences(
+                rule.split(" | ").map(|sequence| sequence.iter_unsigned().collect()).collect(),
+            ),
+        };
+
+        if rules.len() <= index {
+            rules.resize(index + 1, Rule::Sequences(Vec::new()));
+        }
+        rules[index] = rule;
+    }
+
+    Input { rules, messages: suffix.lines().map(str::as_bytes).collect() }
+}
+
+pub fn part1(input: &Input<'_>) -> usize {
+    count(&input.rules, &input.messages)
+}
+
+pub fn part2(input: &Input<'_>) -> usize {
+    let mut rules = input.rules.clone();
+    rules[8] = Rule::Sequences(vec![vec![42], vec![42, 8]]);
+    rules[11] = Rule::Sequences(vec![vec![42, 31], vec![42, 11, 31]]);
+    count(&rules, &input.messages)
+}
+
+fn count(rules: &[Rule], messages: &[&[u8]]) -> usize {
+    messages.iter().filter(|message| matches(rules, 0, message, 0).contains(&message.len())).count()
+}
+
+/// Every position where a match of `rule` starting at `start` could end.
+fn matches(rules: &[Rule], rule: usize, message: &[u8], start: usize) -> Vec<usize> {
+    match &rules[rule] {
+        Rule::Letter(letter) => {
+            if message.get(start) == Some(letter) {
+                vec![start + 1]
+            } else {
+                vec![]
+            }
+        }
+        Rule::Sequences(sequences) => sequences
+            .iter()
+            .flat_map(|sequence| {
+                sequence.iter().fold(vec![start], |ends, &next| {
+                    ends.into_iter().flat_map(|end| matches(rules, next, message, end)).collect()
+                })
+            })
+            .collect(),
+    }
+}
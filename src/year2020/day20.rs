@@ -0,0 +1,138 @@
+//! # Jurassic Jigsaw
+//!
+//! Each edge of the puzzle matches at most one other edge, so edges are counted in a map keyed
+//! by the edge or its reverse, whichever sorts first, to ignore flipping. The corners are the
+//! only tiles with two unmatched edges, which is enough for the first part.
+//!
+//! For the second part, a corner is oriented so that its unmatched edges face up and left, then
+//! the rest of the image is placed left to right and top to bottom. Each position has exactly
+//! one remaining tile and orientation whose edges line up with the neighbors above and to the
+//! left. Once assembled with the borders removed, the sea monsters appear in exactly one of the
+//! eight orientations of the image.
+use crate::util::grid::*;
+use crate::util::hash::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+const MONSTER: [&str; 3] = ["                  # ", "#    ##    ##    ###", " #  #  #  #  #  #   "];
+
+pub struct Tile {
+    id: u64,
+    grid: Grid<u8>,
+}
+
+pub fn parse(input: &str) -> Vec<Tile> {
+    input
+        .split("\n\n")
+        .map(|block| {
+            let (header, grid) = block.split_once('\n').unwrap();
+            Tile { id: header.unsigned(), grid: Grid::parse(grid) }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Tile]) -> u64 {
+    let counts = edge_counts(input);
+    input.iter().filter(|tile| unmatched(&counts, &tile.grid) == 2).map(|tile| tile.id).product()
+}
+
+pub fn part2(input: &[Tile]) -> usize {
+    let counts = edge_counts(input);
+    let size = input.len().isqrt();
+    let tiles: Vec<Vec<_>> = input.iter().map(|tile| tile.grid.orientations().collect()).collect();
+    let mut used = vec![false; input.len()];
+    let mut placed: Vec<Grid<u8>> = Vec::with_capacity(input.len());
+
+    // Orient the first corner so that its unmatched edges are on the top and left.
+    let corner = input.iter().position(|tile| unmatched(&counts, &tile.grid) == 2).unwrap();
+    let first = tiles[corner].iter().find(|grid| {
+        counts[&canonical(row(grid, 0))] == 1 && counts[&canonical(column(grid, 0))] == 1
+    });
+    placed.push(first.unwrap().clone());
+    used[corner] = true;
+
+    for index in 1..input.len() {
+        let fits = |grid: &Grid<u8>| {
+            let last = grid.width - 1;
+            (index % size == 0 || column(grid, 0) == column(&placed[index - 1], last))
+                && (index < size || row(grid, 0) == row(&placed[index - size], last))
+        };
+        let (tile, grid) = (0..input.len())
+            .filter(|&tile| !used[tile])
+            .find_map(|tile| {
+                tiles[tile].iter().find(|grid| fits(grid)).map(|grid| (tile, grid.clone()))
+            })
+            .unwrap();
+
+        placed.push(grid);
+        used[tile] = true;
+    }
+
+    // Remove the borders and join the tiles.
+    let inner = input[0].grid.width - 2;
+    let mut image = Grid::new(size as i32 * inner, size as i32 * inner, b'.');
+
+    for (index, grid) in placed.iter().enumerate() {
+        let offset = Point::new((index % size) as i32 * inner, (index / size) as i32 * inner);
+        image.paste(offset, &grid.view(Point::new(1, 1), inner, inner).to_grid());
+    }
+
+    let monster: Vec<_> = MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.bytes()
+                .enumerate()
+                .filter(|&(_, b)| b == b'#')
+                .map(move |(x, _)| Point::new(x as i32, y as i32))
+        })
+        .collect();
+    let rough = image.bytes.iter().filter(|&&b| b == b'#').count();
+    let (width, height) = (MONSTER[0].len() as i32, MONSTER.len() as i32);
+
+    image
+        .orientations()
+        .map(|image| {
+            let corners =
+                image.view(Point::new(0, 0), image.width - width + 1, image.height - height + 1);
+            corners
+                .iter()
+                .filter(|&(corner, _)| monster.iter().all(|&offset| image[corner + offset] == b'#'))
+                .count()
+        })
+        .find(|&monsters| monsters > 0)
+        .map(|monsters| rough - monsters * monster.len())
+        .unwrap()
+}
+
+fn edge_counts(input: &[Tile]) -> FastMap<Vec<u8>, usize> {
+    let mut counts = FastMap::new();
+    for tile in input {
+        for edge in edges(&tile.grid) {
+            *counts.entry(canonical(edge)).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+fn unmatched(counts: &FastMap<Vec<u8>, usize>, grid: &Grid<u8>) -> usize {
+    edges(grid).into_iter().filter(|edge| counts[&canonical(edge.clone())] == 1).count()
+}
+
+fn edges(grid: &Grid<u8>) -> [Vec<u8>; 4] {
+    let last = grid.width - 1;
+    [row(grid, 0), row(grid, last), column(grid, 0), column(grid, last)]
+}
+
+fn canonical(edge: Vec<u8>) -> Vec<u8> {
+    let reverse: Vec<_> = edge.iter().rev().copied().collect();
+    edge.min(reverse)
+}
+
+fn row(grid: &Grid<u8>, y: i32) -> Vec<u8> {
+    grid.row(y).to_vec()
+}
+
+fn column(grid: &Grid<u8>, x: i32) -> Vec<u8> {
+    grid.column(x).copied().collect()
+}
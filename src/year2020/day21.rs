@@ -0,0 +1,55 @@
+//! # Allergen Assessment
+//!
+//! The ingredient containing an allergen must appear in every food listing that allergen, so
+//! the candidates for each allergen are the intersection of those foods' ingredients. As with
+//! other matching puzzles, some allergen always has exactly one candidate left, which is then
+//! removed from every other allergen until all are known.
+use crate::util::hash::*;
+
+pub struct Input<'a> {
+    foods: Vec<Vec<&'a str>>,
+    dangerous: Vec<(&'a str, &'a str)>,
+}
+
+pub fn parse(input: &str) -> Input<'_> {
+    let mut foods = Vec::new();
+    let mut candidates: FastMap<&str, FastSet<&str>> = FastMap::new();
+
+    for line in input.lines() {
+        let (ingredients, allergens) = line.split_once(" (contains ").unwrap();
+        let ingredients: Vec<_> = ingredients.split(' ').collect();
+
+        for allergen in allergens.trim_end_matches(')').split(", ") {
+            let food: FastSet<_> = ingredients.iter().copied().collect();
+            candidates
+                .entry(allergen)
+                .and_modify(|set| set.retain(|i| food.contains(i)))
+                .or_insert(food);
+        }
+
+        foods.push(ingredients);
+    }
+
+    let mut dangerous = Vec::new();
+
+    while let Some((&allergen, set)) = candidates.iter().find(|(_, set)| set.len() == 1) {
+        let ingredient = *set.iter().next().unwrap();
+        dangerous.push((allergen, ingredient));
+        candidates.remove(allergen);
+        candidates.values_mut().for_each(|set| {
+            set.remove(ingredient);
+        });
+    }
+
+    dangerous.sort_unstable();
+    Input { foods, dangerous }
+}
+
+pub fn part1(input: &Input<'_>) -> usize {
+    let allergens: FastSet<_> = input.dangerous.iter().map(|&(_, ingredient)| ingredient).collect();
+    input.foods.iter().flatten().filter(|ingredient| !allergens.contains(*ingredient)).count()
+}
+
+pub fn part2(input: &Input<'_>) -> String {
+    input.dangerous.iter().map(|&(_, ingredient)| ingredient).collect::<Vec<_>>().join(",")
+}
@@ -0,0 +1,79 @@
+//! # Crab Combat
+//!
+//! Plays both games directly with each deck in a [`VecDeque`]. Recursive Combat stores every
+//! pair of decks seen in the current game to detect the infinite game rule.
+//!
+//! One well known shortcut is that in a sub-game, if player 1 holds the highest card, they
+//! must eventually win, as that card can never be lost and is too large to trigger a deeper
+//! sub-game. The sub-game can then be skipped entirely.
+use crate::util::hash::*;
+use crate::util::parse::*;
+use std::collections::VecDeque;
+
+type Deck = VecDeque<u8>;
+
+pub fn parse(input: &str) -> (Deck, Deck) {
+    let (first, second) = input.split_once("\n\n").unwrap();
+    let deck = |block: &str| block.lines().skip(1).map(|line| line.unsigned()).collect();
+    (deck(first), deck(second))
+}
+
+pub fn part1(input: &(Deck, Deck)) -> usize {
+    let (mut first, mut second) = input.clone();
+
+    while !first.is_empty() && !second.is_empty() {
+        let (a, b) = (first.pop_front().unwrap(), second.pop_front().unwrap());
+        if a > b {
+            first.extend([a, b]);
+        } else {
+            second.extend([b, a]);
+        }
+    }
+
+    score(if first.is_empty() { &second } else { &first })
+}
+
+pub fn part2(input: &(Deck, Deck)) -> usize {
+    let (mut first, mut second) = input.clone();
+    if recursive(&mut first, &mut second, false) {
+        score(&first)
+    } else {
+        score(&second)
+    }
+}
+
+/// Returns `true` if player 1 wins.
+fn recursive(first: &mut Deck, second: &mut Deck, sub_game: bool) -> bool {
+    if sub_game && first.iter().max() > second.iter().max() {
+        return true;
+    }
+
+    let mut seen = FastSet::new();
+
+    while !first.is_empty() && !second.is_empty() {
+        if !seen.insert((first.clone(), second.clone())) {
+            return true;
+        }
+
+        let (a, b) = (first.pop_front().unwrap(), second.pop_front().unwrap());
+        let first_wins = if first.len() >= a as usize && second.len() >= b as usize {
+            let mut first = first.iter().take(a as usize).copied().collect();
+            let mut second = second.iter().take(b as usize).copied().collect();
+            recursive(&mut first, &mut second, true)
+        } else {
+            a > b
+        };
+
+        if first_wins {
+            first.extend([a, b]);
+        } else {
+            second.extend([b, a]);
+        }
+    }
+
+    second.is_empty()
+}
+
+fn score(deck: &Deck) -> usize {
+    deck.iter().rev().enumerate().map(|(i, &card)| (i + 1) * card as usize).sum()
+}
@@ -0,0 +1,61 @@
+//! # Crab Cups
+//!
+//! The circle is stored as a linked list in a flat array, where `next[cup]` is the label of
+//! the cup clockwise from `cup`. Moving three cups and finding the destination are then
+//! constant time, which is needed for ten million moves of a million cups in the second part.
+pub fn parse(input: &str) -> Vec<usize> {
+    input.trim().bytes().map(|b| (b - b'0') as usize).collect()
+}
+
+pub fn part1(input: &[usize]) -> String {
+    play(input, 100)
+}
+
+pub fn part2(input: &[usize]) -> usize {
+    let next = simulate(input, 1_000_000, 10_000_000);
+    next[1] * next[next[1]]
+}
+
+/// Labels of the cups after cup 1 following `moves` moves.
+pub fn play(input: &[usize], moves: usize) -> String {
+    let next = simulate(input, input.len(), moves);
+    let mut cup = next[1];
+    let mut result = String::new();
+
+    while cup != 1 {
+        result.push((b'0' + cup as u8) as char);
+        cup = next[cup];
+    }
+
+    result
+}
+
+fn simulate(input: &[usize], size: usize, moves: usize) -> Vec<usize> {
+    let mut next = vec![0; size + 1];
+    let labels: Vec<_> = input.iter().copied().chain(input.len() + 1..=size).collect();
+
+    for window in labels.windows(2) {
+        next[window[0]] = window[1];
+    }
+    next[labels[size - 1]] = labels[0];
+
+    let mut current = labels[0];
+
+    for _ in 0..moves {
+        let a = next[current];
+        let b = next[a];
+        let c = next[b];
+
+        let mut destination = if current > 1 { current - 1 } else { size };
+        while destination == a || destination == b || destination == c {
+            destination = if destination > 1 { destination - 1 } else { size };
+        }
+
+        next[current] = next[c];
+        next[c] = next[destination];
+        next[destination] = a;
+        current = next[current];
+    }
+
+    next
+}
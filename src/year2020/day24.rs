@@ -0,0 +1,73 @@
+//! # Lobby Layout
+//!
+//! Hexagonal tiles are located with [axial coordinates], storing each tile as a [`Point`]
+//! where east and west change `x`, and the four diagonal directions change `y`, with northeast
+//! and southwest also changing `x`.
+//!
+//! For the second part, each day every black tile adds one to the count of each of its six
+//! neighbors. Only tiles with a count can turn or stay black, so there's no need to search the
+//! unbounded floor.
+//!
+//! [axial coordinates]: https://www.redblobgames.com/grids/hexagons/#coordinates-axial
+use crate::util::hash::*;
+use crate::util::point::*;
+
+const HEX: [Point; 6] = [
+    Point::new(1, 0),
+    Point::new(-1, 0),
+    Point::new(1, -1),
+    Point::new(0, -1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+];
+
+pub fn parse(input: &str) -> FastSet<Point> {
+    let mut black = FastSet::new();
+
+    for line in input.lines() {
+        let mut bytes = line.bytes();
+        let mut tile = ORIGIN;
+
+        while let Some(b) = bytes.next() {
+            tile += match (b, if matches!(b, b'n' | b's') { bytes.next() } else { None }) {
+                (b'e', _) => HEX[0],
+                (b'w', _) => HEX[1],
+                (b'n', Some(b'e')) => HEX[2],
+                (b'n', _) => HEX[3],
+                (b's', Some(b'e')) => HEX[4],
+                _ => HEX[5],
+            };
+        }
+
+        if !black.insert(tile) {
+            black.remove(&tile);
+        }
+    }
+
+    black
+}
+
+pub fn part1(input: &FastSet<Point>) -> usize {
+    input.len()
+}
+
+pub fn part2(input: &FastSet<Point>) -> usize {
+    let mut black = input.clone();
+
+    for _ in 0..100 {
+        let mut count = FastMap::with_capacity(black.len() * 6);
+        for &tile in &black {
+            for offset in HEX {
+                *count.entry(tile + offset).or_insert(0) += 1;
+            }
+        }
+
+        black = count
+            .into_iter()
+            .filter(|&(tile, n)| n == 2 || (n == 1 && black.contains(&tile)))
+            .map(|(tile, _)| tile)
+            .collect();
+    }
+
+    black.len()
+}
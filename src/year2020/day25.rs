@@ -0,0 +1,31 @@
+//! # Combo Breaker
+//!
+//! Finds the card's loop size by repeatedly multiplying by the subject number 7 until the
+//! card's public key appears, then transforms the door's public key with [modular
+//! exponentiation].
+//!
+//! This puzzle only has one part.
+//!
+//! [modular exponentiation]: crate::util::math
+use crate::util::math::*;
+use crate::util::parse::*;
+
+const MODULUS: u64 = 20201227;
+
+pub fn parse(input: &str) -> [u64; 2] {
+    let mut keys = input.iter_unsigned();
+    [keys.next().unwrap(), keys.next().unwrap()]
+}
+
+pub fn part1(input: &[u64; 2]) -> u64 {
+    let [card, door] = *input;
+    let mut value = 1;
+    let mut loop_size = 0;
+
+    while value != card {
+        value = (value * 7) % MODULUS;
+        loop_size += 1;
+    }
+
+    door.mod_pow(loop_size, MODULUS)
+}
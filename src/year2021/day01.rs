@@ -0,0 +1,21 @@
+//! # Sonar Sweep
+//!
+//! The sums of two neighboring three measurement windows share two measurements, so comparing
+//! the sums is the same as comparing the measurements three apart.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<u32> {
+    input.iter_unsigned().collect()
+}
+
+pub fn part1(input: &[u32]) -> usize {
+    increases(input, 1)
+}
+
+pub fn part2(input: &[u32]) -> usize {
+    increases(input, 3)
+}
+
+fn increases(input: &[u32], gap: usize) -> usize {
+    input.iter().zip(&input[gap..]).filter(|(a, b)| a < b).count()
+}
@@ -0,0 +1,32 @@
+//! # Dive!
+//!
+//! Each command is parsed into a change of horizontal position and a change of depth, or aim
+//! in the second part.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<(i32, i32)> {
+    input
+        .lines()
+        .map(|line| {
+            let (command, amount) = line.split_once(' ').unwrap();
+            let amount = amount.signed();
+            match command {
+                "forward" => (amount, 0),
+                "down" => (0, amount),
+                "up" => (0, -amount),
+                _ => panic!("Unknown command {line}"),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[(i32, i32)]) -> i32 {
+    let (position, depth) = input.iter().fold((0, 0), |(p, d), &(dp, dd)| (p + dp, d + dd));
+    position * depth
+}
+
+pub fn part2(input: &[(i32, i32)]) -> i32 {
+    let (position, depth, _) =
+        input.iter().fold((0, 0, 0), |(p, d, aim), &(dp, da)| (p + dp, d + aim * dp, aim + da));
+    position * depth
+}
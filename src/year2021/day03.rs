@@ -0,0 +1,45 @@
+//! # Binary Diagnostic
+//!
+//! The epsilon rate is the gamma rate with every bit inverted, so only one needs computing.
+//! The second part filters a shrinking list one bit at a time, keeping the numbers whose bit
+//! matches the most or least common value among the numbers that remain.
+pub struct Input {
+    width: usize,
+    numbers: Vec<u32>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let width = input.lines().next().unwrap().len();
+    let numbers = input.lines().map(|line| u32::from_str_radix(line, 2).unwrap()).collect();
+    Input { width, numbers }
+}
+
+pub fn part1(input: &Input) -> u32 {
+    let gamma = (0..input.width)
+        .filter(|&bit| ones(&input.numbers, bit) * 2 >= input.numbers.len())
+        .fold(0, |acc, bit| acc | (1 << bit));
+    let epsilon = !gamma & ((1 << input.width) - 1);
+    gamma * epsilon
+}
+
+pub fn part2(input: &Input) -> u32 {
+    rating(input, true) * rating(input, false)
+}
+
+fn ones(numbers: &[u32], bit: usize) -> usize {
+    numbers.iter().filter(|&&n| n & (1 << bit) != 0).count()
+}
+
+fn rating(input: &Input, most: bool) -> u32 {
+    let mut numbers = input.numbers.clone();
+
+    for bit in (0..input.width).rev() {
+        if numbers.len() == 1 {
+            break;
+        }
+        let keep_ones = (ones(&numbers, bit) * 2 >= numbers.len()) == most;
+        numbers.retain(|&n| (n & (1 << bit) != 0) == keep_ones);
+    }
+
+    numbers[0]
+}
@@ -0,0 +1,47 @@
+//! # Giant Squid
+//!
+//! Each number is replaced by the turn it's drawn. A line of a board is complete on the turn its
+//! last number is drawn, so each board wins on the earliest of its ten lines. Both parts then
+//! score the board with the earliest or latest winning turn, without playing out the game.
+use crate::util::parse::*;
+
+pub struct Input {
+    draws: Vec<u32>,
+    /// Winning turn and score of each board.
+    boards: Vec<(usize, u32)>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let (draws, boards) = input.split_once("\n\n").unwrap();
+    let draws: Vec<u32> = draws.iter_unsigned().collect();
+
+    let mut turn = vec![usize::MAX; 100];
+    draws.iter().enumerate().for_each(|(i, &n)| turn[n as usize] = i);
+
+    let boards = boards
+        .split("\n\n")
+        .map(|board| {
+            let numbers: Vec<u32> = board.iter_unsigned().collect();
+            let turns: Vec<_> = numbers.iter().map(|&n| turn[n as usize]).collect();
+
+            let row = |r: usize| (0..5).map(|c| turns[5 * r + c]).max().unwrap();
+            let column = |c: usize| (0..5).map(|r| turns[5 * r + c]).max().unwrap();
+            let win = (0..5).map(|i| row(i).min(column(i))).min().unwrap();
+
+            let unmarked: u32 =
+                numbers.iter().zip(&turns).filter(|&(_, &t)| t > win).map(|(n, _)| n).sum();
+            (win, unmarked * draws[win])
+        })
+        .collect();
+
+    Input { draws, boards }
+}
+
+pub fn part1(input: &Input) -> u32 {
+    input.boards.iter().min_by_key(|board| board.0).unwrap().1
+}
+
+pub fn part2(input: &Input) -> u32 {
+    debug_assert!(input.boards.iter().all(|board| board.0 < input.draws.len()));
+    input.boards.iter().max_by_key(|board| board.0).unwrap().1
+}
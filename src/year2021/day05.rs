@@ -0,0 +1,47 @@
+//! # Hydrothermal Venture
+//!
+//! Vents are drawn onto a dense 1000 by 1000 grid of counts by stepping one unit at a time in
+//! the direction given by [`Point::signum`], which handles horizontal, vertical and diagonal
+//! lines alike.
+use crate::util::grid::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Vec<[Point; 2]> {
+    // Coordinates are never negative, and the `-` of each arrow would otherwise parse as a sign.
+    input
+        .iter_unsigned::<u32>()
+        .map(|n| n as i32)
+        .chunk::<4>()
+        .map(|[x1, y1, x2, y2]| [Point::new(x1, y1), Point::new(x2, y2)])
+        .collect()
+}
+
+pub fn part1(input: &[[Point; 2]]) -> usize {
+    overlaps(input, false)
+}
+
+pub fn part2(input: &[[Point; 2]]) -> usize {
+    overlaps(input, true)
+}
+
+fn overlaps(input: &[[Point; 2]], diagonal: bool) -> usize {
+    let mut grid = Grid::new(1000, 1000, 0_u8);
+
+    for &[start, end] in input {
+        if !diagonal && start.x != end.x && start.y != end.y {
+            continue;
+        }
+
+        let step = end.signum(start);
+        let mut point = start;
+        grid[point] += 1;
+        while point != end {
+            point += step;
+            grid[point] += 1;
+        }
+    }
+
+    grid.bytes.iter().filter(|&&n| n > 1).count()
+}
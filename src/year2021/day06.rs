@@ -0,0 +1,29 @@
+//! # Lanternfish
+//!
+//! Fish with the same timer behave identically, so only the number of fish with each timer
+//! value is tracked. Each day the counts rotate left by one, with the fish at zero both
+//! resetting to six and spawning new fish at eight.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> [u64; 9] {
+    let mut counts = [0; 9];
+    input.iter_unsigned::<usize>().for_each(|timer| counts[timer] += 1);
+    counts
+}
+
+pub fn part1(input: &[u64; 9]) -> u64 {
+    simulate(input, 80)
+}
+
+pub fn part2(input: &[u64; 9]) -> u64 {
+    simulate(input, 256)
+}
+
+fn simulate(input: &[u64; 9], days: usize) -> u64 {
+    let mut counts = *input;
+    for _ in 0..days {
+        counts.rotate_left(1);
+        counts[6] += counts[8];
+    }
+    counts.iter().sum()
+}
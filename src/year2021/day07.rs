@@ -0,0 +1,33 @@
+//! # The Treachery of Whales
+//!
+//! With linear fuel cost the best position is the [median]. With triangular cost the best
+//! position is within a half of the mean, so only the floor and ceiling of the mean need
+//! checking.
+//!
+//! [median]: https://en.wikipedia.org/wiki/Median#Optimality_property
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<i32> {
+    let mut crabs: Vec<_> = input.iter_signed().collect();
+    crabs.sort_unstable();
+    crabs
+}
+
+pub fn part1(input: &[i32]) -> i32 {
+    let median = input[input.len() / 2];
+    input.iter().map(|&crab| (crab - median).abs()).sum()
+}
+
+pub fn part2(input: &[i32]) -> i32 {
+    let mean = input.iter().sum::<i32>() / input.len() as i32;
+    let fuel = |target: i32| {
+        input
+            .iter()
+            .map(|&crab| {
+                let n = (crab - target).abs();
+                n * (n + 1) / 2
+            })
+            .sum::<i32>()
+    };
+    fuel(mean).min(fuel(mean + 1))
+}
@@ -0,0 +1,52 @@
+//! # Seven Segment Search
+//!
+//! Counting how often each segment appears across the ten unique patterns gives the same
+//! answer for every display regardless of wiring. Adding up these frequencies for the segments
+//! of each digit then gives a score that's different for every digit, so each output digit can
+//! be decoded with a lookup rather than deducing the wiring.
+pub fn parse(input: &str) -> Vec<u32> {
+    input
+        .lines()
+        .map(|line| {
+            let (patterns, output) = line.split_once(" | ").unwrap();
+            let mut frequency = [0; 7];
+            patterns
+                .bytes()
+                .filter(u8::is_ascii_lowercase)
+                .for_each(|b| frequency[(b - b'a') as usize] += 1);
+
+            output.split(' ').fold(0, |acc, digit| {
+                let score: u32 = digit.bytes().map(|b| frequency[(b - b'a') as usize]).sum();
+                10 * acc + decode(score)
+            })
+        })
+        .collect()
+}
+
+pub fn part1(input: &[u32]) -> usize {
+    input
+        .iter()
+        .flat_map(|&n| [n / 1000, (n / 100) % 10, (n / 10) % 10, n % 10])
+        .filter(|digit| matches!(digit, 1 | 4 | 7 | 8))
+        .count()
+}
+
+pub fn part2(input: &[u32]) -> u32 {
+    input.iter().sum()
+}
+
+fn decode(score: u32) -> u32 {
+    match score {
+        42 => 0,
+        17 => 1,
+        34 => 2,
+        39 => 3,
+        30 => 4,
+        37 => 5,
+        41 => 6,
+        25 => 7,
+        49 => 8,
+        45 => 9,
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,43 @@
+//! # Smoke Basin
+//!
+//! Every location except height 9 belongs to exactly one basin, so the basins are the
+//! connected regions of lower locations. Labeling the [connected components] and counting
+//! the locations with each label gives every basin size at once.
+//!
+//! [connected components]: https://en.wikipedia.org/wiki/Connected-component_labeling
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(input: &Grid<u8>) -> u32 {
+    let mut risk = 0;
+
+    for y in 0..input.height {
+        for x in 0..input.width {
+            let point = Point::new(x, y);
+            let height = input[point];
+            if input.orthogonal(point).all(|next| input[next] > height) {
+                risk += (height - b'0' + 1) as u32;
+            }
+        }
+    }
+
+    risk
+}
+
+pub fn part2(input: &Grid<u8>) -> usize {
+    let (labels, count) = input.components(|from, to| input[from] != b'9' && input[to] != b'9');
+    let mut basins = vec![0; count as usize];
+
+    for (label, &height) in labels.bytes.iter().zip(&input.bytes) {
+        if height != b'9' {
+            basins[*label as usize] += 1;
+        }
+    }
+
+    basins.sort_unstable();
+    basins.iter().rev().take(3).product()
+}
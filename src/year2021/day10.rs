@@ -0,0 +1,50 @@
+//! # Syntax Scoring
+//!
+//! Each line is checked with a stack of expected closing characters. A mismatch makes the line
+//! corrupted, otherwise the characters left on the stack are exactly the completion string.
+pub fn parse(input: &str) -> Vec<Result<u64, u64>> {
+    input
+        .lines()
+        .map(|line| {
+            let mut stack = Vec::new();
+
+            for b in line.bytes() {
+                match b {
+                    b'(' => stack.push(b')'),
+                    b'[' => stack.push(b']'),
+                    b'{' => stack.push(b'}'),
+                    b'<' => stack.push(b'>'),
+                    _ if stack.pop() != Some(b) => {
+                        return Err(match b {
+                            b')' => 3,
+                            b']' => 57,
+                            b'}' => 1197,
+                            _ => 25137,
+                        });
+                    }
+                    _ => (),
+                }
+            }
+
+            let score = |b| match b {
+                b')' => 1,
+                b']' => 2,
+                b'}' => 3,
+                _ => 4,
+            };
+            Ok(stack.iter().rev().fold(0, |acc, &b| 5 * acc + score(b)))
+        })
+        .collect()
+}
+
+/// Total syntax error score of the corrupted lines.
+pub fn part1(input: &[Result<u64, u64>]) -> u64 {
+    input.iter().filter_map(|line| line.err()).sum()
+}
+
+/// Middle completion score of the incomplete lines.
+pub fn part2(input: &[Result<u64, u64>]) -> u64 {
+    let mut scores: Vec<_> = input.iter().filter_map(|line| line.ok()).collect();
+    scores.sort_unstable();
+    scores[scores.len() / 2]
+}
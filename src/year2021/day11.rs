@@ -0,0 +1,52 @@
+//! # Dumbo Octopus
+//!
+//! Each step increases every energy level, then propagates flashes using a stack of octopuses
+//! that have just reached 10. An octopus flashes at most once per step, so its energy is reset
+//! to zero only after all flashes have finished and anything above 9 flashed.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse_with(input, |b| b - b'0')
+}
+
+pub fn part1(input: &Grid<u8>) -> usize {
+    let mut grid = input.clone();
+    (0..100).map(|_| step(&mut grid)).sum()
+}
+
+pub fn part2(input: &Grid<u8>) -> usize {
+    let mut grid = input.clone();
+    let size = grid.bytes.len();
+    (1..).find(|_| step(&mut grid) == size).unwrap()
+}
+
+/// Returns the number of flashes.
+fn step(grid: &mut Grid<u8>) -> usize {
+    let mut todo = Vec::new();
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            grid[point] += 1;
+            if grid[point] == 10 {
+                todo.push(point);
+            }
+        }
+    }
+
+    let mut flashes = 0;
+
+    while let Some(point) = todo.pop() {
+        flashes += 1;
+        for next in grid.diagonal(point) {
+            grid[next] += 1;
+            if grid[next] == 10 {
+                todo.push(next);
+            }
+        }
+    }
+
+    grid.bytes.iter_mut().filter(|b| **b > 9).for_each(|b| *b = 0);
+    flashes
+}
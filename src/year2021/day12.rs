@@ -0,0 +1,79 @@
+//! # Passage Pathing
+//!
+//! Caves are numbered with the small caves first, so that the set of visited small caves fits
+//! in a bitmask. The number of paths from a cave only depends on the cave, the visited small
+//! caves and whether the single repeat visit has been used yet, so a depth first search is
+//! memoized on that state.
+use crate::util::hash::*;
+
+const START: usize = 0;
+const END: usize = 1;
+
+pub struct Input {
+    small: usize,
+    edges: Vec<Vec<usize>>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let pairs: Vec<_> = input.lines().map(|line| line.split_once('-').unwrap()).collect();
+
+    // Start and end first, then the remaining small caves, then the large caves.
+    let mut names: Vec<_> = pairs.iter().flat_map(|&(a, b)| [a, b]).collect();
+    names.sort_unstable_by_key(|&name| {
+        (name != "start", name != "end", name.as_bytes()[0].is_ascii_uppercase(), name)
+    });
+    names.dedup();
+
+    let index: FastMap<_, _> = names.iter().enumerate().map(|(i, &name)| (name, i)).collect();
+    let small = names.iter().filter(|name| name.as_bytes()[0].is_ascii_lowercase()).count();
+    let mut edges = vec![Vec::new(); names.len()];
+
+    for (a, b) in pairs {
+        let (a, b) = (index[a], index[b]);
+        edges[a].push(b);
+        edges[b].push(a);
+    }
+
+    Input { small, edges }
+}
+
+pub fn part1(input: &Input) -> u64 {
+    paths(input, &mut FastMap::new(), START, 1 << START, true)
+}
+
+pub fn part2(input: &Input) -> u64 {
+    paths(input, &mut FastMap::new(), START, 1 << START, false)
+}
+
+fn paths(
+    input: &Input,
+    cache: &mut FastMap<(usize, u32, bool), u64>,
+    cave: usize,
+    visited: u32,
+    twice: bool,
+) -> u64 {
+    if cave == END {
+        return 1;
+    }
+    if let Some(&result) = cache.get(&(cave, visited, twice)) {
+        return result;
+    }
+
+    let mut result = 0;
+
+    for &next in &input.edges[cave] {
+        if next == START {
+            continue;
+        }
+        if next >= input.small {
+            result += paths(input, cache, next, visited, twice);
+        } else if visited & (1 << next) == 0 {
+            result += paths(input, cache, next, visited | (1 << next), twice);
+        } else if !twice {
+            result += paths(input, cache, next, visited, true);
+        }
+    }
+
+    cache.insert((cave, visited, twice), result);
+    result
+}
@@ -0,0 +1,51 @@
+//! # Transparent Origami
+//!
+//! Folding along a line reflects every dot beyond the line back over it. Dots that land on the
+//! same position merge, so the dots are kept in a set after each fold.
+use crate::util::hash::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub struct Input {
+    dots: Vec<(u32, u32)>,
+    folds: Vec<(bool, u32)>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let (dots, folds) = input.split_once("\n\n").unwrap();
+    let dots = dots.iter_unsigned().chunk::<2>().map(|[x, y]| (x, y)).collect();
+    let folds = folds.lines().map(|line| (line.contains("x="), line.unsigned())).collect();
+    Input { dots, folds }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let fold = input.folds[0];
+    input.dots.iter().map(|&dot| reflect(dot, fold)).collect::<FastSet<_>>().len()
+}
+
+pub fn part2(input: &Input) -> String {
+    let dots: FastSet<_> = input
+        .dots
+        .iter()
+        .map(|&dot| input.folds.iter().fold(dot, |dot, &fold| reflect(dot, fold)))
+        .collect();
+    let width = dots.iter().map(|&(x, _)| x).max().unwrap();
+    let height = dots.iter().map(|&(_, y)| y).max().unwrap();
+
+    let mut result = String::new();
+    for y in 0..=height {
+        result.push('\n');
+        for x in 0..=width {
+            result.push(if dots.contains(&(x, y)) { '#' } else { '.' });
+        }
+    }
+    result
+}
+
+fn reflect((x, y): (u32, u32), (vertical, line): (bool, u32)) -> (u32, u32) {
+    if vertical {
+        (if x > line { 2 * line - x } else { x }, y)
+    } else {
+        (x, if y > line { 2 * line - y } else { y })
+    }
+}
@@ -0,0 +1,60 @@
+//! # Extended Polymerization
+//!
+//! The polymer doubles in length every step, but only the count of each pair of adjacent
+//! elements matters. Each insertion rule turns every copy of a pair into two new pairs. Each
+//! element is then the first of exactly one pair, except the final element of the template
+//! which never changes.
+use crate::util::hash::*;
+
+pub struct Input {
+    last: u8,
+    pairs: FastMap<[u8; 2], u64>,
+    rules: FastMap<[u8; 2], u8>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let (template, rules) = input.split_once("\n\n").unwrap();
+    let template = template.as_bytes();
+
+    let mut pairs = FastMap::new();
+    template.windows(2).for_each(|w| *pairs.entry([w[0], w[1]]).or_insert(0) += 1);
+
+    let rules = rules
+        .lines()
+        .map(|line| {
+            let b = line.as_bytes();
+            ([b[0], b[1]], b[6])
+        })
+        .collect();
+
+    Input { last: template[template.len() - 1], pairs, rules }
+}
+
+pub fn part1(input: &Input) -> u64 {
+    polymerize(input, 10)
+}
+
+pub fn part2(input: &Input) -> u64 {
+    polymerize(input, 40)
+}
+
+fn polymerize(input: &Input, steps: usize) -> u64 {
+    let mut pairs = input.pairs.clone();
+
+    for _ in 0..steps {
+        let mut next = FastMap::with_capacity(pairs.len());
+        for (pair @ [a, b], count) in pairs {
+            let c = input.rules[&pair];
+            *next.entry([a, c]).or_insert(0) += count;
+            *next.entry([c, b]).or_insert(0) += count;
+        }
+        pairs = next;
+    }
+
+    let mut elements = [0; 26];
+    elements[(input.last - b'A') as usize] += 1;
+    pairs.iter().for_each(|(&[a, _], &count)| elements[(a - b'A') as usize] += count);
+
+    let present = elements.iter().filter(|&&count| count > 0);
+    present.clone().max().unwrap() - present.min().unwrap()
+}
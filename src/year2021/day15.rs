@@ -0,0 +1,57 @@
+//! # Chiton
+//!
+//! [Dijkstra's algorithm] from the top left to the bottom right corner. The full map of the
+//! second part is never built, instead the risk of any location is computed from the tile it
+//! falls in, wrapping values above 9 back around to 1.
+//!
+//! [Dijkstra's algorithm]: https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+use crate::util::grid::*;
+use crate::util::heap::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse_with(input, |b| b - b'0')
+}
+
+pub fn part1(input: &Grid<u8>) -> u32 {
+    dijkstra(input, 1)
+}
+
+pub fn part2(input: &Grid<u8>) -> u32 {
+    dijkstra(input, 5)
+}
+
+fn dijkstra(grid: &Grid<u8>, tiles: i32) -> u32 {
+    let (width, height) = (grid.width * tiles, grid.height * tiles);
+    let risk = |point: Point| {
+        let base = grid[Point::new(point.x % grid.width, point.y % grid.height)] as i32;
+        let shift = point.x / grid.width + point.y / grid.height;
+        ((base + shift - 1) % 9 + 1) as u32
+    };
+
+    let end = Point::new(width - 1, height - 1);
+    let mut cost = Grid::new(width, height, u32::MAX);
+    let mut todo = MinHeap::new();
+
+    cost[ORIGIN] = 0;
+    todo.push(0, ORIGIN);
+
+    while let Some((total, point)) = todo.pop() {
+        if point == end {
+            return total;
+        }
+        if total > cost[point] {
+            continue;
+        }
+
+        for next in cost.orthogonal(point) {
+            let next_total = total + risk(next);
+            if next_total < cost[next] {
+                cost[next] = next_total;
+                todo.push(next_total, next);
+            }
+        }
+    }
+
+    unreachable!()
+}
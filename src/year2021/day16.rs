@@ -0,0 +1,75 @@
+//! # Packet Decoder
+//!
+//! The hexadecimal transmission is expanded to one bit per byte, then a recursive parser reads
+//! each packet, returning both the sum of its version numbers and its value so that both
+//! parts come from a single pass.
+pub fn parse(input: &str) -> (u64, u64) {
+    let bits: Vec<_> = input
+        .trim()
+        .bytes()
+        .map(|b| (b as char).to_digit(16).unwrap())
+        .flat_map(|n| (0..4).rev().map(move |i| (n >> i) & 1))
+        .collect();
+    packet(&bits, &mut 0)
+}
+
+pub fn part1(input: &(u64, u64)) -> u64 {
+    input.0
+}
+
+pub fn part2(input: &(u64, u64)) -> u64 {
+    input.1
+}
+
+/// Returns the version sum and value of the packet starting at `index`.
+fn packet(bits: &[u32], index: &mut usize) -> (u64, u64) {
+    let mut read = |width: usize| {
+        let value = bits[*index..*index + width].iter().fold(0, |acc, &b| (acc << 1) | b as u64);
+        *index += width;
+        value
+    };
+
+    let mut versions = read(3);
+    let kind = read(3);
+
+    if kind == 4 {
+        let mut value = 0;
+        loop {
+            let group = read(5);
+            value = (value << 4) | (group & 0xf);
+            if group < 16 {
+                return (versions, value);
+            }
+        }
+    }
+
+    let mut values = Vec::new();
+
+    if read(1) == 0 {
+        let length = read(15) as usize;
+        let end = *index + length;
+        while *index < end {
+            let (version, value) = packet(bits, index);
+            versions += version;
+            values.push(value);
+        }
+    } else {
+        for _ in 0..read(11) {
+            let (version, value) = packet(bits, index);
+            versions += version;
+            values.push(value);
+        }
+    }
+
+    let value = match kind {
+        0 => values.iter().sum(),
+        1 => values.iter().product(),
+        2 => *values.iter().min().unwrap(),
+        3 => *values.iter().max().unwrap(),
+        5 => (values[0] > values[1]) as u64,
+        6 => (values[0] < values[1]) as u64,
+        _ => (values[0] == values[1]) as u64,
+    };
+
+    (versions, value)
+}
@@ -0,0 +1,41 @@
+//! # Trick Shot
+//!
+//! Going up, the probe passes through exactly the same heights on the way back down, so it
+//! returns to `y = 0` with its launch speed plus one. The highest launch that can still hit
+//! the target is the one whose next step lands on the bottom row, giving a triangular number.
+//!
+//! The second part checks every launch velocity within the bounds that could possibly hit.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> [i32; 4] {
+    input.iter_signed().chunk::<4>().next().unwrap()
+}
+
+pub fn part1(input: &[i32; 4]) -> i32 {
+    let [_, _, bottom, _] = *input;
+    let n = -bottom - 1;
+    n * (n + 1) / 2
+}
+
+pub fn part2(input: &[i32; 4]) -> usize {
+    let [left, right, bottom, top] = *input;
+    let hits = |mut dx: i32, mut dy: i32| {
+        let (mut x, mut y) = (0, 0);
+        while x <= right && y >= bottom {
+            if x >= left && y <= top {
+                return true;
+            }
+            x += dx;
+            y += dy;
+            dx -= dx.signum();
+            dy -= 1;
+        }
+        false
+    };
+
+    (1..=right)
+        .flat_map(|dx| (bottom..-bottom).map(move |dy| (dx, dy)))
+        .filter(|&(dx, dy)| hits(dx, dy))
+        .count()
+}
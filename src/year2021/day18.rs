@@ -0,0 +1,92 @@
+//! # Snailfish
+//!
+//! Each number is flattened into a list of regular numbers paired with their depth, the
+//! number of pairs enclosing them. Exploding and splitting only ever look at neighboring
+//! regular numbers, which are simply adjacent in the list. An exploding pair is two adjacent
+//! numbers at depth five, as numbers are always reduced before another level is added.
+type Number = Vec<(u32, u32)>;
+
+pub fn parse(input: &str) -> Vec<Number> {
+    input
+        .lines()
+        .map(|line| {
+            let mut depth = 0;
+            let mut number = Vec::new();
+
+            // Numbers in the input are always reduced, so every regular number is a single digit.
+            for b in line.bytes() {
+                match b {
+                    b'[' => depth += 1,
+                    b']' => depth -= 1,
+                    b'0'..=b'9' => number.push(((b - b'0') as u32, depth)),
+                    _ => (),
+                }
+            }
+
+            number
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Number]) -> u32 {
+    let sum = input.iter().skip(1).fold(input[0].clone(), |acc, n| add(&acc, n));
+    magnitude(&sum, &mut 0, 0)
+}
+
+pub fn part2(input: &[Number]) -> u32 {
+    let mut best = 0;
+
+    for (i, a) in input.iter().enumerate() {
+        for (j, b) in input.iter().enumerate() {
+            if i != j {
+                best = best.max(magnitude(&add(a, b), &mut 0, 0));
+            }
+        }
+    }
+
+    best
+}
+
+fn add(a: &Number, b: &Number) -> Number {
+    let mut number: Number = a.iter().chain(b).map(|&(value, depth)| (value, depth + 1)).collect();
+    while explode(&mut number) || split(&mut number) {}
+    number
+}
+
+fn explode(number: &mut Number) -> bool {
+    let Some(i) = number.iter().position(|&(_, depth)| depth > 4) else {
+        return false;
+    };
+
+    let (left, right) = (number[i].0, number[i + 1].0);
+    if i > 0 {
+        number[i - 1].0 += left;
+    }
+    if i + 2 < number.len() {
+        number[i + 2].0 += right;
+    }
+
+    number[i] = (0, 4);
+    number.remove(i + 1);
+    true
+}
+
+fn split(number: &mut Number) -> bool {
+    let Some(i) = number.iter().position(|&(value, _)| value >= 10) else {
+        return false;
+    };
+
+    let (value, depth) = number[i];
+    number[i] = (value / 2, depth + 1);
+    number.insert(i + 1, (value.div_ceil(2), depth + 1));
+    true
+}
+
+fn magnitude(number: &Number, index: &mut usize, depth: u32) -> u32 {
+    if number[*index].1 == depth {
+        *index += 1;
+        number[*index - 1].0
+    } else {
+        3 * magnitude(number, index, depth + 1) + 2 * magnitude(number, index, depth + 1)
+    }
+}
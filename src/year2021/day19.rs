@@ -0,0 +1,121 @@
+//! # Beacon Scanner
+//!
+//! Distances between pairs of beacons don't change with the orientation or position of a
+//! scanner. Two scanners sharing at least 12 beacons must share at least 66 of these
+//! fingerprints, so pairs of scanners that can't overlap are ruled out cheaply.
+//!
+//! Starting from scanner 0, each located scanner is matched against the remaining scanners.
+//! For each of the 24 orientations, every pair of beacons votes for the offset between them.
+//! An offset with at least 12 votes locates the scanner, whose beacons are then converted to
+//! the coordinates of scanner 0.
+use crate::util::hash::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+struct Scanner {
+    beacons: Vec<Point3>,
+    fingerprints: FastSet<i32>,
+}
+
+pub fn parse(input: &str) -> (usize, i32) {
+    let scanners: Vec<_> = input
+        .split("\n\n")
+        .map(|block| {
+            let (_, beacons) = block.split_once('\n').unwrap();
+            let beacons: Vec<Point3> =
+                beacons.iter_signed().chunk::<3>().map(Point3::from).collect();
+            let fingerprints = beacons
+                .iter()
+                .enumerate()
+                .flat_map(|(i, &a)| beacons[i + 1..].iter().map(move |&b| squared(a - b)))
+                .collect();
+            Scanner { beacons, fingerprints }
+        })
+        .collect();
+
+    let mut located: Vec<Option<(Vec<Point3>, Point3)>> = vec![None; scanners.len()];
+    located[0] = Some((scanners[0].beacons.clone(), ORIGIN3));
+    let mut todo = vec![0];
+
+    while let Some(known) = todo.pop() {
+        for other in 0..scanners.len() {
+            if located[other].is_some()
+                || scanners[known].fingerprints.intersection(&scanners[other].fingerprints).count()
+                    < 66
+            {
+                continue;
+            }
+
+            let (beacons, _) = located[known].as_ref().unwrap();
+            if let Some(found) = align(beacons, &scanners[other].beacons) {
+                located[other] = Some(found);
+                todo.push(other);
+            }
+        }
+    }
+
+    let located: Vec<_> = located.into_iter().map(Option::unwrap).collect();
+    let beacons: FastSet<_> = located.iter().flat_map(|(beacons, _)| beacons).collect();
+    let furthest = located
+        .iter()
+        .flat_map(|(_, a)| located.iter().map(move |(_, b)| a.manhattan(*b)))
+        .max()
+        .unwrap();
+
+    (beacons.len(), furthest)
+}
+
+pub fn part1(input: &(usize, i32)) -> usize {
+    input.0
+}
+
+pub fn part2(input: &(usize, i32)) -> i32 {
+    input.1
+}
+
+/// Returns the other scanner's beacons and position relative to the known scanner.
+fn align(known: &[Point3], other: &[Point3]) -> Option<(Vec<Point3>, Point3)> {
+    for rotation in 0..24 {
+        let rotated: Vec<_> = other.iter().map(|&beacon| rotate(beacon, rotation)).collect();
+        let mut votes = FastMap::new();
+
+        for a in known {
+            for b in &rotated {
+                let offset = *a - *b;
+                let count = votes.entry(offset).or_insert(0);
+                *count += 1;
+
+                if *count >= 12 {
+                    let beacons = rotated.iter().map(|&b| b + offset).collect();
+                    return Some((beacons, offset));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// One of the 24 orientations, made up of six directions for the x axis each with four
+/// rotations around it.
+fn rotate(Point3 { x, y, z }: Point3, rotation: usize) -> Point3 {
+    let [x, y, z] = match rotation / 4 {
+        0 => [x, y, z],
+        1 => [-x, -y, z],
+        2 => [y, -x, z],
+        3 => [-y, x, z],
+        4 => [z, y, -x],
+        _ => [-z, y, x],
+    };
+    match rotation % 4 {
+        0 => Point3::new(x, y, z),
+        1 => Point3::new(x, -z, y),
+        2 => Point3::new(x, -y, -z),
+        _ => Point3::new(x, z, -y),
+    }
+}
+
+fn squared(Point3 { x, y, z }: Point3) -> i32 {
+    x * x + y * y + z * z
+}
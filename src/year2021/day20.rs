@@ -0,0 +1,61 @@
+//! # Trench Map
+//!
+//! The image is infinite, but every pixel far enough from the input shares the same value.
+//! This background starts dark, and if the first character of the algorithm is lit then it
+//! flashes on and off each step. The image only needs to grow by one pixel on each side per
+//! step, with any pixel outside the stored image taking the value of the background.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub struct Input {
+    algorithm: Vec<bool>,
+    image: Grid<bool>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let (algorithm, image) = input.split_once("\n\n").unwrap();
+    let algorithm = algorithm.bytes().map(|b| b == b'#').collect();
+    let grid = Grid::parse(image);
+    let image = Grid {
+        width: grid.width,
+        height: grid.height,
+        bytes: grid.bytes.iter().map(|&b| b == b'#').collect(),
+    };
+    Input { algorithm, image }
+}
+
+pub fn part1(input: &Input) -> usize {
+    enhance(input, 2)
+}
+
+pub fn part2(input: &Input) -> usize {
+    enhance(input, 50)
+}
+
+fn enhance(input: &Input, steps: usize) -> usize {
+    let mut image = input.image.clone();
+    let mut background = false;
+
+    for _ in 0..steps {
+        let mut next = Grid::new(image.width + 2, image.height + 2, false);
+
+        for y in 0..next.height {
+            for x in 0..next.width {
+                let center = Point::new(x - 1, y - 1);
+                let index = [-1, 0, 1]
+                    .iter()
+                    .flat_map(|&dy| [-1, 0, 1].map(|dx| center + Point::new(dx, dy)))
+                    .fold(0, |acc, point| {
+                        let lit = if image.contains(point) { image[point] } else { background };
+                        (acc << 1) | lit as usize
+                    });
+                next[Point::new(x, y)] = input.algorithm[index];
+            }
+        }
+
+        background = input.algorithm[if background { 511 } else { 0 }];
+        image = next;
+    }
+
+    image.bytes.iter().filter(|&&b| b).count()
+}
@@ -0,0 +1,81 @@
+//! # Dirac Dice
+//!
+//! The first part plays the deterministic game directly.
+//!
+//! In the second part, three rolls of the Dirac die only give seven different totals, from 3
+//! to 9, with 27 universes split between them. The number of wins from any position only
+//! depends on both players' positions and scores. Every turn increases the combined score, so a
+//! table over those four values is filled in order of combined score from highest to lowest,
+//! looking up the state after each total with the players swapped.
+use crate::util::parse::*;
+
+/// Total of three rolls and the number of universes it occurs in.
+const DIRAC: [(usize, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+pub fn parse(input: &str) -> [usize; 2] {
+    let mut numbers = input.iter_unsigned().skip(1).step_by(2);
+    [numbers.next().unwrap(), numbers.next().unwrap()]
+}
+
+pub fn part1(input: &[usize; 2]) -> usize {
+    let mut position = *input;
+    let mut score = [0; 2];
+    let mut rolls = 0;
+
+    for player in [0, 1].into_iter().cycle() {
+        let total = 3 * rolls + 6;
+        rolls += 3;
+        position[player] = (position[player] + total - 1) % 10 + 1;
+        score[player] += position[player];
+
+        if score[player] >= 1000 {
+            return score[1 - player] * rolls;
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part2(input: &[usize; 2]) -> u64 {
+    let wins = wins();
+    let [first, second] = wins[index(input[0], input[1], 0, 0)];
+    first.max(second)
+}
+
+/// Universes where the current and other player win respectively, for every pair of positions
+/// and scores below 21.
+fn wins() -> Vec<[u64; 2]> {
+    let mut wins = vec![[0; 2]; 10 * 10 * 21 * 21];
+
+    for combined in (0..41_usize).rev() {
+        for score in combined.saturating_sub(20)..=combined.min(20) {
+            let other_score = combined - score;
+
+            for position in 1..=10 {
+                for other in 1..=10 {
+                    let mut result = [0, 0];
+
+                    for (total, universes) in DIRAC {
+                        let next = (position + total - 1) % 10 + 1;
+                        if score + next >= 21 {
+                            result[0] += universes;
+                        } else {
+                            let [theirs, ours] =
+                                wins[index(other, next, other_score, score + next)];
+                            result[0] += universes * ours;
+                            result[1] += universes * theirs;
+                        }
+                    }
+
+                    wins[index(position, other, score, other_score)] = result;
+                }
+            }
+        }
+    }
+
+    wins
+}
+
+fn index(position: usize, other: usize, score: usize, other_score: usize) -> usize {
+    (((position - 1) * 10 + other - 1) * 21 + score) * 21 + other_score
+}
@@ -0,0 +1,67 @@
+//! # Reactor Reboot
+//!
+//! Tracks a list of signed cuboids using the [inclusion-exclusion principle]. Each new step
+//! adds a cuboid of opposite sign for its intersection with every existing cuboid, cancelling
+//! any overlap, then "on" steps add the cuboid itself. The total volume is the signed sum.
+//!
+//! [inclusion-exclusion principle]: https://en.wikipedia.org/wiki/Inclusion%E2%80%93exclusion_principle
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+#[derive(Clone, Copy)]
+pub struct Cuboid {
+    min: [i64; 3],
+    max: [i64; 3],
+}
+
+impl Cuboid {
+    fn intersect(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min: [i64; 3] = std::array::from_fn(|i| self.min[i].max(other.min[i]));
+        let max: [i64; 3] = std::array::from_fn(|i| self.max[i].min(other.max[i]));
+        (0..3).all(|i| min[i] <= max[i]).then_some(Cuboid { min, max })
+    }
+
+    fn volume(&self) -> i64 {
+        (0..3).map(|i| self.max[i] - self.min[i] + 1).product()
+    }
+}
+
+pub fn parse(input: &str) -> Vec<(bool, Cuboid)> {
+    input
+        .lines()
+        .map(|line| {
+            let [x1, x2, y1, y2, z1, z2] = line.iter_signed().chunk::<6>().next().unwrap();
+            (line.starts_with("on"), Cuboid { min: [x1, y1, z1], max: [x2, y2, z2] })
+        })
+        .collect()
+}
+
+pub fn part1(input: &[(bool, Cuboid)]) -> i64 {
+    let region = Cuboid { min: [-50; 3], max: [50; 3] };
+    let steps: Vec<_> = input
+        .iter()
+        .filter_map(|&(on, cuboid)| cuboid.intersect(&region).map(|c| (on, c)))
+        .collect();
+    reboot(&steps)
+}
+
+pub fn part2(input: &[(bool, Cuboid)]) -> i64 {
+    reboot(input)
+}
+
+fn reboot(steps: &[(bool, Cuboid)]) -> i64 {
+    let mut cuboids: Vec<(i64, Cuboid)> = Vec::new();
+
+    for &(on, cuboid) in steps {
+        let overlaps: Vec<_> = cuboids
+            .iter()
+            .filter_map(|(sign, other)| other.intersect(&cuboid).map(|c| (-sign, c)))
+            .collect();
+        cuboids.extend(overlaps);
+        if on {
+            cuboids.push((1, cuboid));
+        }
+    }
+
+    cuboids.iter().map(|(sign, cuboid)| sign * cuboid.volume()).sum()
+}
@@ -0,0 +1,126 @@
+//! # Amphipod
+//!
+//! [Dijkstra's algorithm] over the states of the burrow. The rules limit the moves a lot, since
+//! an amphipod only ever makes two moves, first from its starting room into the hallway, then
+//! from the hallway straight into its destination room. Each state is the contents of the
+//! hallway plus the rooms, listed from top to bottom.
+//!
+//! Amphipods are stored as 1 to 4 with 0 as empty, so that the destination room of type `t`
+//! is room `t - 1`.
+//!
+//! [Dijkstra's algorithm]: https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+use crate::util::hash::*;
+use crate::util::heap::*;
+
+/// Hallway spaces that aren't directly outside a room.
+const STOPS: [usize; 7] = [0, 1, 3, 5, 7, 9, 10];
+const ENERGY: [u32; 5] = [0, 1, 10, 100, 1000];
+/// Rows unfolded from the diagram in the second part.
+const UNFOLDED: [[u8; 4]; 2] = [[4, 3, 2, 1], [4, 2, 1, 3]];
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Burrow {
+    hallway: [u8; 11],
+    rooms: [[u8; 4]; 4],
+}
+
+pub fn parse(input: &str) -> [[u8; 4]; 2] {
+    let letters: Vec<_> =
+        input.bytes().filter(u8::is_ascii_uppercase).map(|b| b - b'A' + 1).collect();
+    [
+        [letters[0], letters[1], letters[2], letters[3]],
+        [letters[4], letters[5], letters[6], letters[7]],
+    ]
+}
+
+pub fn part1(input: &[[u8; 4]; 2]) -> u32 {
+    organize(&[input[0], input[1]])
+}
+
+pub fn part2(input: &[[u8; 4]; 2]) -> u32 {
+    organize(&[input[0], UNFOLDED[0], UNFOLDED[1], input[1]])
+}
+
+fn organize(rows: &[[u8; 4]]) -> u32 {
+    let depth = rows.len();
+    let mut rooms = [[0; 4]; 4];
+    for (i, row) in rows.iter().enumerate() {
+        for r in 0..4 {
+            rooms[r][i] = row[r];
+        }
+    }
+
+    let start = Burrow { hallway: [0; 11], rooms };
+    let mut todo = MinHeap::new();
+    let mut cost = FastMap::build([(start, 0)]);
+    todo.push(0, start);
+
+    while let Some((energy, burrow)) = todo.pop() {
+        if energy > cost[&burrow] {
+            continue;
+        }
+        if (0..4).all(|r| burrow.rooms[r][..depth].iter().all(|&a| a as usize == r + 1)) {
+            return energy;
+        }
+
+        let mut push = |next: Burrow, extra: u32| {
+            let total = energy + extra;
+            if cost.get(&next).is_none_or(|&c| total < c) {
+                cost.insert(next, total);
+                todo.push(total, next);
+            }
+        };
+
+        // Amphipods in the hallway move into their destination room.
+        for h in 0..11 {
+            let kind = burrow.hallway[h];
+            if kind == 0 {
+                continue;
+            }
+
+            let room = kind as usize - 1;
+            let entrance = 2 + 2 * room;
+            let contents = &burrow.rooms[room][..depth];
+            if contents.iter().any(|&a| a != 0 && a != kind) || !clear(&burrow, h, entrance) {
+                continue;
+            }
+
+            let slot = contents.iter().rposition(|&a| a == 0).unwrap();
+            let mut next = burrow;
+            next.hallway[h] = 0;
+            next.rooms[room][slot] = kind;
+            push(next, (h.abs_diff(entrance) + slot + 1) as u32 * ENERGY[kind as usize]);
+        }
+
+        // The top amphipod of each room moves into the hallway, unless the room is finished.
+        for room in 0..4 {
+            let contents = &burrow.rooms[room][..depth];
+            let Some(slot) = contents.iter().position(|&a| a != 0) else {
+                continue;
+            };
+            if contents[slot..].iter().all(|&a| a as usize == room + 1) {
+                continue;
+            }
+
+            let kind = contents[slot];
+            let entrance = 2 + 2 * room;
+
+            for h in STOPS {
+                if clear(&burrow, entrance, h) {
+                    let mut next = burrow;
+                    next.hallway[h] = kind;
+                    next.rooms[room][slot] = 0;
+                    push(next, (h.abs_diff(entrance) + slot + 1) as u32 * ENERGY[kind as usize]);
+                }
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+/// Checks the hallway from `from`, exclusive, to `to`, inclusive, is empty.
+fn clear(burrow: &Burrow, from: usize, to: usize) -> bool {
+    let range = if from < to { from + 1..=to } else { to..=from - 1 };
+    burrow.hallway[range].iter().all(|&a| a == 0)
+}
@@ -0,0 +1,48 @@
+//! # Arithmetic Logic Unit
+//!
+//! The MONAD program is 14 blocks of 18 instructions, one per digit, that differ only in three
+//! constants. Decompiled, `z` is a stack of base 26 digits. Blocks with `div z 1` push the
+//! digit plus the constant added to `y`. Blocks with `div z 26` pop, and avoid pushing again
+//! only if their digit equals the popped value plus the constant added to `x`.
+//!
+//! For `z` to end at zero every pop must avoid pushing, so the blocks pair up like brackets,
+//! each pair constraining the later digit to equal the earlier digit plus a fixed offset. The
+//! largest and smallest model numbers then pick the largest or smallest digits satisfying each
+//! constraint independently.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> [u64; 2] {
+    let lines: Vec<_> = input.lines().collect();
+    let mut largest = [0; 14];
+    let mut smallest = [0; 14];
+    let mut stack = Vec::new();
+
+    for (digit, block) in lines.chunks_exact(18).enumerate() {
+        if block[4].ends_with(" 1") {
+            let push: i64 = block[15].signed();
+            stack.push((digit, push));
+        } else {
+            let (previous, push) = stack.pop().unwrap();
+            let offset = push + block[5].signed::<i64>();
+
+            if offset >= 0 {
+                (largest[previous], largest[digit]) = (9 - offset, 9);
+                (smallest[previous], smallest[digit]) = (1, 1 + offset);
+            } else {
+                (largest[previous], largest[digit]) = (9, 9 + offset);
+                (smallest[previous], smallest[digit]) = (1 - offset, 1);
+            }
+        }
+    }
+
+    let number = |digits: [i64; 14]| digits.iter().fold(0, |acc, &d| 10 * acc + d as u64);
+    [number(largest), number(smallest)]
+}
+
+pub fn part1(input: &[u64; 2]) -> u64 {
+    input[0]
+}
+
+pub fn part2(input: &[u64; 2]) -> u64 {
+    input[1]
+}
@@ -0,0 +1,49 @@
+//! # Sea Cucumber
+//!
+//! Simulates each herd in turn. Every sea cucumber in a herd decides whether to move before
+//! any of them move, so each herd collects its moves first, then applies them.
+//!
+//! This puzzle only has one part.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(input: &Grid<u8>) -> usize {
+    let mut grid = input.clone();
+
+    for step in 1.. {
+        let east = advance(&mut grid, b'>', RIGHT);
+        let south = advance(&mut grid, b'v', DOWN);
+        if !east && !south {
+            return step;
+        }
+    }
+
+    unreachable!()
+}
+
+/// Returns `true` if any sea cucumber in the herd moved.
+fn advance(grid: &mut Grid<u8>, herd: u8, direction: Point) -> bool {
+    let wrap = |point: Point| Point::new(point.x % grid.width, point.y % grid.height);
+    let mut moves = Vec::new();
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            let next = wrap(point + direction);
+            if grid[point] == herd && grid[next] == b'.' {
+                moves.push((point, next));
+            }
+        }
+    }
+
+    for &(from, to) in &moves {
+        grid[from] = b'.';
+        grid[to] = herd;
+    }
+
+    !moves.is_empty()
+}
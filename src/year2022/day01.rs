@@ -0,0 +1,20 @@
+//! # Calorie Counting
+//!
+//! Sums each elf's snacks then sorts the totals in descending order so that both parts are a
+//! simple slice of the result.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<u32> {
+    let mut elves: Vec<u32> =
+        input.split("\n\n").map(|elf| elf.iter_unsigned::<u32>().sum()).collect();
+    elves.sort_unstable_by(|a, b| b.cmp(a));
+    elves
+}
+
+pub fn part1(input: &[u32]) -> u32 {
+    input[0]
+}
+
+pub fn part2(input: &[u32]) -> u32 {
+    input.iter().take(3).sum()
+}
@@ -0,0 +1,28 @@
+//! # Rock Paper Scissors
+//!
+//! Mapping both columns to `0`, `1` and `2` turns the rules into modular arithmetic. Each shape
+//! beats the one before it, so `(ours - theirs + 1) mod 3` gives `0` for a loss, `1` for a draw
+//! and `2` for a win, which multiplied by three is exactly the outcome score.
+pub fn parse(input: &str) -> Vec<(u32, u32)> {
+    input
+        .lines()
+        .map(|line| {
+            let bytes = line.as_bytes();
+            ((bytes[0] - b'A') as u32, (bytes[2] - b'X') as u32)
+        })
+        .collect()
+}
+
+pub fn part1(input: &[(u32, u32)]) -> u32 {
+    input.iter().map(|&(theirs, ours)| ours + 1 + 3 * ((ours + 4 - theirs) % 3)).sum()
+}
+
+pub fn part2(input: &[(u32, u32)]) -> u32 {
+    input
+        .iter()
+        .map(|&(theirs, outcome)| {
+            let ours = (theirs + outcome + 2) % 3;
+            ours + 1 + 3 * outcome
+        })
+        .sum()
+}
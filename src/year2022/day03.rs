@@ -0,0 +1,40 @@
+//! # Rucksack Reorganization
+//!
+//! Each compartment is converted to a `u64` bitmask where bit `n` is set if the item with
+//! priority `n` is present. Intersecting masks is then a bitwise AND and the common item's
+//! priority is the index of the only remaining set bit.
+use crate::util::bitset::*;
+use crate::util::iter::*;
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(input: &[&str]) -> usize {
+    input
+        .iter()
+        .map(|line| {
+            let (left, right) = line.split_at(line.len() / 2);
+            priority(mask(left) & mask(right))
+        })
+        .sum()
+}
+
+pub fn part2(input: &[&str]) -> usize {
+    input.iter().chunk::<3>().map(|[a, b, c]| priority(mask(a) & mask(b) & mask(c))).sum()
+}
+
+fn mask(items: &str) -> u64 {
+    items.bytes().fold(0, |mask, b| {
+        let priority = match b {
+            b'a'..=b'z' => b - b'a' + 1,
+            b'A'..=b'Z' => b - b'A' + 27,
+            _ => unreachable!(),
+        };
+        mask | (1 << priority)
+    })
+}
+
+fn priority(mask: u64) -> usize {
+    mask.biterator().sum()
+}
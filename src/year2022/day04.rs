@@ -0,0 +1,17 @@
+//! # Camp Cleanup
+//!
+//! Each pair of ranges is parsed as four numbers `[a, b, c, d]` meaning `a-b,c-d`.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<[u32; 4]> {
+    input.iter_unsigned().chunk::<4>().collect()
+}
+
+pub fn part1(input: &[[u32; 4]]) -> usize {
+    input.iter().filter(|[a, b, c, d]| (a <= c && b >= d) || (c <= a && d >= b)).count()
+}
+
+pub fn part2(input: &[[u32; 4]]) -> usize {
+    input.iter().filter(|[a, b, c, d]| a <= d && c <= b).count()
+}
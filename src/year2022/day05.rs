@@ -0,0 +1,54 @@
+//! # Supply Stacks
+//!
+//! The drawing is parsed bottom up, skipping the row of stack numbers, with each crate found at
+//! column `1 + 4 * index`. Lines in the drawing may have trailing whitespace trimmed so shorter
+//! rows are allowed.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+type Input = (Vec<Vec<u8>>, Vec<[usize; 3]>);
+
+pub fn parse(input: &str) -> Input {
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+    let lines: Vec<_> = prefix.lines().collect();
+    let width = lines[lines.len() - 1].len().div_ceil(4);
+
+    let mut stacks = vec![Vec::new(); width];
+    for line in lines.iter().rev().skip(1) {
+        for (index, &b) in line.as_bytes().iter().skip(1).step_by(4).enumerate() {
+            if b.is_ascii_uppercase() {
+                stacks[index].push(b);
+            }
+        }
+    }
+
+    // Convert stack numbers to zero based indices.
+    let moves =
+        suffix.iter_unsigned().chunk::<3>().map(|[amount, from, to]| [amount, from - 1, to - 1]);
+
+    (stacks, moves.collect())
+}
+
+pub fn part1(input: &Input) -> String {
+    play(input, true)
+}
+
+pub fn part2(input: &Input) -> String {
+    play(input, false)
+}
+
+fn play((initial, moves): &Input, reverse: bool) -> String {
+    let mut stacks = initial.clone();
+    let mut crane = Vec::new();
+
+    for &[amount, from, to] in moves {
+        let start = stacks[from].len() - amount;
+        crane.extend(stacks[from].drain(start..));
+        if reverse {
+            crane.reverse();
+        }
+        stacks[to].append(&mut crane);
+    }
+
+    stacks.iter().filter_map(|stack| stack.last()).map(|&b| b as char).collect()
+}
@@ -0,0 +1,33 @@
+//! # Tuning Trouble
+//!
+//! A sliding window over the buffer toggles one bit per letter as it enters and leaves the
+//! window. Duplicate letters cancel each other out, so the window contains only distinct letters
+//! exactly when the number of set bits equals its length. This needs no allocation and visits
+//! each byte at most twice.
+pub fn parse(input: &str) -> &[u8] {
+    input.trim().as_bytes()
+}
+
+pub fn part1(input: &[u8]) -> usize {
+    find(input, 4)
+}
+
+pub fn part2(input: &[u8]) -> usize {
+    find(input, 14)
+}
+
+fn find(input: &[u8], size: usize) -> usize {
+    let mut mask = 0_u32;
+
+    for (i, &b) in input.iter().enumerate() {
+        mask ^= 1 << (b - b'a');
+        if i >= size {
+            mask ^= 1 << (input[i - size] - b'a');
+        }
+        if mask.count_ones() as usize == size {
+            return i + 1;
+        }
+    }
+
+    unreachable!()
+}
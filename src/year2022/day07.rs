@@ -0,0 +1,44 @@
+//! # No Space Left On Device
+//!
+//! The transcript is a depth first traversal of the filesystem, so the directory sizes can be
+//! calculated with a stack. Entering a directory pushes a new running total. Leaving adds the
+//! completed total to its parent. Directory names are irrelevant and `$ ls` or `dir` lines can
+//! be skipped entirely.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<u32> {
+    let mut stack = Vec::new();
+    let mut sizes = Vec::new();
+
+    for line in input.lines() {
+        if line == "$ cd .." {
+            let size = stack.pop().unwrap();
+            sizes.push(size);
+            *stack.last_mut().unwrap() += size;
+        } else if line.starts_with("$ cd") {
+            stack.push(0);
+        } else if line.as_bytes()[0].is_ascii_digit() {
+            *stack.last_mut().unwrap() += line.unsigned::<u32>();
+        }
+    }
+
+    // Unwind back to the root, which is always the last and largest directory.
+    while let Some(size) = stack.pop() {
+        sizes.push(size);
+        if let Some(parent) = stack.last_mut() {
+            *parent += size;
+        }
+    }
+
+    sizes
+}
+
+pub fn part1(input: &[u32]) -> u32 {
+    input.iter().filter(|&&size| size <= 100_000).sum()
+}
+
+pub fn part2(input: &[u32]) -> u32 {
+    let root = input[input.len() - 1];
+    let needed = 30_000_000 - (70_000_000 - root);
+    *input.iter().filter(|&&size| size >= needed).min().unwrap()
+}
@@ -0,0 +1,69 @@
+//! # Treetop Tree House
+//!
+//! For part one each row and column is scanned from both ends, tracking the tallest tree seen
+//! so far. Any tree taller than that is visible from the outside.
+//!
+//! Part two walks outwards from each tree in each direction until reaching the edge or a tree
+//! at least as tall.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(grid: &Grid<u8>) -> usize {
+    let mut visible = grid.same_size_with(false);
+
+    let mut scan = |start: Point, step: Point| {
+        let mut point = start;
+        let mut tallest = 0;
+
+        while grid.contains(point) {
+            if grid[point] > tallest {
+                tallest = grid[point];
+                visible[point] = true;
+            }
+            point += step;
+        }
+    };
+
+    for y in 0..grid.height {
+        scan(Point::new(0, y), RIGHT);
+        scan(Point::new(grid.width - 1, y), LEFT);
+    }
+    for x in 0..grid.width {
+        scan(Point::new(x, 0), DOWN);
+        scan(Point::new(x, grid.height - 1), UP);
+    }
+
+    visible.bytes.iter().filter(|&&v| v).count()
+}
+
+pub fn part2(grid: &Grid<u8>) -> usize {
+    let mut best = 0;
+
+    for y in 1..grid.height - 1 {
+        for x in 1..grid.width - 1 {
+            let tree = Point::new(x, y);
+            let score = ORTHOGONAL
+                .iter()
+                .map(|&direction| {
+                    let mut point = tree + direction;
+                    let mut distance = 1;
+
+                    while grid.contains(point + direction) && grid[point] < grid[tree] {
+                        point += direction;
+                        distance += 1;
+                    }
+
+                    distance
+                })
+                .product();
+
+            best = best.max(score);
+        }
+    }
+
+    best
+}
@@ -1,64 +1,136 @@
-pub fn parse(input: &str) -> Vec<&str> {
-    input.lines().collect()
+//! # Trebuchet?!
+//!
+//! Each line is scanned as bytes for its first and last numeric digit, which is all that part
+//! one needs. A digit word for part two can only come before the first digit or after the last
+//! one, so only those ends of the line are checked for words.
+//!
+//! No digit word contains another, so the first word to finish is also the first to start. Words
+//! are found by packing the most recent bytes into an integer, then comparing the bottom three,
+//! four and five bytes against each word packed the same way. The end of the line is handled by
+//! the same code scanning backwards, comparing against the words packed in reverse.
+/// Each word packed into the bottom bytes of an integer, a mask covering its length and its value.
+type Word = (u64, u64, u32);
+
+const FORWARD: [Word; 9] = words(false);
+const REVERSE: [Word; 9] = words(true);
+
+pub fn parse(input: &str) -> [u32; 2] {
+    input.lines().map(str::as_bytes).fold([0, 0], |[part1, part2], line| {
+        let (first, first_word) = match line.iter().position(u8::is_ascii_digit) {
+            Some(i) => (line[i] - b'0', find(&FORWARD, line[..i].iter())),
+            None => (0, find(&FORWARD, line.iter())),
+        };
+        let (last, last_word) = match line.iter().rposition(u8::is_ascii_digit) {
+            Some(i) => (line[i] - b'0', find(&REVERSE, line[i + 1..].iter().rev())),
+            None => (0, find(&REVERSE, line.iter().rev())),
+        };
+
+        let (first, last) = (first as u32, last as u32);
+        let word = 10 * first_word.unwrap_or(first) + last_word.unwrap_or(last);
+        [part1 + 10 * first + last, part2 + word]
+    })
 }
 
-pub fn part1(input: &[&str]) -> u32 {
-    let mut sum: u32 = 0;
+pub fn part1(input: &[u32; 2]) -> u32 {
+    input[0]
+}
+
+pub fn part2(input: &[u32; 2]) -> u32 {
+    input[1]
+}
 
-    for line in input {
-        let nums: String = line.chars().filter(|c| c.is_numeric()).collect();
-        if let (Some(first), Some(last)) = (nums.chars().next(), nums.chars().last()) {
-            let first_num = first.to_digit(10).unwrap();
-            let last_num = last.to_digit(10).unwrap();
+/// Value of the first word completed while packing `bytes` in order.
+#[inline]
+fn find<'a>(words: &[Word; 9], bytes: impl Iterator<Item = &'a u8>) -> Option<u32> {
+    let mut window = 0;
 
-            sum += first_num * 10 + last_num;
+    for &b in bytes {
+        window = (window << 8) | b as u64;
+        for &(word, mask, value) in words {
+            if window & mask == word {
+                return Some(value);
+            }
         }
     }
 
-    sum
+    None
 }
 
-pub fn part2(input: &[&str]) -> usize {
-    let mut sum: usize = 0;
+/// Packs every digit word, optionally with its letters reversed.
+const fn words(reverse: bool) -> [Word; 9] {
+    let words: [&[u8]; 9] =
+        [b"one", b"two", b"three", b"four", b"five", b"six", b"seven", b"eight", b"nine"];
+    let mut packed = [(0, 0, 0); 9];
+    let mut i = 0;
 
-    for line in input {
-        let mut numbers = Vec::new();
-        let chars: Vec<char> = line.chars().collect();
+    while i < 9 {
+        let word = words[i];
+        let mut j = 0;
+        while j < word.len() {
+            let b = if reverse { word[word.len() - 1 - j] } else { word[j] };
+            packed[i].0 = (packed[i].0 << 8) | b as u64;
+            packed[i].1 = (packed[i].1 << 8) | 0xff;
+            j += 1;
+        }
+        packed[i].2 = i as u32 + 1;
+        i += 1;
+    }
 
-        for (i, _) in chars.iter().enumerate() {
-            // Check for digit
-            if chars[i].is_numeric() {
-                numbers.push(chars[i].to_digit(10).unwrap() as usize);
-                continue;
-            }
+    packed
+}
 
-            // Check for word numbers starting at position i
-            let remaining: String = chars[i..].iter().collect();
-            if remaining.starts_with("one") {
-                numbers.push(1);
-            } else if remaining.starts_with("two") {
-                numbers.push(2);
-            } else if remaining.starts_with("three") {
-                numbers.push(3);
-            } else if remaining.starts_with("four") {
-                numbers.push(4);
-            } else if remaining.starts_with("five") {
-                numbers.push(5);
-            } else if remaining.starts_with("six") {
-                numbers.push(6);
-            } else if remaining.starts_with("seven") {
-                numbers.push(7);
-            } else if remaining.starts_with("eight") {
-                numbers.push(8);
-            } else if remaining.starts_with("nine") {
-                numbers.push(9);
-            }
+/// Straightforward implementation used to check optimized versions with differential testing.
+pub mod reference {
+    use crate::util::differential::*;
+
+    const WORDS: [&str; 9] =
+        ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+    pub fn differential() -> Differential {
+        Differential {
+            optimized: |input| {
+                let input = super::parse(input);
+                (super::part1(&input).to_string(), super::part2(&input).to_string())
+            },
+            reference: |input| (part1(input).to_string(), part2(input).to_string()),
+            generate,
         }
+    }
 
-        if let (Some(&first), Some(&last)) = (numbers.first(), numbers.last()) {
-            sum += first * 10 + last;
+    pub fn part1(input: &str) -> u32 {
+        input.lines().map(|line| calibration(line, |s| s.chars().next()?.to_digit(10))).sum()
+    }
+
+    pub fn part2(input: &str) -> u32 {
+        let digit = |s: &str| {
+            let word = WORDS.iter().position(|word| s.starts_with(word));
+            s.chars().next()?.to_digit(10).or(word.map(|i| i as u32 + 1))
+        };
+        input.lines().map(|line| calibration(line, digit)).sum()
+    }
+
+    /// Lines are a random mix of letters, digits and (sometimes overlapping) digit words.
+    pub fn generate(rng: &mut Rng) -> String {
+        let mut input = String::new();
+
+        for _ in 0..rng.between(1, 20) {
+            for _ in 0..rng.between(1, 8) {
+                match rng.below(3) {
+                    0 => input.push((b'a' + rng.below(26) as u8) as char),
+                    1 => input.push((b'1' + rng.below(9) as u8) as char),
+                    _ => input.push_str(WORDS[rng.below(9)]),
+                }
+            }
+            input.push('\n');
         }
+
+        input
     }
 
-    sum
+    fn calibration(line: &str, digit: impl Fn(&str) -> Option<u32>) -> u32 {
+        let mut digits = (0..line.len()).filter_map(|i| digit(&line[i..]));
+        let first = digits.next().unwrap_or(0);
+        let last = digits.last().unwrap_or(first);
+        10 * first + last
+    }
 }
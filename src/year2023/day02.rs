@@ -0,0 +1,40 @@
+//! # Cube Conundrum
+//!
+//! Only the maximum number of each color drawn matters for both parts, so each game is reduced
+//! to a single `[red, green, blue]` array while parsing.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<[u32; 3]> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, draws) = line.split_once(": ").unwrap();
+            let mut maximum = [0; 3];
+
+            for cubes in draws.split(&[',', ';']) {
+                let (amount, color) = cubes.trim().split_once(' ').unwrap();
+                let index = match color {
+                    "red" => 0,
+                    "green" => 1,
+                    _ => 2,
+                };
+                maximum[index] = maximum[index].max(amount.unsigned());
+            }
+
+            maximum
+        })
+        .collect()
+}
+
+pub fn part1(input: &[[u32; 3]]) -> usize {
+    input
+        .iter()
+        .enumerate()
+        .filter(|(_, [red, green, blue])| *red <= 12 && *green <= 13 && *blue <= 14)
+        .map(|(index, _)| index + 1)
+        .sum()
+}
+
+pub fn part2(input: &[[u32; 3]]) -> u32 {
+    input.iter().map(|[red, green, blue]| red * green * blue).sum()
+}
@@ -0,0 +1,66 @@
+//! # Gear Ratios
+//!
+//! Each number is found by scanning the rows of the [`Grid`], then every cell surrounding the
+//! number is checked for symbols. A second grid accumulates the count and product of the numbers
+//! next to each `*` so that gears can be found in a single pass.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> (u32, u32) {
+    let grid = Grid::parse(input);
+    let mut gears = grid.same_size_with((0, 1));
+    let mut part_numbers = 0;
+
+    for y in 0..grid.height {
+        let mut x = 0;
+
+        while x < grid.width {
+            if !grid[Point::new(x, y)].is_ascii_digit() {
+                x += 1;
+                continue;
+            }
+
+            let start = x;
+            let mut number = 0;
+            while x < grid.width && grid[Point::new(x, y)].is_ascii_digit() {
+                number = 10 * number + (grid[Point::new(x, y)] - b'0') as u32;
+                x += 1;
+            }
+
+            let mut is_part = false;
+            for ny in y - 1..=y + 1 {
+                for nx in start - 1..=x {
+                    let point = Point::new(nx, ny);
+                    if !grid.contains(point) {
+                        continue;
+                    }
+
+                    let b = grid[point];
+                    if b != b'.' && !b.is_ascii_digit() {
+                        is_part = true;
+                    }
+                    if b == b'*' {
+                        let (count, product) = &mut gears[point];
+                        *count += 1;
+                        *product *= number;
+                    }
+                }
+            }
+
+            if is_part {
+                part_numbers += number;
+            }
+        }
+    }
+
+    let ratios = gears.bytes.iter().filter(|(count, _)| *count == 2).map(|(_, p)| p).sum();
+    (part_numbers, ratios)
+}
+
+pub fn part1(input: &(u32, u32)) -> u32 {
+    input.0
+}
+
+pub fn part2(input: &(u32, u32)) -> u32 {
+    input.1
+}
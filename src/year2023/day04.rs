@@ -0,0 +1,33 @@
+//! # Scratchcards
+//!
+//! Numbers are all less than 100 so each side of a card is stored as a `u128` bitmask, with the
+//! number of matches given by the population count of the intersection.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<usize> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(": ").unwrap();
+            let (winning, have) = numbers.split_once(" | ").unwrap();
+            let mask = |s: &str| s.iter_unsigned().fold(0_u128, |mask, n: u32| mask | (1 << n));
+            (mask(winning) & mask(have)).count_ones() as usize
+        })
+        .collect()
+}
+
+pub fn part1(input: &[usize]) -> u32 {
+    input.iter().map(|&matches| (1 << matches) >> 1).sum()
+}
+
+pub fn part2(input: &[usize]) -> u32 {
+    let mut copies = vec![1; input.len()];
+
+    for (i, &matches) in input.iter().enumerate() {
+        for j in i + 1..(i + 1 + matches).min(input.len()) {
+            copies[j] += copies[i];
+        }
+    }
+
+    copies.iter().sum()
+}
@@ -0,0 +1,70 @@
+//! # If You Give A Seed A Fertilizer
+//!
+//! Part two maps whole ranges at once. Each range is split wherever it crosses the boundary of
+//! a mapping, mapped pieces move on to the next stage and unmapped pieces are checked against
+//! the remaining mappings of the same stage.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub struct Input {
+    seeds: Vec<u64>,
+    stages: Vec<Vec<[u64; 3]>>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let chunks: Vec<_> = input.split("\n\n").collect();
+    let seeds = chunks[0].iter_unsigned().collect();
+    let stages =
+        chunks[1..].iter().map(|chunk| chunk.iter_unsigned().chunk::<3>().collect()).collect();
+    Input { seeds, stages }
+}
+
+pub fn part1(input: &Input) -> u64 {
+    input
+        .seeds
+        .iter()
+        .map(|&seed| {
+            input.stages.iter().fold(seed, |value, stage| {
+                stage
+                    .iter()
+                    .find(|&&[_, source, length]| source <= value && value < source + length)
+                    .map_or(value, |&[destination, source, _]| value - source + destination)
+            })
+        })
+        .min()
+        .unwrap()
+}
+
+pub fn part2(input: &Input) -> u64 {
+    // Half open ranges `[start, end)`.
+    let mut current: Vec<_> =
+        input.seeds.iter().chunk::<2>().map(|[&start, &length]| [start, start + length]).collect();
+    let mut next = Vec::new();
+
+    for stage in &input.stages {
+        for &[destination, source, length] in stage {
+            let end = source + length;
+            let mut unmapped = Vec::new();
+
+            for [s, e] in current.drain(..) {
+                // Piece before, inside and after the mapping.
+                let inside = [s.max(source), e.min(end)];
+                if s < inside[0].min(e) {
+                    unmapped.push([s, inside[0].min(e)]);
+                }
+                if inside[0] < inside[1] {
+                    next.push([inside[0] - source + destination, inside[1] - source + destination]);
+                }
+                if inside[1].max(s) < e {
+                    unmapped.push([inside[1].max(s), e]);
+                }
+            }
+
+            current = unmapped;
+        }
+
+        current.append(&mut next);
+    }
+
+    current.iter().map(|range| range[0]).min().unwrap()
+}
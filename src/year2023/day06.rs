@@ -0,0 +1,38 @@
+//! # Wait For It
+//!
+//! Holding the button for `h` milliseconds of a race lasting `t` travels `h(t - h)`, so the
+//! winning hold times lie strictly between the roots of the quadratic `h² - th + d = 0`. An
+//! integer square root finds an approximate lower root that is then nudged to the exact value.
+use crate::util::math::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(input: &[&str]) -> u64 {
+    let times = input[0].iter_unsigned();
+    let distances = input[1].iter_unsigned();
+    times.zip(distances).map(|(time, distance)| ways(time, distance)).product()
+}
+
+pub fn part2(input: &[&str]) -> u64 {
+    let merge = |line: &str| {
+        line.bytes().filter(u8::is_ascii_digit).fold(0, |n, b| 10 * n + (b - b'0') as u64)
+    };
+    ways(merge(input[0]), merge(input[1]))
+}
+
+fn ways(time: u64, distance: u64) -> u64 {
+    let wins = |hold: u64| hold * (time - hold) > distance;
+
+    let mut hold = (time - (time * time - 4 * distance).sqrt()) / 2;
+    while !wins(hold) {
+        hold += 1;
+    }
+    while hold > 0 && wins(hold - 1) {
+        hold -= 1;
+    }
+
+    time - 2 * hold + 1
+}
@@ -0,0 +1,51 @@
+//! # Camel Cards
+//!
+//! Each hand is converted to a single number that sorts correctly. The hand type is the most
+//! significant digit (from the two highest card counts) followed by each card's value in base 16.
+//! Jokers in part two are worth the least and always join the largest group of other cards.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<(&[u8], u32)> {
+    input
+        .lines()
+        .map(|line| {
+            let (hand, bid) = line.split_once(' ').unwrap();
+            (hand.as_bytes(), bid.unsigned())
+        })
+        .collect()
+}
+
+pub fn part1(input: &[(&[u8], u32)]) -> u32 {
+    winnings(input, b"23456789TJQKA")
+}
+
+pub fn part2(input: &[(&[u8], u32)]) -> u32 {
+    winnings(input, b"J23456789TQKA")
+}
+
+fn winnings(input: &[(&[u8], u32)], order: &[u8]) -> u32 {
+    let jokers = order[0] == b'J';
+
+    let mut hands: Vec<_> = input
+        .iter()
+        .map(|&(hand, bid)| {
+            let mut counts = [0; 13];
+            let mut value = 0;
+
+            for &card in hand {
+                let rank = order.iter().position(|&c| c == card).unwrap();
+                counts[rank] += 1;
+                value = (value << 4) | rank;
+            }
+
+            let joker = if jokers { std::mem::take(&mut counts[0]) } else { 0 };
+            counts.sort_unstable_by(|a, b| b.cmp(a));
+            let kind = 3 * (counts[0] + joker) + counts[1];
+
+            ((kind << 20) | value, bid)
+        })
+        .collect();
+
+    hands.sort_unstable();
+    hands.iter().enumerate().map(|(rank, &(_, bid))| (rank as u32 + 1) * bid).sum()
+}
@@ -0,0 +1,52 @@
+//! # Haunted Wasteland
+//!
+//! Part two relies on a property of the input. Each ghost reaches exactly one `Z` node, after a
+//! number of steps that is a multiple of the instruction length, and then loops back with the
+//! same period. The answer is therefore the least common multiple of each ghost's first arrival.
+use crate::util::hash::*;
+use crate::util::math::*;
+
+pub struct Input<'a> {
+    directions: &'a [u8],
+    names: Vec<&'a str>,
+    nodes: Vec<[usize; 2]>,
+}
+
+pub fn parse(input: &str) -> Input<'_> {
+    let (directions, network) = input.split_once("\n\n").unwrap();
+    let lines: Vec<_> = network.lines().collect();
+    let names: Vec<_> = lines.iter().map(|line| &line[0..3]).collect();
+    let indices: FastMap<_, _> = names.iter().enumerate().map(|(i, &name)| (name, i)).collect();
+    let nodes = lines.iter().map(|line| [indices[&line[7..10]], indices[&line[12..15]]]).collect();
+
+    Input { directions: directions.trim().as_bytes(), names, nodes }
+}
+
+pub fn part1(input: &Input<'_>) -> u64 {
+    let start = input.names.iter().position(|&name| name == "AAA").unwrap();
+    steps(input, start, |name| name == "ZZZ")
+}
+
+pub fn part2(input: &Input<'_>) -> u64 {
+    input
+        .names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| name.ends_with('A'))
+        .map(|(start, _)| steps(input, start, |name| name.ends_with('Z')))
+        .fold(1, u64::lcm)
+}
+
+fn steps(input: &Input<'_>, mut node: usize, end: impl Fn(&str) -> bool) -> u64 {
+    let mut steps = 0;
+
+    for &direction in input.directions.iter().cycle() {
+        if end(input.names[node]) {
+            break;
+        }
+        node = input.nodes[node][(direction == b'R') as usize];
+        steps += 1;
+    }
+
+    steps
+}
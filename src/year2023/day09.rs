@@ -0,0 +1,31 @@
+//! # Mirage Maintenance
+//!
+//! Repeatedly takes differences, summing the last value of each row to extrapolate forwards.
+//! Extrapolating backwards is the same calculation on the reversed sequence.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<Vec<i64>> {
+    input.lines().map(|line| line.iter_signed().collect()).collect()
+}
+
+pub fn part1(input: &[Vec<i64>]) -> i64 {
+    input.iter().map(|history| extrapolate(history.clone())).sum()
+}
+
+pub fn part2(input: &[Vec<i64>]) -> i64 {
+    input.iter().map(|history| extrapolate(history.iter().rev().copied().collect())).sum()
+}
+
+fn extrapolate(mut row: Vec<i64>) -> i64 {
+    let mut total = 0;
+
+    while row.iter().any(|&n| n != 0) {
+        total += row[row.len() - 1];
+        for i in 0..row.len() - 1 {
+            row[i] = row[i + 1] - row[i];
+        }
+        row.pop();
+    }
+
+    total
+}
@@ -0,0 +1,55 @@
+//! # Pipe Maze
+//!
+//! Follows the loop once, counting its length and accumulating its area with the
+//! [shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula). The farthest point is half
+//! the loop length. [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem) then gives
+//! the number of interior points from the area and the number of points on the boundary.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> (i32, i32) {
+    let grid = Grid::parse(input);
+    let start = grid.find(b'S').unwrap();
+
+    // Any direction from the start that connects back leads around the loop.
+    let connects = |direction: Point, pipes: &[u8]| {
+        let next = start + direction;
+        grid.contains(next) && pipes.contains(&grid[next])
+    };
+    let mut direction = if connects(UP, b"|7F") {
+        UP
+    } else if connects(DOWN, b"|LJ") {
+        DOWN
+    } else {
+        RIGHT
+    };
+
+    let mut position = start;
+    let mut steps = 0;
+    let mut area = 0;
+
+    loop {
+        let next = position + direction;
+        area += position.x * next.y - position.y * next.x;
+        position = next;
+        steps += 1;
+
+        direction = match grid[position] {
+            b'S' => break,
+            b'L' | b'7' => Point::new(direction.y, direction.x),
+            b'J' | b'F' => Point::new(-direction.y, -direction.x),
+            _ => direction,
+        };
+    }
+
+    let interior = area.abs() / 2 - steps / 2 + 1;
+    (steps / 2, interior)
+}
+
+pub fn part1(input: &(i32, i32)) -> i32 {
+    input.0
+}
+
+pub fn part2(input: &(i32, i32)) -> i32 {
+    input.1
+}
@@ -0,0 +1,56 @@
+//! # Cosmic Expansion
+//!
+//! Rows and columns are independent so each axis is handled separately. After expansion the
+//! galaxy coordinates along an axis are sorted, then the sum of the distances between every pair
+//! is calculated in linear time. The coordinate at index `i` is at least as large as the `i`
+//! before it, so its distance to all of them is `i` times its value minus their running sum.
+use crate::util::grid::*;
+
+pub struct Input {
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let grid = Grid::parse(input);
+    let rows = grid.rows().map(|row| row.iter().filter(|&&b| b == b'#').count()).collect();
+    let columns = grid.columns().map(|column| column.filter(|&&b| b == b'#').count()).collect();
+    Input { rows, columns }
+}
+
+pub fn part1(input: &Input) -> usize {
+    distances(input, 2)
+}
+
+pub fn part2(input: &Input) -> usize {
+    distances(input, 1_000_000)
+}
+
+/// Sum of the shortest paths between every pair of galaxies when each empty row and column is
+/// replaced by `factor` copies.
+pub fn distances(input: &Input, factor: usize) -> usize {
+    axis(&input.rows, factor) + axis(&input.columns, factor)
+}
+
+/// Galaxies per row (or column) are already in sorted order.
+fn axis(counts: &[usize], factor: usize) -> usize {
+    let mut position = 0;
+    let mut index = 0;
+    let mut sum = 0;
+    let mut result = 0;
+
+    for &count in counts {
+        if count == 0 {
+            position += factor;
+            continue;
+        }
+        for _ in 0..count {
+            result += position * index - sum;
+            sum += position;
+            index += 1;
+        }
+        position += 1;
+    }
+
+    result
+}
@@ -0,0 +1,75 @@
+//! # Hot Springs
+//!
+//! Counts arrangements with bottom-up dynamic programming. `ways[g][i]` is the number of ways
+//! to place groups `g..` into the springs starting at index `i`. Each spring can either be
+//! skipped if it's not damaged, or start the next group if the following `size` springs could all
+//! be damaged and the spring immediately after could be operational.
+//!
+//! A prefix sum of operational springs checks whether a group fits in constant time, so each
+//! line takes `O(springs * groups)`, which is fast even after unfolding for part two.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<(&str, Vec<usize>)> {
+    input
+        .lines()
+        .map(|line| {
+            let (springs, groups) = line.split_once(' ').unwrap();
+            (springs, groups.iter_unsigned().collect())
+        })
+        .collect()
+}
+
+pub fn part1(input: &[(&str, Vec<usize>)]) -> u64 {
+    input.iter().map(|(springs, groups)| arrangements(springs.as_bytes(), groups)).sum()
+}
+
+pub fn part2(input: &[(&str, Vec<usize>)]) -> u64 {
+    input
+        .iter()
+        .map(|(springs, groups)| {
+            let springs = [*springs; 5].join("?");
+            let groups = groups.repeat(5);
+            arrangements(springs.as_bytes(), &groups)
+        })
+        .sum()
+}
+
+fn arrangements(springs: &[u8], groups: &[usize]) -> u64 {
+    let n = springs.len();
+
+    // `operational[i]` is the number of definitely operational springs before index `i`.
+    let mut operational = vec![0; n + 1];
+    for (i, &b) in springs.iter().enumerate() {
+        operational[i + 1] = operational[i] + (b == b'.') as usize;
+    }
+
+    // Two extra columns so that a group ending exactly at the last spring has somewhere to go.
+    let mut next = vec![0; n + 2];
+    next[n] = 1;
+    next[n + 1] = 1;
+    for i in (0..n).rev() {
+        if springs[i] == b'#' {
+            break;
+        }
+        next[i] = 1;
+    }
+
+    for &size in groups.iter().rev() {
+        let mut ways = vec![0; n + 2];
+
+        for i in (0..n).rev() {
+            if springs[i] != b'#' {
+                ways[i] = ways[i + 1];
+            }
+            let end = i + size;
+            if end <= n && operational[end] == operational[i] && (end == n || springs[end] != b'#')
+            {
+                ways[i] += next[end + 1];
+            }
+        }
+
+        next = ways;
+    }
+
+    next[0]
+}
@@ -0,0 +1,58 @@
+//! # Point of Incidence
+//!
+//! Each row and column is converted to a bitmask so that comparing two lines is a single XOR.
+//! A reflection line is valid when the total number of differing bits across every mirrored
+//! pair of lines is exactly zero for part one, or exactly one (the smudge) for part two.
+pub struct Pattern {
+    rows: Vec<u32>,
+    columns: Vec<u32>,
+}
+
+pub fn parse(input: &str) -> Vec<Pattern> {
+    input
+        .split("\n\n")
+        .map(|block| {
+            let lines: Vec<_> = block.lines().map(str::as_bytes).collect();
+            let mut rows = vec![0; lines.len()];
+            let mut columns = vec![0; lines[0].len()];
+
+            for (y, line) in lines.iter().enumerate() {
+                for (x, &b) in line.iter().enumerate() {
+                    let bit = (b == b'#') as u32;
+                    rows[y] = (rows[y] << 1) | bit;
+                    columns[x] = (columns[x] << 1) | bit;
+                }
+            }
+
+            Pattern { rows, columns }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Pattern]) -> usize {
+    summarize(input, 0)
+}
+
+pub fn part2(input: &[Pattern]) -> usize {
+    summarize(input, 1)
+}
+
+fn summarize(input: &[Pattern], smudges: u32) -> usize {
+    input
+        .iter()
+        .map(|pattern| {
+            reflection(&pattern.columns, smudges)
+                .or_else(|| reflection(&pattern.rows, smudges).map(|row| 100 * row))
+                .unwrap()
+        })
+        .sum()
+}
+
+/// Returns the number of lines before the reflection.
+fn reflection(lines: &[u32], smudges: u32) -> Option<usize> {
+    (1..lines.len()).find(|&i| {
+        let before = lines[..i].iter().rev();
+        let after = lines[i..].iter();
+        before.zip(after).map(|(a, b)| (a ^ b).count_ones()).sum::<u32>() == smudges
+    })
+}
@@ -0,0 +1,80 @@
+//! # Parabolic Reflector Dish
+//!
+//! Tilting processes each lane (a column when tilting north or south, a row when tilting east
+//! or west) starting from the edge that rocks roll towards, remembering the next free position.
+//!
+//! A billion spin cycles is far too many to simulate, however the platform quickly settles into
+//! a loop. [`Cycle`] remembers the spin each state was first seen on. Once a state repeats, the
+//! state after a billion spins is found directly from the loop length.
+//!
+//! [`Cycle`]: crate::util::cycle::Cycle
+use crate::util::cycle::*;
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(input: &Grid<u8>) -> i32 {
+    let mut grid = input.clone();
+    tilt(&mut grid, UP);
+    load(&grid)
+}
+
+pub fn part2(input: &Grid<u8>) -> i32 {
+    let spin = |grid: &Grid<u8>| {
+        let mut next = grid.clone();
+        for direction in [UP, LEFT, DOWN, RIGHT] {
+            tilt(&mut next, direction);
+        }
+        next
+    };
+
+    let cycle = Cycle::detect(input.clone(), spin);
+    load(cycle.nth(1_000_000_000))
+}
+
+fn tilt(grid: &mut Grid<u8>, direction: Point) {
+    let (width, height) = (grid.width, grid.height);
+
+    // Each lane starts at the edge the rocks roll towards, stepping away from it.
+    let (starts, step): (Vec<_>, _) = match direction {
+        UP => ((0..width).map(|x| Point::new(x, 0)).collect(), DOWN),
+        DOWN => ((0..width).map(|x| Point::new(x, height - 1)).collect(), UP),
+        LEFT => ((0..height).map(|y| Point::new(0, y)).collect(), RIGHT),
+        _ => ((0..height).map(|y| Point::new(width - 1, y)).collect(), LEFT),
+    };
+
+    for start in starts {
+        let mut free = start;
+        let mut position = start;
+
+        while grid.contains(position) {
+            match grid[position] {
+                b'#' => free = position + step,
+                b'O' => {
+                    grid[position] = b'.';
+                    grid[free] = b'O';
+                    free += step;
+                }
+                _ => (),
+            }
+            position += step;
+        }
+    }
+}
+
+fn load(grid: &Grid<u8>) -> i32 {
+    let mut total = 0;
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            if grid[Point::new(x, y)] == b'O' {
+                total += grid.height - y;
+            }
+        }
+    }
+
+    total
+}
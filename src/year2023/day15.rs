@@ -0,0 +1,45 @@
+//! # Lens Library
+//!
+//! Implements the HASHMAP procedure directly. Each box is a small vector of labelled lenses,
+//! which is faster than a real hash map for the handful of lenses in each box.
+pub fn parse(input: &str) -> Vec<&str> {
+    input.trim().split(',').collect()
+}
+
+pub fn part1(input: &[&str]) -> usize {
+    input.iter().map(|step| hash(step)).sum()
+}
+
+pub fn part2(input: &[&str]) -> usize {
+    let mut boxes: Vec<Vec<(&str, usize)>> = vec![Vec::new(); 256];
+
+    for step in input {
+        if let Some(label) = step.strip_suffix('-') {
+            boxes[hash(label)].retain(|&(other, _)| other != label);
+        } else {
+            let (label, focal) = step.split_once('=').unwrap();
+            let focal = focal.parse().unwrap();
+            let lenses = &mut boxes[hash(label)];
+
+            match lenses.iter_mut().find(|(other, _)| *other == label) {
+                Some(lens) => lens.1 = focal,
+                None => lenses.push((label, focal)),
+            }
+        }
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(index, lenses)| {
+            lenses
+                .iter()
+                .enumerate()
+                .map(move |(slot, &(_, focal))| (index + 1) * (slot + 1) * focal)
+        })
+        .sum()
+}
+
+fn hash(s: &str) -> usize {
+    s.bytes().fold(0, |acc, b| ((acc + b as usize) * 17) % 256)
+}
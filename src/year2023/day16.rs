@@ -0,0 +1,82 @@
+//! # The Floor Will Be Lava
+//!
+//! Follows each beam with a stack, recording the directions that have already passed through
+//! every tile as a bitmask. A beam that enters a tile in a direction already seen is a loop
+//! so can be dropped. Part two tries every entry point along the edges in parallel.
+use crate::util::grid::*;
+use crate::util::point::*;
+use crate::util::thread::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(input: &Grid<u8>) -> usize {
+    energized(input, Point::new(0, 0), RIGHT)
+}
+
+pub fn part2(input: &Grid<u8>) -> usize {
+    let (width, height) = (input.width, input.height);
+    let mut starts = Vec::new();
+
+    for x in 0..width {
+        starts.push((Point::new(x, 0), DOWN));
+        starts.push((Point::new(x, height - 1), UP));
+    }
+    for y in 0..height {
+        starts.push((Point::new(0, y), RIGHT));
+        starts.push((Point::new(width - 1, y), LEFT));
+    }
+
+    let best = AtomicUsize::new(0);
+    spawn_batches(starts, |batch| {
+        let most = batch.iter().map(|&(start, direction)| energized(input, start, direction));
+        best.fetch_max(most.max().unwrap_or(0), Ordering::Relaxed);
+    });
+    best.into_inner()
+}
+
+fn energized(grid: &Grid<u8>, start: Point, direction: Point) -> usize {
+    let mut seen = grid.same_size_with(0_u8);
+    let mut todo = vec![(start, direction)];
+
+    while let Some((position, direction)) = todo.pop() {
+        if !grid.contains(position) {
+            continue;
+        }
+
+        let mask = bit(direction);
+        if seen[position] & mask != 0 {
+            continue;
+        }
+        seen[position] |= mask;
+
+        let mut next = |direction: Point| todo.push((position + direction, direction));
+
+        match grid[position] {
+            b'/' => next(Point::new(-direction.y, -direction.x)),
+            b'\\' => next(Point::new(direction.y, direction.x)),
+            b'|' if direction.x != 0 => {
+                next(UP);
+                next(DOWN);
+            }
+            b'-' if direction.y != 0 => {
+                next(LEFT);
+                next(RIGHT);
+            }
+            _ => next(direction),
+        }
+    }
+
+    seen.bytes.iter().filter(|&&b| b != 0).count()
+}
+
+fn bit(direction: Point) -> u8 {
+    match direction {
+        UP => 1,
+        DOWN => 2,
+        LEFT => 4,
+        _ => 8,
+    }
+}
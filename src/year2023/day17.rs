@@ -0,0 +1,64 @@
+//! # Clumsy Crucible
+//!
+//! [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm) where the state
+//! is a position plus whether the crucible arrived moving horizontally or vertically. Instead of
+//! tracking the number of steps taken in a straight line, each move turns 90 degrees then
+//! travels every allowed distance at once. This keeps the state space small.
+use crate::util::grid::*;
+use crate::util::heap::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse_with(input, |b| b - b'0')
+}
+
+pub fn part1(input: &Grid<u8>) -> u32 {
+    dijkstra(input, 1, 3)
+}
+
+pub fn part2(input: &Grid<u8>) -> u32 {
+    dijkstra(input, 4, 10)
+}
+
+fn dijkstra(grid: &Grid<u8>, lower: i32, upper: i32) -> u32 {
+    let end = Point::new(grid.width - 1, grid.height - 1);
+    // Index 0 is arrived horizontally, index 1 vertically.
+    let mut cost = grid.same_size_with([u32::MAX; 2]);
+    let mut todo = MinHeap::new();
+
+    cost[ORIGIN] = [0, 0];
+    todo.push(0, (ORIGIN, 0));
+    todo.push(0, (ORIGIN, 1));
+
+    while let Some((heat, (position, axis))) = todo.pop() {
+        if position == end {
+            return heat;
+        }
+        if heat > cost[position][axis] {
+            continue;
+        }
+
+        // Turn onto the other axis.
+        let directions = if axis == 0 { [UP, DOWN] } else { [LEFT, RIGHT] };
+
+        for direction in directions {
+            let mut next = position;
+            let mut next_heat = heat;
+
+            for steps in 1..=upper {
+                next += direction;
+                if !grid.contains(next) {
+                    break;
+                }
+                next_heat += grid[next] as u32;
+
+                if steps >= lower && next_heat < cost[next][1 - axis] {
+                    cost[next][1 - axis] = next_heat;
+                    todo.push(next_heat, (next, 1 - axis));
+                }
+            }
+        }
+    }
+
+    unreachable!()
+}
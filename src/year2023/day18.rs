@@ -0,0 +1,44 @@
+//! # Lavaduct Lagoon
+//!
+//! The [shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula) gives the area of the
+//! polygon traced by the centre of each trench cube. Each cube on the boundary contributes an
+//! extra half a unit outside this polygon, plus one more unit in total for the four net outer
+//! corners, which is equivalent to [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem).
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(input: &[&str]) -> i64 {
+    lagoon(input.iter().map(|line| {
+        let mut tokens = line.split_ascii_whitespace();
+        let direction = Point::from(tokens.next().unwrap().as_bytes()[0]);
+        let amount = tokens.next().unwrap().parse().unwrap();
+        (direction, amount)
+    }))
+}
+
+pub fn part2(input: &[&str]) -> i64 {
+    lagoon(input.iter().map(|line| {
+        let hex = &line[line.len() - 7..line.len() - 1];
+        let direction = [RIGHT, DOWN, LEFT, UP][(hex.as_bytes()[5] - b'0') as usize];
+        let amount = i64::from_str_radix(&hex[..5], 16).unwrap();
+        (direction, amount)
+    }))
+}
+
+fn lagoon(plan: impl Iterator<Item = (Point, i64)>) -> i64 {
+    let (mut x, mut y) = (0, 0);
+    let mut area = 0;
+    let mut perimeter = 0;
+
+    for (direction, amount) in plan {
+        let (nx, ny) = (x + direction.x as i64 * amount, y + direction.y as i64 * amount);
+        area += x * ny - y * nx;
+        perimeter += amount;
+        (x, y) = (nx, ny);
+    }
+
+    area.abs() / 2 + perimeter / 2 + 1
+}
@@ -0,0 +1,126 @@
+//! # Aplenty
+//!
+//! Part two sends ranges of ratings through the workflows instead of individual parts. Each
+//! condition splits the current range in two, the matching piece follows the rule to its
+//! target workflow and the remainder continues on to the next rule.
+use crate::util::hash::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub struct Rule<'a> {
+    /// `None` for the final fallback rule of a workflow.
+    condition: Option<(usize, bool, u32)>,
+    target: &'a str,
+}
+
+pub struct Input<'a> {
+    workflows: FastMap<&'a str, Vec<Rule<'a>>>,
+    parts: Vec<[u32; 4]>,
+}
+
+pub fn parse(input: &str) -> Input<'_> {
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+
+    let workflows = prefix
+        .lines()
+        .map(|line| {
+            let (name, rules) = line[..line.len() - 1].split_once('{').unwrap();
+            let rules = rules
+                .split(',')
+                .map(|rule| match rule.split_once(':') {
+                    Some((condition, target)) => {
+                        let bytes = condition.as_bytes();
+                        let category = match bytes[0] {
+                            b'x' => 0,
+                            b'm' => 1,
+                            b'a' => 2,
+                            _ => 3,
+                        };
+                        let less = bytes[1] == b'<';
+                        let value = condition[2..].parse().unwrap();
+                        Rule { condition: Some((category, less, value)), target }
+                    }
+                    None => Rule { condition: None, target: rule },
+                })
+                .collect();
+            (name, rules)
+        })
+        .collect();
+
+    let parts = suffix.iter_unsigned().chunk::<4>().collect();
+    Input { workflows, parts }
+}
+
+pub fn part1(input: &Input<'_>) -> u32 {
+    input
+        .parts
+        .iter()
+        .filter(|part| accepted(input, part))
+        .map(|part| part.iter().sum::<u32>())
+        .sum()
+}
+
+pub fn part2(input: &Input<'_>) -> u64 {
+    let mut todo = vec![("in", [(1, 4001); 4])];
+    let mut total = 0;
+
+    while let Some((name, mut ranges)) = todo.pop() {
+        match name {
+            "A" => {
+                total += ranges.iter().map(|&(start, end)| (end - start) as u64).product::<u64>();
+                continue;
+            }
+            "R" => continue,
+            _ => (),
+        }
+
+        for rule in &input.workflows[name] {
+            let Some((category, less, value)) = rule.condition else {
+                todo.push((rule.target, ranges));
+                break;
+            };
+
+            // Half open ranges, split into the piece that matches and the piece that doesn't.
+            let (start, end) = ranges[category];
+            let (matched, rest) = if less {
+                ((start, end.min(value)), (start.max(value), end))
+            } else {
+                ((start.max(value + 1), end), (start, end.min(value + 1)))
+            };
+
+            if matched.0 < matched.1 {
+                let mut next = ranges;
+                next[category] = matched;
+                todo.push((rule.target, next));
+            }
+            if rest.0 >= rest.1 {
+                break;
+            }
+            ranges[category] = rest;
+        }
+    }
+
+    total
+}
+
+fn accepted(input: &Input<'_>, part: &[u32; 4]) -> bool {
+    let mut name = "in";
+
+    loop {
+        match name {
+            "A" => return true,
+            "R" => return false,
+            _ => (),
+        }
+
+        name = input.workflows[name]
+            .iter()
+            .find(|rule| match rule.condition {
+                Some((category, true, value)) => part[category] < value,
+                Some((category, false, value)) => part[category] > value,
+                None => true,
+            })
+            .unwrap()
+            .target;
+    }
+}
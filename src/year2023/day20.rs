@@ -0,0 +1,159 @@
+//! # Pulse Propagation
+//!
+//! Simulates the modules with a queue of pulses. Module names are converted to indices and the
+//! memory of each conjunction is a bitmask of the inputs that last sent a high pulse.
+//!
+//! Part two relies on the structure of the input. `rx` is fed by a single conjunction, which
+//! in turn is fed by several independent counters. Each counter sends a high pulse on a fixed
+//! period, so the answer is the least common multiple of the first press each one fires.
+use crate::util::hash::*;
+use crate::util::math::*;
+use std::collections::VecDeque;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    Output,
+}
+
+pub struct Input<'a> {
+    names: Vec<&'a str>,
+    kinds: Vec<Kind>,
+    outputs: Vec<Vec<usize>>,
+    inputs: Vec<u128>,
+}
+
+pub fn parse(input: &str) -> Input<'_> {
+    let mut indices = FastMap::new();
+    let mut names = Vec::new();
+    let mut index_of = |name| {
+        *indices.entry(name).or_insert_with(|| {
+            names.push(name);
+            names.len() - 1
+        })
+    };
+
+    let mut modules = Vec::new();
+    for line in input.lines() {
+        let (prefix, suffix) = line.split_once(" -> ").unwrap();
+        let (kind, name) = match prefix.as_bytes()[0] {
+            b'%' => (Kind::FlipFlop, &prefix[1..]),
+            b'&' => (Kind::Conjunction, &prefix[1..]),
+            _ => (Kind::Broadcaster, prefix),
+        };
+        let from = index_of(name);
+        let to: Vec<_> = suffix.split(", ").map(&mut index_of).collect();
+        modules.push((from, kind, to));
+    }
+
+    let size = names.len();
+    let mut kinds = vec![Kind::Output; size];
+    let mut outputs = vec![Vec::new(); size];
+    let mut inputs = vec![0; size];
+
+    for (from, kind, to) in modules {
+        for &next in &to {
+            inputs[next] |= 1 << from;
+        }
+        kinds[from] = kind;
+        outputs[from] = to;
+    }
+
+    Input { names, kinds, outputs, inputs }
+}
+
+pub fn part1(input: &Input<'_>) -> u64 {
+    let mut state = State::new(input);
+    let mut low = 0;
+    let mut high = 0;
+
+    for _ in 0..1000 {
+        state.press(input, |_, _, pulse| {
+            if pulse {
+                high += 1;
+            } else {
+                low += 1;
+            }
+        });
+    }
+
+    low * high
+}
+
+pub fn part2(input: &Input<'_>) -> u64 {
+    let rx = input.names.iter().position(|&name| name == "rx").unwrap();
+    let feed = (0..input.names.len()).find(|&i| input.outputs[i].contains(&rx)).unwrap();
+    let counters: Vec<_> =
+        (0..input.names.len()).filter(|&i| input.inputs[feed] & (1 << i) != 0).collect();
+
+    let mut state = State::new(input);
+    let mut periods = vec![0; counters.len()];
+
+    for presses in 1.. {
+        state.press(input, |from, to, pulse| {
+            if pulse && to == feed {
+                let index = counters.iter().position(|&i| i == from).unwrap();
+                if periods[index] == 0 {
+                    periods[index] = presses;
+                }
+            }
+        });
+
+        if periods.iter().all(|&period| period > 0) {
+            break;
+        }
+    }
+
+    periods.into_iter().fold(1, u64::lcm)
+}
+
+struct State {
+    on: Vec<bool>,
+    memory: Vec<u128>,
+    todo: VecDeque<(usize, usize, bool)>,
+    broadcaster: usize,
+}
+
+impl State {
+    fn new(input: &Input<'_>) -> Self {
+        let size = input.names.len();
+        let broadcaster = input.kinds.iter().position(|&kind| kind == Kind::Broadcaster).unwrap();
+        State { on: vec![false; size], memory: vec![0; size], todo: VecDeque::new(), broadcaster }
+    }
+
+    /// Presses the button once, calling `observe` with every `(from, to, pulse)` sent.
+    fn press(&mut self, input: &Input<'_>, mut observe: impl FnMut(usize, usize, bool)) {
+        // The button itself is not a module, so it's treated as sending from the broadcaster.
+        observe(self.broadcaster, self.broadcaster, false);
+        self.todo.push_back((self.broadcaster, self.broadcaster, false));
+
+        while let Some((from, to, pulse)) = self.todo.pop_front() {
+            let next = match input.kinds[to] {
+                Kind::Broadcaster => pulse,
+                Kind::FlipFlop => {
+                    if pulse {
+                        continue;
+                    }
+                    self.on[to] = !self.on[to];
+                    self.on[to]
+                }
+                Kind::Conjunction => {
+                    if pulse {
+                        self.memory[to] |= 1 << from;
+                    } else {
+                        self.memory[to] &= !(1 << from);
+                    }
+                    self.memory[to] != input.inputs[to]
+                }
+                Kind::Output => continue,
+            };
+
+            for &output in &input.outputs[to] {
+                observe(to, output, next);
+                self.todo.push_back((to, output, next));
+            }
+        }
+    }
+}
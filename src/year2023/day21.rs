@@ -0,0 +1,65 @@
+//! # Step Counter
+//!
+//! A cell can be reached in exactly `n` steps if its shortest distance is at most `n` and has
+//! the same parity, since the elf can always step back and forth. Distances are found with a
+//! single BFS over a 5 by 5 tiling of the garden centered on the start.
+//!
+//! Part two relies on the structure of the input. The start is in the exact center of a square
+//! garden with clear lines to every edge, and `26501365 = 202300 * 131 + 65`. The number of
+//! reachable plots after `65 + 131 * x` steps is then a quadratic in `x`, which is fitted from
+//! the first three values then extrapolated.
+use crate::util::grid::*;
+use crate::util::point::*;
+use std::collections::VecDeque;
+
+pub struct Input {
+    size: usize,
+    distances: Vec<u32>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let grid = Grid::parse(input);
+    let garden = grid.wrapped();
+    let start = grid.find(b'S').unwrap();
+    let size = grid.width;
+
+    let tiled = Point::new(2 * size, 2 * size);
+    let mut distance = Grid::new(5 * size, 5 * size, u32::MAX);
+    let mut todo = VecDeque::new();
+    let mut distances = Vec::new();
+
+    distance[start + tiled] = 0;
+    todo.push_back(start + tiled);
+
+    while let Some(position) = todo.pop_front() {
+        let cost = distance[position];
+        distances.push(cost);
+
+        for next in distance.orthogonal(position) {
+            if garden[next] != b'#' && distance[next] == u32::MAX {
+                distance[next] = cost + 1;
+                todo.push_back(next);
+            }
+        }
+    }
+
+    Input { size: size as usize, distances }
+}
+
+pub fn part1(input: &Input) -> usize {
+    plots(input, 64)
+}
+
+pub fn part2(input: &Input) -> usize {
+    let steps = 26501365;
+    let (x, remainder) = (steps / input.size, steps % input.size);
+    let [a, b, c] = [0, 1, 2].map(|i| plots(input, (remainder + i * input.size) as u32));
+
+    // Newton's forward difference formula for a quadratic.
+    a + x * (b - a) + x * (x - 1) / 2 * (c + a - 2 * b)
+}
+
+/// Number of garden plots reachable in exactly `steps`, up to twice the garden size plus half.
+pub fn plots(input: &Input, steps: u32) -> usize {
+    input.distances.iter().filter(|&&d| d <= steps && d % 2 == steps % 2).count()
+}
@@ -0,0 +1,70 @@
+//! # Sand Slabs
+//!
+//! Bricks are dropped in order of their lowest `z` coordinate onto a 10 by 10 height map that
+//! remembers the top brick in each column, which finds the bricks supporting each one.
+//!
+//! For part two, a brick falls if every brick supporting it falls. This is exactly the
+//! [dominator](https://en.wikipedia.org/wiki/Dominator_(graph_theory)) relationship with the
+//! ground as the root. The immediate dominator of each brick is the lowest common ancestor of its
+//! supporters, so the number of bricks that fall when one is removed is the sum of every brick's
+//! depth in the dominator tree.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> (usize, usize) {
+    let mut bricks: Vec<[usize; 6]> = input.iter_unsigned().chunk::<6>().collect();
+    bricks.sort_unstable_by_key(|brick| brick[2]);
+
+    // Index 0 is the ground. Each column stores the height of its top and the brick there.
+    let mut heights = [(0, 0); 100];
+    let mut parent = vec![0; bricks.len() + 1];
+    let mut depth = vec![0; bricks.len() + 1];
+    let mut unsafe_bricks = vec![false; bricks.len() + 1];
+
+    for (i, &[x1, y1, z1, x2, y2, z2]) in bricks.iter().enumerate() {
+        let id = i + 1;
+        let columns: Vec<_> = (y1..=y2).flat_map(|y| (x1..=x2).map(move |x| 10 * y + x)).collect();
+
+        let top = columns.iter().map(|&c| heights[c].0).max().unwrap();
+        let mut supporters: Vec<_> =
+            columns.iter().filter(|&&c| heights[c].0 == top).map(|&c| heights[c].1).collect();
+        supporters.sort_unstable();
+        supporters.dedup();
+
+        if supporters.len() == 1 {
+            unsafe_bricks[supporters[0]] = true;
+        }
+
+        // Lowest common ancestor of every supporter in the dominator tree.
+        let mut dominator = supporters[0];
+        for &other in &supporters[1..] {
+            let mut other = other;
+            while dominator != other {
+                if depth[dominator] < depth[other] {
+                    other = parent[other];
+                } else {
+                    dominator = parent[dominator];
+                }
+            }
+        }
+
+        parent[id] = dominator;
+        depth[id] = depth[dominator] + 1;
+
+        let height = top + z2 - z1 + 1;
+        columns.iter().for_each(|&c| heights[c] = (height, id));
+    }
+
+    let safe = bricks.len() - unsafe_bricks[1..].iter().filter(|&&b| b).count();
+    // Depth counts the ground, which can't be removed.
+    let falling = depth[1..].iter().map(|&d| d - 1).sum();
+    (safe, falling)
+}
+
+pub fn part1(input: &(usize, usize)) -> usize {
+    input.0
+}
+
+pub fn part2(input: &(usize, usize)) -> usize {
+    input.1
+}
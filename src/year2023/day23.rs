@@ -0,0 +1,100 @@
+//! # A Long Walk
+//!
+//! Finding the longest path is NP-hard in general, however the maze is mostly long corridors
+//! joined by a few dozen junctions. The grid is first compressed into a graph of junctions with
+//! weighted edges, then a depth first search tries every path, using a bitmask of visited
+//! junctions.
+//!
+//! For part one an edge can only be followed if every slope along it points the same way as the
+//! direction of travel. Part two ignores slopes so every edge is bidirectional.
+use crate::util::grid::*;
+use crate::util::hash::*;
+use crate::util::point::*;
+
+pub struct Input {
+    start: usize,
+    end: usize,
+    /// Each edge is `(to, steps, downhill)`.
+    edges: Vec<Vec<(usize, u32, bool)>>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let grid = Grid::parse(input);
+    let start = Point::new(1, 0);
+    let end = Point::new(grid.width - 2, grid.height - 1);
+
+    let open = |point: Point| grid.contains(point) && grid[point] != b'#';
+    let mut junctions = FastMap::new();
+    junctions.insert(start, 0);
+    junctions.insert(end, 1);
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            if open(point) && ORTHOGONAL.iter().filter(|&&o| open(point + o)).count() >= 3 {
+                junctions.insert(point, junctions.len());
+            }
+        }
+    }
+
+    let mut edges = vec![Vec::new(); junctions.len()];
+
+    for (&from, &index) in &junctions {
+        for direction in ORTHOGONAL {
+            if !open(from + direction) {
+                continue;
+            }
+
+            let mut previous = from;
+            let mut position = from + direction;
+            let mut steps = 1;
+            let mut downhill = true;
+
+            loop {
+                if let Some(&to) = junctions.get(&position) {
+                    edges[index].push((to, steps, downhill));
+                    break;
+                }
+
+                let step = position - previous;
+                if grid[position] != b'.' && Point::from(grid[position]) != step {
+                    downhill = false;
+                }
+
+                // Corridors have exactly one way forward, otherwise this is a dead end.
+                let Some(next) = ORTHOGONAL
+                    .map(|o| position + o)
+                    .into_iter()
+                    .find(|&n| n != previous && open(n))
+                else {
+                    break;
+                };
+                previous = position;
+                position = next;
+                steps += 1;
+            }
+        }
+    }
+
+    Input { start: 0, end: 1, edges }
+}
+
+pub fn part1(input: &Input) -> u32 {
+    dfs(input, input.start, 1 << input.start, 0, true).unwrap()
+}
+
+pub fn part2(input: &Input) -> u32 {
+    dfs(input, input.start, 1 << input.start, 0, false).unwrap()
+}
+
+fn dfs(input: &Input, from: usize, seen: u64, steps: u32, slopes: bool) -> Option<u32> {
+    if from == input.end {
+        return Some(steps);
+    }
+
+    input.edges[from]
+        .iter()
+        .filter(|&&(to, _, downhill)| seen & (1 << to) == 0 && (downhill || !slopes))
+        .filter_map(|&(to, cost, _)| dfs(input, to, seen | (1 << to), steps + cost, slopes))
+        .max()
+}
@@ -0,0 +1,101 @@
+//! # Never Tell Me The Odds
+//!
+//! Part one solves each pair of 2D lines for their intersection using
+//! [Cramer's rule](https://en.wikipedia.org/wiki/Cramer%27s_rule), keeping everything in
+//! integers by comparing numerators against the scaled bounds.
+//!
+//! Part two switches to the reference frame of the first hailstone, so that the rock must pass
+//! through the origin. The rock's path then lies in the plane containing the origin and the path
+//! of the second hailstone, and also in the plane containing the origin and the path of the
+//! third hailstone. The direction of the rock is the intersection of these planes, the cross
+//! product of their normals. Dividing by the greatest common divisor of its components keeps the
+//! numbers small enough for `i128`.
+//!
+//! Knowing the direction, the times that the second and third hailstones are hit follow from
+//! requiring their position to be parallel to it. Two collisions at known times and places
+//! then give the rock's velocity and starting position exactly.
+use crate::util::iter::*;
+use crate::util::math::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<[i64; 6]> {
+    input.iter_signed().chunk::<6>().collect()
+}
+
+pub fn part1(input: &[[i64; 6]]) -> usize {
+    intersections(input, 200_000_000_000_000, 400_000_000_000_000)
+}
+
+pub fn part2(input: &[[i64; 6]]) -> i128 {
+    let [origin, first, second] = [input[0], input[1], input[2]].map(|h| h.map(i128::from));
+    let relative = |h: [i128; 6]| {
+        let position = [h[0] - origin[0], h[1] - origin[1], h[2] - origin[2]];
+        let velocity = [h[3] - origin[3], h[4] - origin[4], h[5] - origin[5]];
+        (position, velocity)
+    };
+    let (p1, v1) = relative(first);
+    let (p2, v2) = relative(second);
+
+    let direction = cross(cross(p1, v1), cross(p2, v2));
+    let divisor = direction.iter().fold(0, |acc, &n| acc.gcd(n.abs()));
+    let direction = direction.map(|n| n / divisor);
+
+    // Solve (p + t * v) × d = 0 for time t, using any component with a non-zero denominator.
+    let time = |p, v| {
+        let (a, b) = (cross(p, direction), cross(v, direction));
+        let k = (0..3).find(|&k| b[k] != 0).unwrap();
+        -a[k] / b[k]
+    };
+    let (t1, t2) = (time(p1, v1), time(p2, v2));
+
+    let q1: [i128; 3] = std::array::from_fn(|k| p1[k] + t1 * v1[k]);
+    let q2: [i128; 3] = std::array::from_fn(|k| p2[k] + t2 * v2[k]);
+    let velocity: [i128; 3] = std::array::from_fn(|k| (q2[k] - q1[k]) / (t2 - t1));
+
+    (0..3).map(|k| q1[k] - t1 * velocity[k] + origin[k]).sum()
+}
+
+/// Number of pairs of hailstones whose future paths cross inside the test area in `x` and `y`.
+pub fn intersections(input: &[[i64; 6]], lower: i64, upper: i64) -> usize {
+    let mut count = 0;
+
+    for (i, &[x1, y1, _, vx1, vy1, _]) in input.iter().enumerate() {
+        for &[x2, y2, _, vx2, vy2, _] in &input[i + 1..] {
+            let [x1, y1, vx1, vy1, x2, y2, vx2, vy2] =
+                [x1, y1, vx1, vy1, x2, y2, vx2, vy2].map(i128::from);
+
+            // Solve x1 + t * vx1 = x2 + u * vx2 (and the same for y) for times t and u.
+            let determinant = vy1 * vx2 - vx1 * vy2;
+            if determinant == 0 {
+                continue;
+            }
+
+            let (dx, dy) = (x2 - x1, y2 - y1);
+            let mut t = dy * vx2 - dx * vy2;
+            let mut u = dy * vx1 - dx * vy1;
+            let mut determinant = determinant;
+
+            // Normalize so the determinant is positive, simplifying the comparisons below.
+            if determinant < 0 {
+                (t, u, determinant) = (-t, -u, -determinant);
+            }
+            if t < 0 || u < 0 {
+                continue;
+            }
+
+            let x = x1 * determinant + t * vx1;
+            let y = y1 * determinant + t * vy1;
+            let (lower, upper) = (lower as i128 * determinant, upper as i128 * determinant);
+
+            if (lower..=upper).contains(&x) && (lower..=upper).contains(&y) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
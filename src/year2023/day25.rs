@@ -0,0 +1,105 @@
+//! # Snowverload
+//!
+//! The three wires to cut are a [minimum cut](https://en.wikipedia.org/wiki/Minimum_cut) of
+//! size three. By the [max-flow min-cut theorem](https://en.wikipedia.org/wiki/Max-flow_min-cut_theorem)
+//! two components on opposite sides of the cut have exactly three edge disjoint paths between
+//! them, found with the [Edmonds–Karp algorithm](https://en.wikipedia.org/wiki/Edmonds%E2%80%93Karp_algorithm).
+//! When a fourth path can't be found, the components still reachable from the source form one
+//! side of the cut.
+//!
+//! The farthest component from the source is very likely to be on the other side, so sinks are
+//! tried in order of decreasing distance until one has a flow of exactly three.
+use crate::util::hash::*;
+use std::collections::VecDeque;
+
+pub struct Input {
+    /// Each neighbor is `(component, edge index)`.
+    neighbors: Vec<Vec<(usize, usize)>>,
+    edges: usize,
+}
+
+pub fn parse(input: &str) -> Input {
+    let mut indices = FastMap::new();
+    let mut neighbors: Vec<Vec<(usize, usize)>> = Vec::new();
+    let mut edges = 0;
+
+    let mut index_of = |name, neighbors: &mut Vec<Vec<_>>| {
+        *indices.entry(name).or_insert_with(|| {
+            neighbors.push(Vec::new());
+            neighbors.len() - 1
+        })
+    };
+
+    for line in input.lines() {
+        let (from, others) = line.split_once(": ").unwrap();
+        let from = index_of(from, &mut neighbors);
+
+        for to in others.split_ascii_whitespace() {
+            let to = index_of(to, &mut neighbors);
+            neighbors[from].push((to, edges));
+            neighbors[to].push((from, edges));
+            edges += 1;
+        }
+    }
+
+    Input { neighbors, edges }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let size = input.neighbors.len();
+    let (order, _) = bfs(input, 0, &[]);
+
+    for &sink in order.iter().rev() {
+        // Flow along each edge, positive in the direction from the lower to the higher index.
+        let mut flow = vec![0; input.edges];
+
+        for _ in 0..3 {
+            let (_, path) = bfs(input, 0, &flow);
+            let mut current = sink;
+            while current != 0 {
+                let (previous, edge) = path[current].unwrap();
+                flow[edge] += if previous < current { 1 } else { -1 };
+                current = previous;
+            }
+        }
+
+        let (reachable, path) = bfs(input, 0, &flow);
+        if path[sink].is_none() {
+            return reachable.len() * (size - reachable.len());
+        }
+    }
+
+    unreachable!()
+}
+
+/// Breadth first search through edges with spare capacity, returning the components in the
+/// order visited and the edge used to reach each one. An empty `flow` ignores capacity.
+fn bfs(input: &Input, start: usize, flow: &[i32]) -> (Vec<usize>, Vec<Option<(usize, usize)>>) {
+    let mut order = Vec::new();
+    let mut path = vec![None; input.neighbors.len()];
+    let mut todo = VecDeque::new();
+
+    path[start] = Some((start, usize::MAX));
+    todo.push_back(start);
+
+    while let Some(current) = todo.pop_front() {
+        order.push(current);
+
+        for &(next, edge) in &input.neighbors[current] {
+            // Each undirected edge has a capacity of one in both directions.
+            let capacity = if flow.is_empty() {
+                1
+            } else {
+                let forward = if current < next { flow[edge] } else { -flow[edge] };
+                1 - forward
+            };
+
+            if capacity > 0 && path[next].is_none() {
+                path[next] = Some((current, edge));
+                todo.push_back(next);
+            }
+        }
+    }
+
+    (order, path)
+}
@@ -0,0 +1,25 @@
+//! # Historian Hysteria
+//!
+//! Part one sorts both lists then pairs them up in order. Part two counts occurrences in the
+//! right list with a map.
+use crate::util::hash::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> (Vec<u32>, Vec<u32>) {
+    let (mut left, mut right): (Vec<_>, Vec<_>) =
+        input.iter_unsigned::<u32>().chunk::<2>().map(|[l, r]| (l, r)).unzip();
+    left.sort_unstable();
+    right.sort_unstable();
+    (left, right)
+}
+
+pub fn part1((left, right): &(Vec<u32>, Vec<u32>)) -> u32 {
+    left.iter().zip(right).map(|(l, r)| l.abs_diff(*r)).sum()
+}
+
+pub fn part2((left, right): &(Vec<u32>, Vec<u32>)) -> u32 {
+    let mut counts = FastMap::with_capacity(right.len());
+    right.iter().for_each(|&r| *counts.entry(r).or_insert(0) += 1);
+    left.iter().map(|l| l * counts.get(l).unwrap_or(&0)).sum()
+}
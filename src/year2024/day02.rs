@@ -0,0 +1,31 @@
+//! # Red-Nosed Reports
+//!
+//! Part two only needs to try removing each level once, so a brute force check of every
+//! report with one level skipped is fast enough for the short reports in the input.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<Vec<i32>> {
+    input.lines().map(|line| line.iter_signed().collect()).collect()
+}
+
+pub fn part1(input: &[Vec<i32>]) -> usize {
+    input.iter().filter(|report| safe(report.iter().copied())).count()
+}
+
+pub fn part2(input: &[Vec<i32>]) -> usize {
+    input
+        .iter()
+        .filter(|report| {
+            (0..report.len()).any(|skip| {
+                let levels = report.iter().enumerate().filter(|&(i, _)| i != skip);
+                safe(levels.map(|(_, &level)| level))
+            })
+        })
+        .count()
+}
+
+/// Levels must all increase or all decrease by between 1 and 3 inclusive.
+fn safe(levels: impl Iterator<Item = i32> + Clone) -> bool {
+    let deltas = levels.clone().zip(levels.skip(1)).map(|(a, b)| b - a);
+    deltas.clone().all(|d| (1..=3).contains(&d)) || deltas.clone().all(|d| (-3..=-1).contains(&d))
+}
@@ -0,0 +1,62 @@
+//! # Mull It Over
+//!
+//! A small hand written parser steps through the memory a byte at a time. Both parts are found
+//! in the same pass, tracking whether multiplications are currently enabled for part two.
+pub fn parse(input: &str) -> (u32, u32) {
+    let memory = input.as_bytes();
+    let mut index = 0;
+    let mut enabled = true;
+    let mut part1 = 0;
+    let mut part2 = 0;
+
+    while index < memory.len() {
+        let rest = &memory[index..];
+
+        if rest.starts_with(b"do()") {
+            enabled = true;
+            index += 4;
+        } else if rest.starts_with(b"don't()") {
+            enabled = false;
+            index += 7;
+        } else if rest.starts_with(b"mul(") {
+            index += 4;
+            let Some(first) = number(memory, &mut index, b',') else { continue };
+            let Some(second) = number(memory, &mut index, b')') else { continue };
+
+            part1 += first * second;
+            if enabled {
+                part2 += first * second;
+            }
+        } else {
+            index += 1;
+        }
+    }
+
+    (part1, part2)
+}
+
+pub fn part1(input: &(u32, u32)) -> u32 {
+    input.0
+}
+
+pub fn part2(input: &(u32, u32)) -> u32 {
+    input.1
+}
+
+/// Parses 1 to 3 digits followed by `end`, leaving `index` at the first unexpected byte.
+fn number(memory: &[u8], index: &mut usize, end: u8) -> Option<u32> {
+    let mut value = 0;
+    let start = *index;
+
+    while *index < memory.len() && memory[*index].is_ascii_digit() && *index - start < 3 {
+        value = 10 * value + (memory[*index] - b'0') as u32;
+        *index += 1;
+    }
+
+    if *index == start || *index >= memory.len() || memory[*index] != end {
+        return None;
+    }
+
+    *index += 1;
+    Some(value)
+}
@@ -0,0 +1,57 @@
+//! # Ceres Search
+//!
+//! Part one looks for `X` then checks all eight directions for the rest of the word. Part two
+//! looks for an `A` in the center, then checks that each diagonal has one `M` and one `S`,
+//! which is the case when their sum equals `M + S`.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(grid: &Grid<u8>) -> usize {
+    let mut count = 0;
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            if grid[point] != b'X' {
+                continue;
+            }
+
+            count += DIAGONAL
+                .iter()
+                .filter(|&&step| {
+                    (1..4).all(|i| {
+                        let next = point + step * i;
+                        grid.contains(next) && grid[next] == b"XMAS"[i as usize]
+                    })
+                })
+                .count();
+        }
+    }
+
+    count
+}
+
+pub fn part2(grid: &Grid<u8>) -> usize {
+    let mut count = 0;
+
+    for y in 1..grid.height - 1 {
+        for x in 1..grid.width - 1 {
+            let point = Point::new(x, y);
+            if grid[point] != b'A' {
+                continue;
+            }
+
+            let corner = |dx, dy| grid[point + Point::new(dx, dy)] as u32;
+            let target = (b'M' + b'S') as u32;
+            if corner(-1, -1) + corner(1, 1) == target && corner(1, -1) + corner(-1, 1) == target {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
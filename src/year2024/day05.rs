@@ -0,0 +1,52 @@
+//! # Print Queue
+//!
+//! The rules form a total order for the pages within each update, so they can be used directly
+//! as a comparator. An update is correct if it's already sorted and part two sorts the rest.
+use crate::util::parse::*;
+use std::cmp::Ordering;
+
+pub struct Input {
+    /// `before[a][b]` is true if page `a` must be printed before page `b`.
+    before: Vec<[bool; 100]>,
+    updates: Vec<Vec<usize>>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+    let mut before = vec![[false; 100]; 100];
+
+    for line in prefix.lines() {
+        let (a, b) = line.split_once('|').unwrap();
+        before[a.unsigned::<usize>()][b.unsigned::<usize>()] = true;
+    }
+
+    let updates = suffix.lines().map(|line| line.iter_unsigned().collect()).collect();
+    Input { before, updates }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let order = |a: &usize, b: &usize| input.before[*a][*b];
+    input
+        .updates
+        .iter()
+        .filter(|update| update.is_sorted_by(order))
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
+
+pub fn part2(input: &Input) -> usize {
+    let order = |a: &usize, b: &usize| input.before[*a][*b];
+    let compare =
+        |a: &usize, b: &usize| if order(a, b) { Ordering::Less } else { Ordering::Greater };
+
+    input
+        .updates
+        .iter()
+        .filter(|update| !update.is_sorted_by(order))
+        .map(|update| {
+            let mut update = update.clone();
+            update.sort_unstable_by(compare);
+            update[update.len() / 2]
+        })
+        .sum()
+}
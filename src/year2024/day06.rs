@@ -0,0 +1,90 @@
+//! # Guard Gallivant
+//!
+//! Part one walks the guard until they leave the map. For part two, an obstruction can only
+//! change the route if it's placed somewhere on the original path, so only those positions are
+//! tried. Each candidate is checked in parallel, detecting a loop when the guard passes the same
+//! point in the same direction twice.
+use crate::util::grid::*;
+use crate::util::point::*;
+use crate::util::thread::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(grid: &Grid<u8>) -> usize {
+    path(grid).len()
+}
+
+pub fn part2(grid: &Grid<u8>) -> usize {
+    let start = grid.find(b'^').unwrap();
+    let candidates: Vec<_> = path(grid).into_iter().filter(|&point| point != start).collect();
+    let count = AtomicUsize::new(0);
+
+    spawn_batches(candidates, |batch| {
+        let mut grid = grid.clone();
+        let mut seen = grid.same_size_with(0_u8);
+
+        for obstruction in batch {
+            grid[obstruction] = b'#';
+            if loops(&grid, &mut seen, start) {
+                count.fetch_add(1, Ordering::Relaxed);
+            }
+            grid[obstruction] = b'.';
+        }
+    });
+
+    count.into_inner()
+}
+
+/// Distinct positions visited by the guard before leaving the map.
+fn path(grid: &Grid<u8>) -> Vec<Point> {
+    let mut position = grid.find(b'^').unwrap();
+    let mut direction = UP;
+    let mut visited = grid.same_size_with(false);
+    let mut path = Vec::new();
+
+    while grid.contains(position) {
+        if !visited[position] {
+            visited[position] = true;
+            path.push(position);
+        }
+
+        let next = position + direction;
+        if grid.contains(next) && grid[next] == b'#' {
+            direction = direction.clockwise();
+        } else {
+            position = next;
+        }
+    }
+
+    path
+}
+
+fn loops(grid: &Grid<u8>, seen: &mut Grid<u8>, mut position: Point) -> bool {
+    let mut direction = UP;
+    seen.bytes.fill(0);
+
+    while grid.contains(position) {
+        let bit = match direction {
+            UP => 1,
+            RIGHT => 2,
+            DOWN => 4,
+            _ => 8,
+        };
+        if seen[position] & bit != 0 {
+            return true;
+        }
+        seen[position] |= bit;
+
+        let next = position + direction;
+        if grid.contains(next) && grid[next] == b'#' {
+            direction = direction.clockwise();
+        } else {
+            position = next;
+        }
+    }
+
+    false
+}
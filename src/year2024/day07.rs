@@ -0,0 +1,42 @@
+//! # Bridge Repair
+//!
+//! Works backwards from the test value. Operators are evaluated left to right, so the last
+//! number must be removed first. Each operator is only possible if it can be undone, for example
+//! multiplication requires the value to be divisible by the number, which prunes the search
+//! heavily compared to trying every combination forwards.
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<Vec<u64>> {
+    input.lines().map(|line| line.iter_unsigned().collect()).collect()
+}
+
+pub fn part1(input: &[Vec<u64>]) -> u64 {
+    input
+        .iter()
+        .filter(|equation| valid(equation[0], &equation[1..], false))
+        .map(|equation| equation[0])
+        .sum()
+}
+
+pub fn part2(input: &[Vec<u64>]) -> u64 {
+    input
+        .iter()
+        .filter(|equation| valid(equation[0], &equation[1..], true))
+        .map(|equation| equation[0])
+        .sum()
+}
+
+fn valid(target: u64, numbers: &[u64], concat: bool) -> bool {
+    let (&last, rest) = numbers.split_last().unwrap();
+
+    if rest.is_empty() {
+        return target == last;
+    }
+
+    (target.is_multiple_of(last) && valid(target / last, rest, concat))
+        || (target > last && valid(target - last, rest, concat))
+        || (concat && {
+            let power = 10_u64.pow(last.ilog10() + 1);
+            target % power == last && target > last && valid(target / power, rest, concat)
+        })
+}
@@ -0,0 +1,64 @@
+//! # Resonant Collinearity
+//!
+//! Antennas are grouped by frequency, then every pair in each group is checked. Part one places
+//! a single antinode beyond each antenna, part two keeps stepping by the same offset until the
+//! edge of the map.
+use crate::util::grid::*;
+use crate::util::hash::*;
+use crate::util::point::*;
+
+pub struct Input {
+    grid: Grid<u8>,
+    antennas: Vec<Vec<Point>>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let grid = Grid::parse(input);
+    let mut frequencies: FastMap<u8, Vec<Point>> = FastMap::new();
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            if grid[point] != b'.' {
+                frequencies.entry(grid[point]).or_default().push(point);
+            }
+        }
+    }
+
+    Input { grid, antennas: frequencies.into_values().collect() }
+}
+
+pub fn part1(input: &Input) -> usize {
+    antinodes(input, 1, 1)
+}
+
+pub fn part2(input: &Input) -> usize {
+    antinodes(input, 0, i32::MAX)
+}
+
+/// Counts antinodes from `first` to `last` multiples of the offset away from each antenna.
+fn antinodes(input: &Input, first: i32, last: i32) -> usize {
+    let mut locations = input.grid.same_size_with(false);
+
+    for group in &input.antennas {
+        for &a in group {
+            for &b in group {
+                if a == b {
+                    continue;
+                }
+
+                let step = a - b;
+                let mut next = a + step * first;
+                let mut count = first;
+
+                while count <= last && input.grid.contains(next) {
+                    locations[next] = true;
+                    next += step;
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    locations.bytes.iter().filter(|&&b| b).count()
+}
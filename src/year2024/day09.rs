@@ -0,0 +1,82 @@
+//! # Disk Fragmenter
+//!
+//! Part one expands the disk map into individual blocks, then moves blocks from the back into
+//! gaps at the front with two pointers.
+//!
+//! Part two keeps a [`MinHeap`] of free spans for each possible size from 1 to 9, keyed by
+//! position. To move a file, the leftmost span that fits is the smallest position found among
+//! the heaps for every size at least as large as the file. Any space left over after the file is
+//! moved is pushed back onto the heap for its new smaller size.
+use crate::util::heap::*;
+
+pub fn parse(input: &str) -> Vec<usize> {
+    input.trim().bytes().map(|b| (b - b'0') as usize).collect()
+}
+
+pub fn part1(input: &[usize]) -> usize {
+    let mut blocks = Vec::new();
+
+    for (index, &size) in input.iter().enumerate() {
+        let id = if index % 2 == 0 { Some(index / 2) } else { None };
+        blocks.extend(std::iter::repeat_n(id, size));
+    }
+
+    let (mut left, mut right) = (0, blocks.len() - 1);
+
+    while left < right {
+        if blocks[left].is_some() {
+            left += 1;
+        } else if blocks[right].is_none() {
+            right -= 1;
+        } else {
+            blocks.swap(left, right);
+        }
+    }
+
+    blocks.iter().enumerate().map(|(position, id)| position * id.unwrap_or(0)).sum()
+}
+
+pub fn part2(input: &[usize]) -> usize {
+    let mut files = Vec::new();
+    let mut free: Vec<MinHeap<usize, ()>> = (0..10).map(|_| MinHeap::new()).collect();
+    let mut position = 0;
+
+    for (index, &size) in input.iter().enumerate() {
+        if index % 2 == 0 {
+            files.push((position, size));
+        } else if size > 0 {
+            free[size].push(position, ());
+        }
+        position += size;
+    }
+
+    let mut checksum = 0;
+
+    for (id, &(position, size)) in files.iter().enumerate().rev() {
+        let mut best: Option<(usize, usize)> = None;
+
+        for (span, heap) in free.iter().enumerate().skip(size) {
+            if let Some((&start, _)) = heap.peek() {
+                if start < position && best.is_none_or(|(other, _)| start < other) {
+                    best = Some((start, span));
+                }
+            }
+        }
+
+        let position = match best {
+            Some((start, span)) => {
+                free[span].pop();
+                if span > size {
+                    free[span - size].push(start + size, ());
+                }
+                start
+            }
+            None => position,
+        };
+
+        // Sum of `id * block` for every block from `position` to `position + size - 1`.
+        checksum += id * (size * position + size * (size.saturating_sub(1)) / 2);
+    }
+
+    checksum
+}
@@ -0,0 +1,54 @@
+//! # Hoof It
+//!
+//! Depth first search from every trailhead. Part one marks each peak the first time it's
+//! reached so that it's only counted once, part two counts every distinct route.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input)
+}
+
+pub fn part1(grid: &Grid<u8>) -> u32 {
+    solve(grid, true)
+}
+
+pub fn part2(grid: &Grid<u8>) -> u32 {
+    solve(grid, false)
+}
+
+fn solve(grid: &Grid<u8>, distinct: bool) -> u32 {
+    let mut seen = grid.same_size_with(usize::MAX);
+    let mut total = 0;
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let start = Point::new(x, y);
+            if grid[start] == b'0' {
+                let id = (y * grid.width + x) as usize;
+                total += dfs(grid, &mut seen, distinct, id, start);
+            }
+        }
+    }
+
+    total
+}
+
+fn dfs(grid: &Grid<u8>, seen: &mut Grid<usize>, distinct: bool, id: usize, point: Point) -> u32 {
+    if grid[point] == b'9' {
+        if distinct {
+            if seen[point] == id {
+                return 0;
+            }
+            seen[point] = id;
+        }
+        return 1;
+    }
+
+    ORTHOGONAL
+        .iter()
+        .map(|&o| point + o)
+        .filter(|&next| grid.contains(next) && grid[next] == grid[point] + 1)
+        .map(|next| dfs(grid, seen, distinct, id, next))
+        .sum()
+}
@@ -0,0 +1,48 @@
+//! # Plutonian Pebbles
+//!
+//! The order of the stones doesn't matter, only how many of each number there are. Counting
+//! stones with a map keeps the work per blink small even though the total grows exponentially.
+use crate::util::hash::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<u64> {
+    input.iter_unsigned().collect()
+}
+
+pub fn part1(input: &[u64]) -> u64 {
+    blink(input, 25)
+}
+
+pub fn part2(input: &[u64]) -> u64 {
+    blink(input, 75)
+}
+
+fn blink(input: &[u64], blinks: usize) -> u64 {
+    let mut stones: FastMap<u64, u64> = FastMap::new();
+    input.iter().for_each(|&stone| *stones.entry(stone).or_insert(0) += 1);
+
+    for _ in 0..blinks {
+        let mut next = FastMap::with_capacity(stones.len());
+        let mut add = |stone, count| *next.entry(stone).or_insert(0) += count;
+
+        for (&stone, &count) in &stones {
+            if stone == 0 {
+                add(1, count);
+                continue;
+            }
+
+            let digits = stone.ilog10() + 1;
+            if digits % 2 == 0 {
+                let power = 10_u64.pow(digits / 2);
+                add(stone / power, count);
+                add(stone % power, count);
+            } else {
+                add(stone * 2024, count);
+            }
+        }
+
+        stones = next;
+    }
+
+    stones.values().sum()
+}
@@ -0,0 +1,65 @@
+//! # Garden Groups
+//!
+//! Each region is found with a flood fill. The perimeter is the number of plot edges that
+//! border another region or the map edge. The number of sides equals the number of corners,
+//! which are counted for each plot by checking each pair of adjacent orthogonal neighbors
+//! together with the diagonal between them.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> (usize, usize) {
+    let grid = Grid::parse(input);
+    let mut seen = grid.same_size_with(false);
+    let mut part1 = 0;
+    let mut part2 = 0;
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let start = Point::new(x, y);
+            if seen[start] {
+                continue;
+            }
+
+            let plant = grid[start];
+            let same = |point: Point| grid.contains(point) && grid[point] == plant;
+            let mut todo = vec![start];
+            let (mut area, mut perimeter, mut sides) = (0, 0, 0);
+            seen[start] = true;
+
+            while let Some(point) = todo.pop() {
+                area += 1;
+
+                for direction in ORTHOGONAL {
+                    let next = point + direction;
+                    if !same(next) {
+                        perimeter += 1;
+                    } else if !seen[next] {
+                        seen[next] = true;
+                        todo.push(next);
+                    }
+
+                    // Check the corner between this direction and the next one clockwise.
+                    let side = direction.clockwise();
+                    let (a, b) = (same(point + direction), same(point + side));
+                    let diagonal = same(point + direction + side);
+                    if (!a && !b) || (a && b && !diagonal) {
+                        sides += 1;
+                    }
+                }
+            }
+
+            part1 += area * perimeter;
+            part2 += area * sides;
+        }
+    }
+
+    (part1, part2)
+}
+
+pub fn part1(input: &(usize, usize)) -> usize {
+    input.0
+}
+
+pub fn part2(input: &(usize, usize)) -> usize {
+    input.1
+}
@@ -0,0 +1,40 @@
+//! # Claw Contraption
+//!
+//! Each machine is a pair of linear equations in the number of presses of each button, solved
+//! exactly with [Cramer's rule](https://en.wikipedia.org/wiki/Cramer%27s_rule). A machine can
+//! only be won if both solutions are non-negative integers.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub fn parse(input: &str) -> Vec<[i64; 6]> {
+    input.iter_signed().chunk::<6>().collect()
+}
+
+pub fn part1(input: &[[i64; 6]]) -> i64 {
+    input.iter().map(|&machine| tokens(machine, 0)).sum()
+}
+
+pub fn part2(input: &[[i64; 6]]) -> i64 {
+    input.iter().map(|&machine| tokens(machine, 10_000_000_000_000)).sum()
+}
+
+fn tokens([ax, ay, bx, by, px, py]: [i64; 6], offset: i64) -> i64 {
+    let (px, py) = (px + offset, py + offset);
+    let determinant = ax * by - ay * bx;
+    if determinant == 0 {
+        return 0;
+    }
+
+    let a = px * by - py * bx;
+    let b = ax * py - ay * px;
+    if a % determinant != 0 || b % determinant != 0 {
+        return 0;
+    }
+
+    let (a, b) = (a / determinant, b / determinant);
+    if a < 0 || b < 0 {
+        0
+    } else {
+        3 * a + b
+    }
+}
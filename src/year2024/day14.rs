@@ -0,0 +1,55 @@
+//! # Restroom Redoubt
+//!
+//! Part one moves each robot directly to its final position with modular arithmetic.
+//!
+//! For part two the robots form a picture when they are most tightly clustered. The horizontal
+//! positions repeat every 101 seconds and the vertical every 103, so the time with the lowest
+//! variance in `x` and the time with the lowest variance in `y` are found independently, then
+//! combined with the [Chinese remainder theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem).
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+const WIDTH: i32 = 101;
+const HEIGHT: i32 = 103;
+
+pub fn parse(input: &str) -> Vec<[i32; 4]> {
+    input.iter_signed().chunk::<4>().collect()
+}
+
+pub fn part1(input: &[[i32; 4]]) -> usize {
+    safety(input, WIDTH, HEIGHT)
+}
+
+pub fn part2(input: &[[i32; 4]]) -> i32 {
+    let best = |size: i32, axis: usize| {
+        (0..size)
+            .min_by_key(|&t| {
+                let values: Vec<_> =
+                    input.iter().map(|r| (r[axis] + t * r[axis + 2]).rem_euclid(size)).collect();
+                let mean = values.iter().sum::<i32>() / values.len() as i32;
+                values.iter().map(|v| (v - mean) * (v - mean)).sum::<i32>()
+            })
+            .unwrap()
+    };
+
+    let (tx, ty) = (best(WIDTH, 0), best(HEIGHT, 1));
+    // 51 is the inverse of 101 modulo 103.
+    tx + WIDTH * ((ty - tx) * 51).rem_euclid(HEIGHT)
+}
+
+/// Product of the number of robots in each quadrant after 100 seconds.
+pub fn safety(input: &[[i32; 4]], width: i32, height: i32) -> usize {
+    let mut quadrants = [0; 4];
+
+    for &[x, y, dx, dy] in input {
+        let x = (x + 100 * dx).rem_euclid(width);
+        let y = (y + 100 * dy).rem_euclid(height);
+
+        if x != width / 2 && y != height / 2 {
+            let index = 2 * (x > width / 2) as usize + (y > height / 2) as usize;
+            quadrants[index] += 1;
+        }
+    }
+
+    quadrants.iter().product()
+}
@@ -0,0 +1,82 @@
+//! # Warehouse Woes
+//!
+//! Each move collects every cell that would be pushed with a breadth first search from the
+//! robot. Wide boxes pushed vertically also pull in their other half. If any collected cell
+//! would hit a wall nothing moves, otherwise the cells are moved in reverse order so that each
+//! one moves into space that has already been vacated.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> (Grid<u8>, &str) {
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+    (Grid::parse(prefix), suffix)
+}
+
+pub fn part1((grid, moves): &(Grid<u8>, &str)) -> i32 {
+    simulate(grid.clone(), moves)
+}
+
+pub fn part2((grid, moves): &(Grid<u8>, &str)) -> i32 {
+    let mut wide = Grid::new(grid.width * 2, grid.height, b'.');
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let pair = match grid[Point::new(x, y)] {
+                b'#' => b"##",
+                b'O' => b"[]",
+                b'@' => b"@.",
+                _ => b"..",
+            };
+            wide[Point::new(2 * x, y)] = pair[0];
+            wide[Point::new(2 * x + 1, y)] = pair[1];
+        }
+    }
+
+    simulate(wide, moves)
+}
+
+fn simulate(mut grid: Grid<u8>, moves: &str) -> i32 {
+    let mut robot = grid.find(b'@').unwrap();
+    let mut todo = Vec::new();
+
+    'outer: for direction in moves.bytes().filter(|b| !b.is_ascii_whitespace()).map(Point::from) {
+        todo.clear();
+        todo.push(robot);
+        let mut index = 0;
+
+        while index < todo.len() {
+            let next = todo[index] + direction;
+            index += 1;
+
+            let other = match grid[next] {
+                b'#' => continue 'outer,
+                b'O' => None,
+                b'[' => Some(next + RIGHT),
+                b']' => Some(next + LEFT),
+                _ => continue,
+            };
+
+            for point in std::iter::once(next).chain(other.filter(|_| direction.y != 0)) {
+                if !todo.contains(&point) {
+                    todo.push(point);
+                }
+            }
+        }
+
+        for &point in todo.iter().rev() {
+            grid[point + direction] = grid[point];
+            grid[point] = b'.';
+        }
+        robot += direction;
+    }
+
+    let mut total = 0;
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            if matches!(grid[Point::new(x, y)], b'O' | b'[') {
+                total += 100 * y + x;
+            }
+        }
+    }
+    total
+}
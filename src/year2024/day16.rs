@@ -0,0 +1,77 @@
+//! # Reindeer Maze
+//!
+//! [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm) with the state
+//! being both position and facing direction. For part two every state on a best path is found
+//! by walking backwards from the end, following only moves whose cost exactly accounts for the
+//! difference in score between two states.
+use crate::util::grid::*;
+use crate::util::heap::*;
+use crate::util::point::*;
+
+/// Clockwise order, so that turning is adding one or three modulo four.
+const DIRECTIONS: [Point; 4] = [RIGHT, DOWN, LEFT, UP];
+
+pub fn parse(input: &str) -> (u32, usize) {
+    let grid = Grid::parse(input);
+    let start = grid.find(b'S').unwrap();
+    let end = grid.find(b'E').unwrap();
+
+    let mut cost = grid.same_size_with([u32::MAX; 4]);
+    let mut todo = MinHeap::new();
+    cost[start][0] = 0;
+    todo.push(0, (start, 0));
+
+    while let Some((score, (position, direction))) = todo.pop() {
+        if score > cost[position][direction] {
+            continue;
+        }
+
+        let forward = position + DIRECTIONS[direction];
+        let moves = [
+            (forward, direction, score + 1),
+            (position, (direction + 1) % 4, score + 1000),
+            (position, (direction + 3) % 4, score + 1000),
+        ];
+
+        for (next, direction, score) in moves {
+            if grid[next] != b'#' && score < cost[next][direction] {
+                cost[next][direction] = score;
+                todo.push(score, (next, direction));
+            }
+        }
+    }
+
+    let best = *cost[end].iter().min().unwrap();
+    let mut on_path = grid.same_size_with([false; 4]);
+    let mut todo: Vec<_> = (0..4).filter(|&d| cost[end][d] == best).map(|d| (end, d)).collect();
+    todo.iter().for_each(|&(point, d)| on_path[point][d] = true);
+
+    while let Some((position, direction)) = todo.pop() {
+        let score = cost[position][direction];
+        let backward = position - DIRECTIONS[direction];
+        let previous = [
+            (backward, direction, 1),
+            (position, (direction + 1) % 4, 1000),
+            (position, (direction + 3) % 4, 1000),
+        ];
+
+        for (point, direction, step) in previous {
+            if score >= step && cost[point][direction] == score - step && !on_path[point][direction]
+            {
+                on_path[point][direction] = true;
+                todo.push((point, direction));
+            }
+        }
+    }
+
+    let tiles = on_path.bytes.iter().filter(|states| states.iter().any(|&b| b)).count();
+    (best, tiles)
+}
+
+pub fn part1(input: &(u32, usize)) -> u32 {
+    input.0
+}
+
+pub fn part2(input: &(u32, usize)) -> usize {
+    input.1
+}
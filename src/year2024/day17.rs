@@ -0,0 +1,77 @@
+//! # Chronospatial Computer
+//!
+//! Part one runs the program directly.
+//!
+//! Part two relies on the structure of the input. Each loop of the program outputs one value
+//! depending only on the lowest few bits of register `A`, then shifts `A` right by 3 bits. This
+//! means `A` can be built 3 bits at a time, starting from the most significant, so that the
+//! output matches ever longer suffixes of the program. A depth first search tries the lowest
+//! candidates first, so the first complete match is the smallest.
+use crate::util::parse::*;
+
+pub struct Input {
+    registers: [u64; 3],
+    program: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let numbers: Vec<_> = input.iter_unsigned().collect();
+    Input { registers: [numbers[0], numbers[1], numbers[2]], program: numbers[3..].to_vec() }
+}
+
+pub fn part1(input: &Input) -> String {
+    let output = run(input.registers, &input.program);
+    output.iter().map(u64::to_string).collect::<Vec<_>>().join(",")
+}
+
+pub fn part2(input: &Input) -> u64 {
+    search(input, input.program.len(), 0).unwrap()
+}
+
+fn search(input: &Input, index: usize, a: u64) -> Option<u64> {
+    if index == 0 {
+        return Some(a);
+    }
+
+    (0..8).find_map(|bits| {
+        let next = (a << 3) | bits;
+        let [_, b, c] = input.registers;
+        let output = run([next, b, c], &input.program);
+        (output == input.program[index - 1..]).then(|| search(input, index - 1, next)).flatten()
+    })
+}
+
+fn run([mut a, mut b, mut c]: [u64; 3], program: &[u64]) -> Vec<u64> {
+    let mut ip = 0;
+    let mut output = Vec::new();
+
+    while ip + 1 < program.len() {
+        let literal = program[ip + 1];
+        let combo = match literal {
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => literal,
+        };
+
+        match program[ip] {
+            0 => a >>= combo,
+            1 => b ^= literal,
+            2 => b = combo % 8,
+            3 if a != 0 => {
+                ip = literal as usize;
+                continue;
+            }
+            3 => (),
+            4 => b ^= c,
+            5 => output.push(combo % 8),
+            6 => b = a >> combo,
+            7 => c = a >> combo,
+            _ => unreachable!(),
+        }
+
+        ip += 2;
+    }
+
+    output
+}
@@ -0,0 +1,48 @@
+//! # RAM Run
+//!
+//! Part one is a breadth first search. For part two the path stays blocked once it's blocked,
+//! so a binary search over the number of fallen bytes finds the first one that cuts off the exit.
+use crate::util::grid::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Vec<Point> {
+    input.iter_signed().chunk::<2>().map(|[x, y]| Point::new(x, y)).collect()
+}
+
+pub fn part1(input: &[Point]) -> u32 {
+    shortest(input, 70, 1024).unwrap()
+}
+
+pub fn part2(input: &[Point]) -> String {
+    blocker(input, 70)
+}
+
+/// Fewest steps from the top left to the bottom right corner of a memory space `size` wide
+/// after the first `fallen` bytes have landed.
+pub fn shortest(input: &[Point], size: i32, fallen: usize) -> Option<u32> {
+    let mut grid = Grid::new(size + 1, size + 1, false);
+    input[..fallen].iter().for_each(|&point| grid[point] = true);
+
+    let distance = grid.bfs(ORIGIN, |_, next| !grid[next]);
+    let steps = distance[Point::new(size, size)];
+    (steps != UNREACHABLE).then_some(steps)
+}
+
+/// Coordinates of the first byte that prevents the exit from being reachable.
+pub fn blocker(input: &[Point], size: i32) -> String {
+    let (mut lower, mut upper) = (0, input.len());
+
+    while lower < upper {
+        let middle = (lower + upper) / 2;
+        if shortest(input, size, middle + 1).is_some() {
+            lower = middle + 1;
+        } else {
+            upper = middle;
+        }
+    }
+
+    let Point { x, y } = input[lower];
+    format!("{x},{y}")
+}
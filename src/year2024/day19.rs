@@ -0,0 +1,39 @@
+//! # Linen Layout
+//!
+//! Dynamic programming over each design, where `ways[i]` is the number of ways to make the
+//! first `i` stripes. Towels are stored in a set so that every prefix length up to the longest
+//! towel can be checked directly.
+use crate::util::hash::*;
+
+pub fn parse(input: &str) -> Vec<u64> {
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+    let towels: FastSet<_> = prefix.split(", ").map(str::as_bytes).collect();
+    let longest = towels.iter().map(|towel| towel.len()).max().unwrap();
+
+    suffix
+        .lines()
+        .map(|design| {
+            let design = design.as_bytes();
+            let mut ways = vec![0; design.len() + 1];
+            ways[0] = 1;
+
+            for end in 1..=design.len() {
+                for start in end.saturating_sub(longest)..end {
+                    if ways[start] > 0 && towels.contains(&design[start..end]) {
+                        ways[end] += ways[start];
+                    }
+                }
+            }
+
+            ways[design.len()]
+        })
+        .collect()
+}
+
+pub fn part1(input: &[u64]) -> usize {
+    input.iter().filter(|&&ways| ways > 0).count()
+}
+
+pub fn part2(input: &[u64]) -> u64 {
+    input.iter().sum()
+}
@@ -0,0 +1,68 @@
+//! # Race Condition
+//!
+//! There's only a single track, so the distance from the start to every point on it is found by
+//! following it once. A cheat from `a` to `b` saves the difference in their track distances
+//! minus the Manhattan distance of the cheat itself. Every point within the cheat duration of
+//! each track point is checked.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<i32> {
+    let grid = Grid::parse(input);
+    let mut distance = grid.same_size_with(-1);
+    let mut position = grid.find(b'S').unwrap();
+    let mut steps = 0;
+
+    loop {
+        distance[position] = steps;
+        if grid[position] == b'E' {
+            break;
+        }
+
+        position = ORTHOGONAL
+            .map(|o| position + o)
+            .into_iter()
+            .find(|&next| grid[next] != b'#' && distance[next] == -1)
+            .unwrap();
+        steps += 1;
+    }
+
+    distance
+}
+
+pub fn part1(input: &Grid<i32>) -> usize {
+    cheats(input, 2, 100)
+}
+
+pub fn part2(input: &Grid<i32>) -> usize {
+    cheats(input, 20, 100)
+}
+
+/// Number of cheats lasting at most `duration` that save at least `threshold` picoseconds.
+pub fn cheats(distance: &Grid<i32>, duration: i32, threshold: i32) -> usize {
+    let mut count = 0;
+
+    for y in 0..distance.height {
+        for x in 0..distance.width {
+            let from = Point::new(x, y);
+            if distance[from] < 0 {
+                continue;
+            }
+
+            for dy in -duration..=duration {
+                let remaining = duration - dy.abs();
+                for dx in -remaining..=remaining {
+                    let to = from + Point::new(dx, dy);
+                    if distance.contains(to) && distance[to] >= 0 {
+                        let saved = distance[to] - distance[from] - dx.abs() - dy.abs();
+                        if saved >= threshold {
+                            count += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    count
+}
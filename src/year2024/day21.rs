@@ -0,0 +1,103 @@
+//! # Keypad Conundrum
+//!
+//! Every robot returns to `A` after each press, so the cost of a move on one keypad only
+//! depends on the start and end keys, not on anything that happened before. Working up from the
+//! human, a table holds the number of human presses needed for each move on the next keypad.
+//!
+//! The best way to move between two keys is always all horizontal moves then all vertical moves
+//! or the other way around, as mixing them costs extra presses further down the chain. Both
+//! orders are tried (unless one passes over the gap) and the cheapest is kept.
+use crate::util::point::*;
+
+type Table = [[u64; 5]; 5];
+
+/// Directional keys in the order `^ A < v >`.
+const DIRECTIONAL: [Point; 5] =
+    [Point::new(1, 0), Point::new(2, 0), Point::new(0, 1), Point::new(1, 1), Point::new(2, 1)];
+const DIRECTIONAL_GAP: Point = Point::new(0, 0);
+const NUMERIC_GAP: Point = Point::new(0, 3);
+const ACTIVATE: usize = 1;
+
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part1(input: &[&str]) -> u64 {
+    complexity(input, 2)
+}
+
+pub fn part2(input: &[&str]) -> u64 {
+    complexity(input, 25)
+}
+
+fn complexity(input: &[&str], robots: usize) -> u64 {
+    let mut table = [[1; 5]; 5];
+    for _ in 0..robots {
+        table = std::array::from_fn(|from| {
+            std::array::from_fn(|to| {
+                cheapest(DIRECTIONAL[from], DIRECTIONAL[to], DIRECTIONAL_GAP, &table)
+            })
+        });
+    }
+
+    input
+        .iter()
+        .map(|code| {
+            let mut from = numeric(b'A');
+            let mut presses = 0;
+
+            for b in code.bytes() {
+                let to = numeric(b);
+                presses += cheapest(from, to, NUMERIC_GAP, &table);
+                from = to;
+            }
+
+            let value: u64 = code[..code.len() - 1].parse().unwrap();
+            value * presses
+        })
+        .sum()
+}
+
+fn numeric(key: u8) -> Point {
+    match key {
+        b'7' => Point::new(0, 0),
+        b'8' => Point::new(1, 0),
+        b'9' => Point::new(2, 0),
+        b'4' => Point::new(0, 1),
+        b'5' => Point::new(1, 1),
+        b'6' => Point::new(2, 1),
+        b'1' => Point::new(0, 2),
+        b'2' => Point::new(1, 2),
+        b'3' => Point::new(2, 2),
+        b'0' => Point::new(1, 3),
+        _ => Point::new(2, 3),
+    }
+}
+
+/// Fewest human presses to move a robot arm from `from` to `to` then press the key.
+fn cheapest(from: Point, to: Point, gap: Point, table: &Table) -> u64 {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let horizontal = if dx < 0 { 2 } else { 4 };
+    let vertical = if dy < 0 { 0 } else { 3 };
+    let horizontal = std::iter::repeat_n(horizontal, dx.unsigned_abs() as usize);
+    let vertical = std::iter::repeat_n(vertical, dy.unsigned_abs() as usize);
+
+    let cost = |keys: &mut dyn Iterator<Item = usize>| {
+        let mut previous = ACTIVATE;
+        let mut total = 0;
+        for key in keys.chain(std::iter::once(ACTIVATE)) {
+            total += table[previous][key];
+            previous = key;
+        }
+        total
+    };
+
+    let mut best = u64::MAX;
+    if Point::new(to.x, from.y) != gap {
+        best = best.min(cost(&mut horizontal.clone().chain(vertical.clone())));
+    }
+    if Point::new(from.x, to.y) != gap {
+        best = best.min(cost(&mut vertical.chain(horizontal)));
+    }
+    best
+}
@@ -0,0 +1,56 @@
+//! # Monkey Market
+//!
+//! Each sequence of four price changes is packed into a single index from 0 to 19⁴, so the
+//! bananas for every sequence can be accumulated in a flat array instead of a map. A separate
+//! array remembers the last buyer that saw each sequence so that only the first occurrence per
+//! buyer counts. Buyers are split between threads which each keep their own totals.
+use crate::util::parse::*;
+use crate::util::thread::*;
+use std::sync::Mutex;
+
+const SEQUENCES: usize = 19 * 19 * 19 * 19;
+
+pub fn parse(input: &str) -> Vec<u64> {
+    input.iter_unsigned().collect()
+}
+
+pub fn part1(input: &[u64]) -> u64 {
+    input.iter().map(|&secret| (0..2000).fold(secret, |s, _| next(s))).sum()
+}
+
+pub fn part2(input: &[u64]) -> u32 {
+    let totals = Mutex::new(vec![0; SEQUENCES]);
+
+    spawn_batches(input.iter().copied().enumerate().collect(), |batch| {
+        let mut bananas = vec![0; SEQUENCES];
+        let mut seen = vec![usize::MAX; SEQUENCES];
+
+        for (buyer, mut secret) in batch {
+            let mut price = (secret % 10) as usize;
+            let mut sequence = 0;
+
+            for i in 0..2000 {
+                secret = next(secret);
+                let next_price = (secret % 10) as usize;
+                sequence = (sequence * 19 + 9 + next_price - price) % SEQUENCES;
+                price = next_price;
+
+                if i >= 3 && seen[sequence] != buyer {
+                    seen[sequence] = buyer;
+                    bananas[sequence] += price as u32;
+                }
+            }
+        }
+
+        let mut totals = totals.lock().unwrap();
+        totals.iter_mut().zip(bananas).for_each(|(total, b)| *total += b);
+    });
+
+    totals.into_inner().unwrap().into_iter().max().unwrap()
+}
+
+fn next(mut secret: u64) -> u64 {
+    secret = (secret ^ (secret << 6)) & 0xffffff;
+    secret = (secret ^ (secret >> 5)) & 0xffffff;
+    (secret ^ (secret << 11)) & 0xffffff
+}
@@ -0,0 +1,99 @@
+//! # LAN Party
+//!
+//! Computer names are two lowercase letters, so each is converted to an index below 26² and
+//! the network is stored as an adjacency matrix.
+//!
+//! Part one checks every pair of connected computers for a common neighbor, only counting each
+//! triangle once by requiring the indices to be increasing. Part two finds the
+//! [maximum clique](https://en.wikipedia.org/wiki/Clique_problem) with the
+//! [Bron–Kerbosch algorithm](https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm).
+const SIZE: usize = 26 * 26;
+
+pub struct Input {
+    computers: Vec<usize>,
+    connected: Vec<[bool; SIZE]>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let mut connected = vec![[false; SIZE]; SIZE];
+    let mut present = [false; SIZE];
+
+    for line in input.lines().map(str::as_bytes) {
+        let (a, b) = (index(&line[0..2]), index(&line[3..5]));
+        connected[a][b] = true;
+        connected[b][a] = true;
+        present[a] = true;
+        present[b] = true;
+    }
+
+    let computers = (0..SIZE).filter(|&i| present[i]).collect();
+    Input { computers, connected }
+}
+
+pub fn part1(input: &Input) -> usize {
+    let t = |i: usize| i / 26 == (b't' - b'a') as usize;
+    let mut count = 0;
+
+    for (n, &a) in input.computers.iter().enumerate() {
+        for &b in input.computers[n + 1..].iter().filter(|&&b| input.connected[a][b]) {
+            for &c in input.computers.iter().filter(|&&c| c > b) {
+                if input.connected[a][c] && input.connected[b][c] && (t(a) || t(b) || t(c)) {
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+pub fn part2(input: &Input) -> String {
+    let mut best = Vec::new();
+    bron_kerbosch(input, &mut Vec::new(), input.computers.clone(), Vec::new(), &mut best);
+    best.sort_unstable();
+
+    let names: Vec<_> = best
+        .iter()
+        .map(|&i| String::from_utf8(vec![b'a' + (i / 26) as u8, b'a' + (i % 26) as u8]).unwrap())
+        .collect();
+    names.join(",")
+}
+
+fn index(name: &[u8]) -> usize {
+    26 * (name[0] - b'a') as usize + (name[1] - b'a') as usize
+}
+
+fn bron_kerbosch(
+    input: &Input,
+    clique: &mut Vec<usize>,
+    mut candidates: Vec<usize>,
+    mut excluded: Vec<usize>,
+    best: &mut Vec<usize>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() && clique.len() > best.len() {
+            best.clone_from(clique);
+        }
+        return;
+    }
+
+    // Choosing the candidate with the most neighbors as the pivot skips most of the search.
+    let connected = &input.connected;
+    let pivot = *candidates
+        .iter()
+        .chain(&excluded)
+        .max_by_key(|&&p| candidates.iter().filter(|&&c| connected[p][c]).count())
+        .unwrap();
+    let choices: Vec<_> = candidates.iter().copied().filter(|&c| !connected[pivot][c]).collect();
+
+    for vertex in choices {
+        let neighbors =
+            |set: &[usize]| set.iter().copied().filter(|&n| connected[vertex][n]).collect();
+        clique.push(vertex);
+        bron_kerbosch(input, clique, neighbors(&candidates), neighbors(&excluded), best);
+        clique.pop();
+
+        candidates.retain(|&c| c != vertex);
+        excluded.push(vertex);
+    }
+}
@@ -0,0 +1,89 @@
+//! # Crossed Wires
+//!
+//! Part one evaluates every `z` wire recursively, caching the value of each wire.
+//!
+//! Part two relies on the circuit being a
+//! [ripple carry adder](https://en.wikipedia.org/wiki/Adder_(electronics)#Ripple-carry_adder).
+//! Rather than finding which wires are swapped with which, it's enough to find every wire that
+//! breaks one of the rules that gates in a correct adder must follow:
+//!
+//! * Every `z` output except the final carry comes from an XOR gate.
+//! * XOR gates either take `x` and `y` inputs or output to `z`.
+//! * The output of an XOR gate never feeds an OR gate.
+//! * The output of an AND gate always feeds an OR gate, apart from the first bit which has no
+//!   carry in.
+use crate::util::hash::*;
+
+pub struct Input<'a> {
+    wires: FastMap<&'a str, bool>,
+    gates: Vec<[&'a str; 4]>,
+}
+
+pub fn parse(input: &str) -> Input<'_> {
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+    let wires = prefix.lines().map(|line| (&line[..3], line.ends_with('1'))).collect();
+    let gates = suffix
+        .lines()
+        .map(|line| {
+            let tokens: Vec<_> = line.split_ascii_whitespace().collect();
+            [tokens[0], tokens[1], tokens[2], tokens[4]]
+        })
+        .collect();
+    Input { wires, gates }
+}
+
+pub fn part1(input: &Input<'_>) -> u64 {
+    let gates: FastMap<_, _> =
+        input.gates.iter().map(|&[a, op, b, out]| (out, [a, op, b])).collect();
+    let mut cache = input.wires.clone();
+    let mut outputs: Vec<_> = gates.keys().copied().filter(|wire| wire.starts_with('z')).collect();
+    outputs.sort_unstable();
+
+    outputs.iter().rev().fold(0, |acc, wire| (acc << 1) | evaluate(&gates, &mut cache, wire) as u64)
+}
+
+pub fn part2(input: &Input<'_>) -> String {
+    let last =
+        input.gates.iter().map(|gate| gate[3]).filter(|wire| wire.starts_with('z')).max().unwrap();
+    let feeds = |wire: &str, op: &str| {
+        input.gates.iter().any(|&[a, o, b, _]| o == op && (a == wire || b == wire))
+    };
+    let external = |wire: &str| wire.starts_with(['x', 'y', 'z']);
+    let first = |wire: &str| wire == "x00" || wire == "y00";
+
+    let mut wrong: Vec<_> = input
+        .gates
+        .iter()
+        .filter(|&&[a, op, _, out]| match op {
+            "XOR" => (!external(a) && !external(out)) || feeds(out, "OR"),
+            "AND" => out.starts_with('z') || (!first(a) && !feeds(out, "OR")),
+            _ => out.starts_with('z') && out != last,
+        })
+        .map(|gate| gate[3])
+        .collect();
+
+    wrong.sort_unstable();
+    wrong.dedup();
+    wrong.join(",")
+}
+
+fn evaluate<'a>(
+    gates: &FastMap<&'a str, [&'a str; 3]>,
+    cache: &mut FastMap<&'a str, bool>,
+    wire: &'a str,
+) -> bool {
+    if let Some(&value) = cache.get(wire) {
+        return value;
+    }
+
+    let [a, op, b] = gates[wire];
+    let (a, b) = (evaluate(gates, cache, a), evaluate(gates, cache, b));
+    let value = match op {
+        "AND" => a && b,
+        "OR" => a || b,
+        _ => a ^ b,
+    };
+
+    cache.insert(wire, value);
+    value
+}
@@ -0,0 +1,27 @@
+//! # Code Chronicle
+//!
+//! Each schematic is converted to a bitmask of its filled cells. A key fits a lock exactly when
+//! no cell is filled in both, which is a single AND per pair.
+pub fn parse(input: &str) -> (Vec<u64>, Vec<u64>) {
+    let mut locks = Vec::new();
+    let mut keys = Vec::new();
+
+    for schematic in input.split("\n\n") {
+        let mask = schematic
+            .bytes()
+            .filter(|&b| b != b'\n')
+            .fold(0, |mask, b| (mask << 1) | (b == b'#') as u64);
+
+        if schematic.starts_with('#') {
+            locks.push(mask);
+        } else {
+            keys.push(mask);
+        }
+    }
+
+    (locks, keys)
+}
+
+pub fn part1((locks, keys): &(Vec<u64>, Vec<u64>)) -> usize {
+    locks.iter().map(|lock| keys.iter().filter(|&&key| lock & key == 0).count()).sum()
+}
@@ -0,0 +1,43 @@
+//! # Secret Entrance
+//!
+//! Tracks the dial position modulo 100. Part two counts how many times each rotation passes
+//! zero directly with division, instead of stepping one click at a time. Turning left is
+//! handled by mirroring the dial so that it becomes the same calculation as turning right.
+pub fn parse(input: &str) -> Vec<i32> {
+    input
+        .lines()
+        .map(|line| {
+            let amount: i32 = line[1..].parse().unwrap();
+            if line.starts_with('L') {
+                -amount
+            } else {
+                amount
+            }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[i32]) -> usize {
+    let mut dial = 50;
+    input
+        .iter()
+        .filter(|&&rotation| {
+            dial = (dial + rotation).rem_euclid(100);
+            dial == 0
+        })
+        .count()
+}
+
+pub fn part2(input: &[i32]) -> i32 {
+    let mut dial = 50;
+    let mut zeros = 0;
+
+    for &rotation in input {
+        // Zero is first reached after `100 - offset` clicks in the direction of travel.
+        let offset = if rotation < 0 { (100 - dial) % 100 } else { dial };
+        zeros += (offset + rotation.abs()) / 100;
+        dial = (dial + rotation).rem_euclid(100);
+    }
+
+    zeros
+}
@@ -0,0 +1,56 @@
+//! # Gift Shop
+//!
+//! Invalid IDs with `d` digits made from a block of `d / k` digits repeated `k` times are exactly
+//! the multiples of `(10ᵈ - 1) / (10ᵈᐟᵏ - 1)` where the block has no leading zero. IDs in the
+//! input have at most 10 digits, so every invalid ID is generated up front, deduplicated since
+//! some have more than one repetition (for example `222222`), then sorted. The sum of the invalid
+//! IDs within each range then comes from a binary search into a prefix sum.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub struct Input {
+    ranges: Vec<[u64; 2]>,
+}
+
+pub fn parse(input: &str) -> Input {
+    Input { ranges: input.iter_unsigned().chunk::<2>().collect() }
+}
+
+pub fn part1(input: &Input) -> u64 {
+    total(input, |repeats| repeats == 2)
+}
+
+pub fn part2(input: &Input) -> u64 {
+    total(input, |repeats| repeats >= 2)
+}
+
+fn total(input: &Input, allowed: impl Fn(u32) -> bool) -> u64 {
+    let mut invalid = Vec::new();
+
+    for digits in 2..=10 {
+        for repeats in (2..=digits).filter(|&k| digits % k == 0 && allowed(k)) {
+            let size = digits / repeats;
+            let multiplier = (10_u64.pow(digits) - 1) / (10_u64.pow(size) - 1);
+            invalid
+                .extend((10_u64.pow(size - 1)..10_u64.pow(size)).map(|block| block * multiplier));
+        }
+    }
+
+    invalid.sort_unstable();
+    invalid.dedup();
+
+    let mut prefix = vec![0; invalid.len() + 1];
+    for (i, id) in invalid.iter().enumerate() {
+        prefix[i + 1] = prefix[i] + id;
+    }
+
+    input
+        .ranges
+        .iter()
+        .map(|&[start, end]| {
+            let lower = invalid.partition_point(|&id| id < start);
+            let upper = invalid.partition_point(|&id| id <= end);
+            prefix[upper] - prefix[lower]
+        })
+        .sum()
+}
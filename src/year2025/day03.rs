@@ -0,0 +1,32 @@
+//! # Lobby
+//!
+//! Greedy choice, one digit at a time. The first digit is the largest that still leaves enough
+//! batteries after it to complete the number, taking the leftmost if there are several so that
+//! as many options as possible remain. The same applies to every following digit.
+pub fn parse(input: &str) -> Vec<&[u8]> {
+    input.lines().map(str::as_bytes).collect()
+}
+
+pub fn part1(input: &[&[u8]]) -> u64 {
+    input.iter().map(|bank| joltage(bank, 2)).sum()
+}
+
+pub fn part2(input: &[&[u8]]) -> u64 {
+    input.iter().map(|bank| joltage(bank, 12)).sum()
+}
+
+fn joltage(bank: &[u8], batteries: usize) -> u64 {
+    let mut start = 0;
+    let mut result = 0;
+
+    for remaining in (0..batteries).rev() {
+        let window = &bank[start..bank.len() - remaining];
+        // `max_by_key` returns the last maximum, so search in reverse to find the first.
+        let (index, &digit) =
+            window.iter().enumerate().rev().max_by_key(|&(_, &digit)| digit).unwrap();
+        result = 10 * result + (digit - b'0') as u64;
+        start += index + 1;
+    }
+
+    result
+}
@@ -0,0 +1,62 @@
+//! # Printing Department
+//!
+//! Stores the number of neighboring rolls for every roll in a grid. For part two, removing a
+//! roll decrements the count of each neighbor, and any neighbor that drops below four becomes
+//! accessible and is queued for removal in turn. Each roll is processed at most once.
+use crate::util::grid::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Grid<u8> {
+    let grid = Grid::parse(input);
+    // Cells without a roll are marked with `u8::MAX` so they are never accessible.
+    let mut counts = grid.same_size_with(u8::MAX);
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let point = Point::new(x, y);
+            if grid[point] == b'@' {
+                let neighbors = DIAGONAL.iter().map(|&d| point + d);
+                counts[point] =
+                    neighbors.filter(|&n| grid.contains(n) && grid[n] == b'@').count() as u8;
+            }
+        }
+    }
+
+    counts
+}
+
+pub fn part1(input: &Grid<u8>) -> usize {
+    input.bytes.iter().filter(|&&count| count < 4).count()
+}
+
+pub fn part2(input: &Grid<u8>) -> usize {
+    let mut counts = input.clone();
+    let mut todo = Vec::new();
+    let mut removed = 0;
+
+    for y in 0..counts.height {
+        for x in 0..counts.width {
+            let point = Point::new(x, y);
+            if counts[point] < 4 {
+                todo.push(point);
+            }
+        }
+    }
+
+    while let Some(point) = todo.pop() {
+        counts[point] = u8::MAX;
+        removed += 1;
+
+        for next in DIAGONAL.map(|d| point + d) {
+            if counts.contains(next) && counts[next] != u8::MAX {
+                counts[next] -= 1;
+                // Queue exactly once, when the count first drops below four.
+                if counts[next] == 3 {
+                    todo.push(next);
+                }
+            }
+        }
+    }
+
+    removed
+}
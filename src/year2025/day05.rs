@@ -0,0 +1,43 @@
+//! # Cafeteria
+//!
+//! The ranges are sorted then merged so that they no longer overlap. Checking an ingredient is
+//! then a binary search, and the total number of fresh IDs is the sum of the merged range sizes.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub struct Input {
+    /// Disjoint inclusive ranges in increasing order.
+    ranges: Vec<[u64; 2]>,
+    ingredients: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let (prefix, suffix) = input.split_once("\n\n").unwrap();
+    let mut sorted: Vec<_> = prefix.iter_unsigned::<u64>().chunk::<2>().collect();
+    sorted.sort_unstable();
+
+    let mut ranges: Vec<[u64; 2]> = Vec::new();
+    for [start, end] in sorted {
+        match ranges.last_mut() {
+            Some(last) if start <= last[1] + 1 => last[1] = last[1].max(end),
+            _ => ranges.push([start, end]),
+        }
+    }
+
+    Input { ranges, ingredients: suffix.iter_unsigned().collect() }
+}
+
+pub fn part1(input: &Input) -> usize {
+    input
+        .ingredients
+        .iter()
+        .filter(|&&id| {
+            let index = input.ranges.partition_point(|range| range[1] < id);
+            index < input.ranges.len() && input.ranges[index][0] <= id
+        })
+        .count()
+}
+
+pub fn part2(input: &Input) -> u64 {
+    input.ranges.iter().map(|[start, end]| end - start + 1).sum()
+}
@@ -0,0 +1,58 @@
+//! # Trash Compactor
+//!
+//! Problems are separated by columns that are blank in every row. Within each problem, part one
+//! reads the numbers along rows while part two reads them down columns. Addition and
+//! multiplication are commutative so the right to left order doesn't matter.
+pub struct Problem {
+    multiply: bool,
+    rows: Vec<u64>,
+    columns: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Vec<Problem> {
+    let lines: Vec<_> = input.lines().map(str::as_bytes).collect();
+    let (operators, numbers) = lines.split_last().unwrap();
+    let width = lines.iter().map(|line| line.len()).max().unwrap();
+    // Editors sometimes trim trailing spaces, so treat anything past the end of a line as blank.
+    let cell = |line: &[u8], x: usize| line.get(x).copied().unwrap_or(b' ');
+
+    let mut problems = Vec::new();
+    let mut start = 0;
+
+    while start < width {
+        let mut end = start;
+        while end < width && !lines.iter().all(|line| cell(line, end) == b' ') {
+            end += 1;
+        }
+
+        let multiply = (start..end).any(|x| cell(operators, x) == b'*');
+        let digits = |cells: &mut dyn Iterator<Item = u8>| {
+            cells.filter(u8::is_ascii_digit).fold(0, |acc, b| 10 * acc + (b - b'0') as u64)
+        };
+        let rows =
+            numbers.iter().map(|line| digits(&mut (start..end).map(|x| cell(line, x)))).collect();
+        let columns =
+            (start..end).map(|x| digits(&mut numbers.iter().map(|line| cell(line, x)))).collect();
+
+        problems.push(Problem { multiply, rows, columns });
+        start = end + 1;
+    }
+
+    problems
+}
+
+pub fn part1(input: &[Problem]) -> u64 {
+    input.iter().map(|problem| solve(problem.multiply, &problem.rows)).sum()
+}
+
+pub fn part2(input: &[Problem]) -> u64 {
+    input.iter().map(|problem| solve(problem.multiply, &problem.columns)).sum()
+}
+
+fn solve(multiply: bool, numbers: &[u64]) -> u64 {
+    if multiply {
+        numbers.iter().product()
+    } else {
+        numbers.iter().sum()
+    }
+}
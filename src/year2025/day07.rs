@@ -0,0 +1,43 @@
+//! # Laboratories
+//!
+//! Processes the manifold one row at a time, tracking the number of timelines that have a beam
+//! in each column. A splitter moves every timeline in its column to both neighbors. Part one
+//! counts the splitters reached by at least one beam and part two sums the timelines remaining
+//! after the last row.
+pub fn parse(input: &str) -> (usize, u64) {
+    let lines: Vec<_> = input.lines().map(str::as_bytes).collect();
+    let width = lines[0].len();
+    let mut timelines = vec![0; width];
+    let mut splits = 0;
+
+    timelines[lines[0].iter().position(|&b| b == b'S').unwrap()] = 1;
+
+    for line in &lines[1..] {
+        let mut next = vec![0; width];
+
+        for (x, &count) in timelines.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            if line[x] == b'^' {
+                splits += 1;
+                next[x - 1] += count;
+                next[x + 1] += count;
+            } else {
+                next[x] += count;
+            }
+        }
+
+        timelines = next;
+    }
+
+    (splits, timelines.iter().sum())
+}
+
+pub fn part1(input: &(usize, u64)) -> usize {
+    input.0
+}
+
+pub fn part2(input: &(usize, u64)) -> u64 {
+    input.1
+}
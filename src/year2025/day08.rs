@@ -0,0 +1,96 @@
+//! # Playground
+//!
+//! Every pair of junction boxes is sorted by distance, then connected in order using a
+//! [disjoint-set](https://en.wikipedia.org/wiki/Disjoint-set_data_structure) to track circuits.
+//! Distances are compared squared so they stay as exact integers.
+use crate::util::iter::*;
+use crate::util::parse::*;
+
+pub struct Input {
+    boxes: Vec<[i64; 3]>,
+    /// Pairs of box indices, closest first.
+    pairs: Vec<(usize, usize)>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let boxes: Vec<[i64; 3]> = input.iter_signed().chunk::<3>().collect();
+    let mut pairs = Vec::with_capacity(boxes.len() * boxes.len() / 2);
+
+    for (i, a) in boxes.iter().enumerate() {
+        for (j, b) in boxes.iter().enumerate().skip(i + 1) {
+            let distance: i64 = (0..3).map(|k| (a[k] - b[k]) * (a[k] - b[k])).sum();
+            pairs.push((distance, i, j));
+        }
+    }
+
+    pairs.sort_unstable();
+    let pairs = pairs.into_iter().map(|(_, i, j)| (i, j)).collect();
+    Input { boxes, pairs }
+}
+
+pub fn part1(input: &Input) -> usize {
+    circuits(input, 1000)
+}
+
+pub fn part2(input: &Input) -> i64 {
+    let mut set = DisjointSet::new(input.boxes.len());
+    let mut circuits = input.boxes.len();
+
+    for &(i, j) in &input.pairs {
+        if set.union(i, j) {
+            circuits -= 1;
+            if circuits == 1 {
+                return input.boxes[i][0] * input.boxes[j][0];
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+/// Product of the three largest circuit sizes after connecting the closest `connections` pairs.
+pub fn circuits(input: &Input, connections: usize) -> usize {
+    let mut set = DisjointSet::new(input.boxes.len());
+    input.pairs.iter().take(connections).for_each(|&(i, j)| {
+        set.union(i, j);
+    });
+
+    let roots: Vec<_> = (0..input.boxes.len()).filter(|&i| set.find(i) == i).collect();
+    let mut sizes: Vec<_> = roots.iter().map(|&i| set.size[i]).collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    sizes.iter().take(3).product()
+}
+
+struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(n: usize) -> Self {
+        DisjointSet { parent: (0..n).collect(), size: vec![1; n] }
+    }
+
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            // Path halving
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    /// Returns `false` if both were already in the same set.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+}
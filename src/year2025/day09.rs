@@ -0,0 +1,103 @@
+//! # Movie Theater
+//!
+//! Part one checks every pair of red tiles.
+//!
+//! For part two the red tiles are the corners of a polygon. The coordinates are compressed so
+//! that each distinct `x` and `y` gets its own row or column, with an extra row or column
+//! between each to represent the gap. The polygon edges are drawn onto the compressed grid,
+//! everything outside is flood filled from the border, then a
+//! [summed-area table](https://en.wikipedia.org/wiki/Summed-area_table) of outside cells checks
+//! whether any rectangle is entirely inside in constant time.
+use crate::util::grid::*;
+use crate::util::iter::*;
+use crate::util::parse::*;
+use crate::util::point::*;
+
+pub fn parse(input: &str) -> Vec<[i64; 2]> {
+    input.iter_signed().chunk::<2>().collect()
+}
+
+pub fn part1(input: &[[i64; 2]]) -> i64 {
+    let mut best = 0;
+
+    for (i, a) in input.iter().enumerate() {
+        for b in &input[i + 1..] {
+            best = best.max(area(a, b));
+        }
+    }
+
+    best
+}
+
+pub fn part2(input: &[[i64; 2]]) -> i64 {
+    let compress = |axis: usize| {
+        let mut values: Vec<_> = input.iter().map(|tile| tile[axis]).collect();
+        values.sort_unstable();
+        values.dedup();
+        values
+    };
+    let (xs, ys) = (compress(0), compress(1));
+    // Index 0 and the last index form a border of outside cells around the polygon.
+    let index = |values: &[i64], value| 2 * values.binary_search(&value).unwrap() as i32 + 1;
+    let tiles: Vec<_> =
+        input.iter().map(|&[x, y]| Point::new(index(&xs, x), index(&ys, y))).collect();
+
+    let width = 2 * xs.len() as i32 + 1;
+    let height = 2 * ys.len() as i32 + 1;
+    let mut grid = Grid::new(width, height, b'.');
+
+    for (i, &from) in tiles.iter().enumerate() {
+        let to = tiles[(i + 1) % tiles.len()];
+        let step = to.signum(from);
+        let mut point = from;
+        grid[point] = b'#';
+        while point != to {
+            point += step;
+            grid[point] = b'#';
+        }
+    }
+
+    let mut todo = vec![ORIGIN];
+    grid[ORIGIN] = b'O';
+    while let Some(point) = todo.pop() {
+        for next in ORTHOGONAL.map(|o| point + o) {
+            if grid.contains(next) && grid[next] == b'.' {
+                grid[next] = b'O';
+                todo.push(next);
+            }
+        }
+    }
+
+    // `outside[y][x]` counts outside cells with smaller coordinates in both axes.
+    let (w, h) = (width as usize, height as usize);
+    let mut outside = vec![vec![0; w + 1]; h + 1];
+    for y in 0..h {
+        for x in 0..w {
+            let cell = (grid[Point::new(x as i32, y as i32)] == b'O') as u32;
+            outside[y + 1][x + 1] = cell + outside[y][x + 1] + outside[y + 1][x] - outside[y][x];
+        }
+    }
+
+    let mut best = 0;
+
+    for (i, (a, &p)) in input.iter().zip(&tiles).enumerate() {
+        for (b, &q) in input[i + 1..].iter().zip(&tiles[i + 1..]) {
+            let area = area(a, b);
+            if area <= best {
+                continue;
+            }
+
+            let (x1, x2) = (p.x.min(q.x) as usize, p.x.max(q.x) as usize + 1);
+            let (y1, y2) = (p.y.min(q.y) as usize, p.y.max(q.y) as usize + 1);
+            if outside[y2][x2] + outside[y1][x1] == outside[y1][x2] + outside[y2][x1] {
+                best = area;
+            }
+        }
+    }
+
+    best
+}
+
+fn area(a: &[i64; 2], b: &[i64; 2]) -> i64 {
+    ((a[0] - b[0]).abs() + 1) * ((a[1] - b[1]).abs() + 1)
+}
@@ -0,0 +1,124 @@
+//! # Factory
+//!
+//! Pressing a button twice has no effect on the lights, so part one only needs to consider
+//! each subset of buttons pressed once. The fewest presses is the smallest subset whose XOR
+//! matches the target pattern.
+//!
+//! Part two builds on this. In any solution, the buttons pressed an odd number of times must
+//! produce the parity pattern of the joltage targets, just like the lights in part one. After
+//! pressing each of those buttons once, every remaining button is pressed an even number of
+//! times, so the remaining targets are all even and can be halved. The problem is then the
+//! same as before with targets half the size:
+//!
+//! `presses(target) = min(|S| + 2 * presses((target - effect(S)) / 2))`
+//!
+//! over every subset `S` with the right parity that doesn't overshoot any target. The targets
+//! shrink exponentially, and results are memoized since many subsets lead to the same halved
+//! target.
+use crate::util::hash::*;
+
+pub struct Machine {
+    lights: u32,
+    buttons: Vec<Vec<usize>>,
+    joltages: Vec<u32>,
+}
+
+pub fn parse(input: &str) -> Vec<Machine> {
+    input
+        .lines()
+        .map(|line| {
+            let tokens: Vec<_> = line.split_ascii_whitespace().collect();
+            let (first, rest) = tokens.split_first().unwrap();
+            let (last, buttons) = rest.split_last().unwrap();
+
+            let lights = first[1..first.len() - 1]
+                .bytes()
+                .enumerate()
+                .fold(0, |acc, (i, b)| acc | ((b == b'#') as u32) << i);
+            let numbers = |token: &str| -> Vec<_> {
+                token[1..token.len() - 1].split(',').map(|n| n.parse::<u32>().unwrap()).collect()
+            };
+            let buttons = buttons
+                .iter()
+                .map(|b| numbers(b).into_iter().map(|n| n as usize).collect())
+                .collect();
+            let joltages = numbers(last);
+
+            Machine { lights, buttons, joltages }
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Machine]) -> u32 {
+    input
+        .iter()
+        .map(|machine| {
+            let masks: Vec<u32> =
+                machine.buttons.iter().map(|b| b.iter().fold(0, |acc, &i| acc | 1 << i)).collect();
+            (0_u32..1 << masks.len())
+                .filter(|subset| {
+                    let lights = (0..masks.len())
+                        .filter(|i| subset & (1 << i) != 0)
+                        .fold(0, |acc, i| acc ^ masks[i]);
+                    lights == machine.lights
+                })
+                .map(u32::count_ones)
+                .min()
+                .unwrap()
+        })
+        .sum()
+}
+
+pub fn part2(input: &[Machine]) -> u32 {
+    input
+        .iter()
+        .map(|machine| {
+            // Group the effect of every subset of buttons by the parity pattern it produces.
+            let size = machine.joltages.len();
+            let mut patterns: FastMap<u32, Vec<(u32, Vec<u32>)>> = FastMap::new();
+
+            for subset in 0_u32..1 << machine.buttons.len() {
+                let mut effect = vec![0; size];
+                for (i, button) in machine.buttons.iter().enumerate() {
+                    if subset & (1 << i) != 0 {
+                        button.iter().for_each(|&counter| effect[counter] += 1);
+                    }
+                }
+                let parity = effect.iter().enumerate().fold(0, |acc, (i, e)| acc | (e & 1) << i);
+                patterns.entry(parity).or_default().push((subset.count_ones(), effect));
+            }
+
+            let mut cache = FastMap::new();
+            presses(&patterns, &mut cache, machine.joltages.clone()).unwrap()
+        })
+        .sum()
+}
+
+fn presses(
+    patterns: &FastMap<u32, Vec<(u32, Vec<u32>)>>,
+    cache: &mut FastMap<Vec<u32>, Option<u32>>,
+    target: Vec<u32>,
+) -> Option<u32> {
+    if target.iter().all(|&t| t == 0) {
+        return Some(0);
+    }
+    if let Some(&result) = cache.get(&target) {
+        return result;
+    }
+
+    let parity = target.iter().enumerate().fold(0, |acc, (i, t)| acc | (t & 1) << i);
+    let mut best = None;
+
+    for (count, effect) in patterns.get(&parity).into_iter().flatten() {
+        if effect.iter().zip(&target).all(|(e, t)| e <= t) {
+            let half = target.iter().zip(effect).map(|(t, e)| (t - e) / 2).collect();
+            if let Some(rest) = presses(patterns, cache, half) {
+                let total = count + 2 * rest;
+                best = Some(best.map_or(total, |b: u32| b.min(total)));
+            }
+        }
+    }
+
+    cache.insert(target, best);
+    best
+}
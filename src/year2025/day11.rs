@@ -0,0 +1,63 @@
+//! # Reactor
+//!
+//! The devices form a directed acyclic graph, so the number of paths between two devices is
+//! found with a memoized depth first search. For part two, paths visit `dac` and `fft` in one
+//! order or the other (both orders can't happen without a cycle). The paths in each order are the
+//! product of the paths for each leg.
+use crate::util::hash::*;
+
+pub struct Input<'a> {
+    indices: FastMap<&'a str, usize>,
+    outputs: Vec<Vec<usize>>,
+}
+
+pub fn parse(input: &str) -> Input<'_> {
+    let mut indices = FastMap::new();
+    let mut outputs = Vec::new();
+    let mut index_of = |name, outputs: &mut Vec<Vec<usize>>| {
+        *indices.entry(name).or_insert_with(|| {
+            outputs.push(Vec::new());
+            outputs.len() - 1
+        })
+    };
+
+    for line in input.lines() {
+        let (from, to) = line.split_once(": ").unwrap();
+        let from = index_of(from, &mut outputs);
+        for name in to.split_ascii_whitespace() {
+            let to = index_of(name, &mut outputs);
+            outputs[from].push(to);
+        }
+    }
+
+    Input { indices, outputs }
+}
+
+pub fn part1(input: &Input<'_>) -> u64 {
+    paths(input, "you", "out")
+}
+
+pub fn part2(input: &Input<'_>) -> u64 {
+    let legs =
+        |order: [&str; 4]| order.windows(2).map(|w| paths(input, w[0], w[1])).product::<u64>();
+    legs(["svr", "dac", "fft", "out"]) + legs(["svr", "fft", "dac", "out"])
+}
+
+fn paths(input: &Input<'_>, from: &str, to: &str) -> u64 {
+    let (from, to) = (input.indices[from], input.indices[to]);
+    let mut cache = vec![None; input.outputs.len()];
+    dfs(input, &mut cache, from, to)
+}
+
+fn dfs(input: &Input<'_>, cache: &mut [Option<u64>], from: usize, to: usize) -> u64 {
+    if from == to {
+        return 1;
+    }
+    if let Some(count) = cache[from] {
+        return count;
+    }
+
+    let count = input.outputs[from].iter().map(|&next| dfs(input, cache, next, to)).sum();
+    cache[from] = Some(count);
+    count
+}
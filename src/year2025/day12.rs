@@ -0,0 +1,194 @@
+//! # Christmas Tree Farm
+//!
+//! Packing shapes is NP-hard in general, however most regions can be decided immediately. If
+//! every present fits in its own 3x3 square without any clever interlocking then the region
+//! obviously works. If the presents have more filled cells than the region has space then it
+//! obviously can't.
+//!
+//! Any region between these two bounds is solved exactly with a backtracking search. Cells are
+//! filled in reading order. The first empty cell must either be covered by a present whose
+//! first cell (in reading order) lands there, or left empty, which is only allowed while there
+//! are spare cells left over. This never tries the same arrangement twice.
+//!
+//! Presents extend at most two rows below the current cell, so everything the remaining search
+//! depends on is the current cell, the filled cells in the next couple of rows and the presents
+//! left to place. Failed states are memoized, and the region is transposed if needed to keep
+//! rows (and so this frontier) short.
+//!
+//! This puzzle only has one part.
+use crate::util::hash::*;
+use crate::util::parse::*;
+
+pub struct Input {
+    /// Every distinct orientation of each shape, as offsets from its first cell.
+    shapes: Vec<Vec<Vec<(i32, i32)>>>,
+    regions: Vec<(i32, i32, Vec<usize>)>,
+}
+
+pub fn parse(input: &str) -> Input {
+    let blocks: Vec<_> = input.split("\n\n").collect();
+    let (regions, shapes) = blocks.split_last().unwrap();
+
+    let shapes = shapes
+        .iter()
+        .map(|block| {
+            let cells: Vec<_> = block
+                .lines()
+                .skip(1)
+                .enumerate()
+                .flat_map(|(y, line)| {
+                    line.bytes()
+                        .enumerate()
+                        .filter(|&(_, b)| b == b'#')
+                        .map(move |(x, _)| (x as i32, y as i32))
+                })
+                .collect();
+            orientations(&cells)
+        })
+        .collect();
+
+    let regions = regions
+        .lines()
+        .map(|line| {
+            let (size, counts) = line.split_once(": ").unwrap();
+            let (width, height) = size.split_once('x').unwrap();
+            (width.signed(), height.signed(), counts.iter_unsigned().collect())
+        })
+        .collect();
+
+    Input { shapes, regions }
+}
+
+pub fn part1(input: &Input) -> usize {
+    input
+        .regions
+        .iter()
+        .filter(|(width, height, counts)| fits(input, *width, *height, counts))
+        .count()
+}
+
+fn fits(input: &Input, width: i32, height: i32, counts: &[usize]) -> bool {
+    let (width, height) = (width.min(height), width.max(height));
+    let presents: usize = counts.iter().sum();
+    if ((width / 3) * (height / 3)) as usize >= presents {
+        return true;
+    }
+
+    let cells: usize =
+        counts.iter().zip(&input.shapes).map(|(count, shape)| count * shape[0].len()).sum();
+    let area = (width * height) as usize;
+    if cells > area {
+        return false;
+    }
+
+    let mut search = Search {
+        shapes: &input.shapes,
+        width,
+        height,
+        filled: vec![false; area],
+        remaining: counts.to_vec(),
+        failed: FastSet::new(),
+    };
+    search.dfs(0, presents, area - cells)
+}
+
+struct Search<'a> {
+    shapes: &'a [Vec<Vec<(i32, i32)>>],
+    width: i32,
+    height: i32,
+    filled: Vec<bool>,
+    remaining: Vec<usize>,
+    failed: FastSet<(usize, Vec<bool>, Vec<usize>)>,
+}
+
+impl Search<'_> {
+    fn dfs(&mut self, mut position: usize, presents: usize, spare: usize) -> bool {
+        if presents == 0 {
+            return true;
+        }
+        while position < self.filled.len() && self.filled[position] {
+            position += 1;
+        }
+        if position == self.filled.len() {
+            return false;
+        }
+
+        let end = self.filled.len().min(position + 2 * self.width as usize + 3);
+        let key = (position, self.filled[position..end].to_vec(), self.remaining.clone());
+        if self.failed.contains(&key) {
+            return false;
+        }
+
+        let found = self.place(position, presents, spare);
+        if !found {
+            self.failed.insert(key);
+        }
+        found
+    }
+
+    fn place(&mut self, position: usize, presents: usize, spare: usize) -> bool {
+        let (x, y) = ((position as i32) % self.width, (position as i32) / self.width);
+
+        for shape in 0..self.shapes.len() {
+            if self.remaining[shape] == 0 {
+                continue;
+            }
+
+            for orientation in &self.shapes[shape] {
+                let cells: Option<Vec<_>> = orientation
+                    .iter()
+                    .map(|&(dx, dy)| {
+                        let (nx, ny) = (x + dx, y + dy);
+                        let inside =
+                            (0..self.width).contains(&nx) && (0..self.height).contains(&ny);
+                        let index = (ny * self.width + nx) as usize;
+                        (inside && !self.filled[index]).then_some(index)
+                    })
+                    .collect();
+                let Some(cells) = cells else { continue };
+
+                cells.iter().for_each(|&i| self.filled[i] = true);
+                self.remaining[shape] -= 1;
+                let found = self.dfs(position + 1, presents - 1, spare);
+                self.remaining[shape] += 1;
+                cells.iter().for_each(|&i| self.filled[i] = false);
+
+                if found {
+                    return true;
+                }
+            }
+        }
+
+        // Leave this cell empty.
+        spare > 0 && {
+            self.filled[position] = true;
+            let found = self.dfs(position + 1, presents, spare - 1);
+            self.filled[position] = false;
+            found
+        }
+    }
+}
+
+/// All distinct rotations and reflections, normalized so that the first cell in reading order
+/// is at the origin.
+fn orientations(cells: &[(i32, i32)]) -> Vec<Vec<(i32, i32)>> {
+    let mut result: Vec<Vec<(i32, i32)>> = Vec::new();
+    let mut current = cells.to_vec();
+
+    for _ in 0..2 {
+        for _ in 0..4 {
+            let mut normalized = current.clone();
+            normalized.sort_unstable_by_key(|&(x, y)| (y, x));
+            let (fx, fy) = normalized[0];
+            normalized.iter_mut().for_each(|(x, y)| (*x, *y) = (*x - fx, *y - fy));
+
+            if !result.contains(&normalized) {
+                result.push(normalized);
+            }
+            current.iter_mut().for_each(|(x, y)| (*x, *y) = (-*y, *x));
+        }
+        current.iter_mut().for_each(|(x, _)| *x = -*x);
+    }
+
+    result
+}
@@ -28,12 +28,64 @@
 //     assert_eq!(part2(&input), 456);
 // }
 
+#[cfg(feature = "_any_year")]
 macro_rules! test {
     ($year:tt $($day:tt),*) => {
         pub mod $year {$(pub mod $day;)*}
     }
 }
 
+#[cfg(feature = "year2015")]
+test!(year2015
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2016")]
+test!(year2016
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2017")]
+test!(year2017
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2018")]
+test!(year2018
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2019")]
+test!(year2019
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2020")]
+test!(year2020
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2021")]
+test!(year2021
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2022")]
+test!(year2022
+    day01, day02, day03, day04, day05, day06, day07, day08
+);
+
+#[cfg(feature = "year2023")]
 test!(year2023
-    day01
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2024")]
+test!(year2024
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15, day16, day17, day18, day19, day20, day21, day22, day23, day24, day25
+);
+
+#[cfg(feature = "year2025")]
+test!(year2025
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12
 );
@@ -0,0 +1,14 @@
+use aoc::year2015::day01::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(parse("(())")), 0);
+    assert_eq!(part1(parse("))(((((")), 3);
+    assert_eq!(part1(parse(")())())")), -3);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(parse(")")), 1);
+    assert_eq!(part2(parse("()())")), 5);
+}
@@ -0,0 +1,17 @@
+use aoc::year2015::day02::*;
+
+const EXAMPLE: &str = "\
+2x3x4
+1x1x10";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 58 + 43);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 34 + 14);
+}
@@ -0,0 +1,15 @@
+use aoc::year2015::day03::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse(">")), 2);
+    assert_eq!(part1(&parse("^>v<")), 4);
+    assert_eq!(part1(&parse("^v^v^v^v^v")), 2);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("^v")), 3);
+    assert_eq!(part2(&parse("^>v<")), 3);
+    assert_eq!(part2(&parse("^v^v^v^v^v")), 11);
+}
@@ -0,0 +1,12 @@
+use aoc::year2015::day04::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(parse("abcdef")), 609043);
+    assert_eq!(part1(parse("pqrstuv")), 1048970);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
@@ -0,0 +1,26 @@
+use aoc::year2015::day07::*;
+
+const EXAMPLE: &str = "\
+123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i
+e -> b
+b AND i -> a";
+
+#[test]
+fn part1_test() {
+    // e = 507 and i = 65079
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 507 & 65079);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), (507 & 65079) & 65079);
+}
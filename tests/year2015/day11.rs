@@ -0,0 +1,12 @@
+use aoc::year2015::day11::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("abcdefgh")), "abcdffaa");
+    assert_eq!(part1(&parse("ghijklmn")), "ghjaabcc");
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("abcdefgh")), "abcdffbb");
+}
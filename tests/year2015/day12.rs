@@ -0,0 +1,21 @@
+use aoc::year2015::day12::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("[1,2,3]")), 6);
+    assert_eq!(part1(&parse(r#"{"a":2,"b":4}"#)), 6);
+    assert_eq!(part1(&parse("[[[3]]]")), 3);
+    assert_eq!(part1(&parse(r#"{"a":{"b":4},"c":-1}"#)), 3);
+    assert_eq!(part1(&parse(r#"{"a":[-1,1]}"#)), 0);
+    assert_eq!(part1(&parse(r#"[-1,{"a":1}]"#)), 0);
+    assert_eq!(part1(&parse("[]")), 0);
+    assert_eq!(part1(&parse("{}")), 0);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("[1,2,3]")), 6);
+    assert_eq!(part2(&parse(r#"[1,{"c":"red","b":2},3]"#)), 4);
+    assert_eq!(part2(&parse(r#"{"d":"red","e":[1,2,3,4],"f":5}"#)), 0);
+    assert_eq!(part2(&parse(r#"[1,"red",5]"#)), 6);
+}
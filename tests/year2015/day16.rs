@@ -0,0 +1,18 @@
+use aoc::year2015::day16::*;
+
+const EXAMPLE: &str = "\
+Sue 1: children: 1, cars: 2, perfumes: 1
+Sue 2: cats: 7, trees: 3, goldfish: 5
+Sue 3: cats: 8, trees: 4, goldfish: 4";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 2);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 3);
+}
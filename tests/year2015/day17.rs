@@ -0,0 +1,13 @@
+use aoc::year2015::day17::*;
+
+#[test]
+fn part1_test() {
+    let input = combinations(&[20, 15, 10, 5, 5], 25);
+    assert_eq!(part1(&input), 4);
+}
+
+#[test]
+fn part2_test() {
+    let input = combinations(&[20, 15, 10, 5, 5], 25);
+    assert_eq!(part2(&input), 3);
+}
@@ -0,0 +1,21 @@
+use aoc::year2015::day18::*;
+
+const EXAMPLE: &str = "\
+.#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(animate(&input, 4, false), 4);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(animate(&input, 5, true), 17);
+}
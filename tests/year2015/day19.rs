@@ -0,0 +1,26 @@
+use aoc::year2015::day19::*;
+
+const FIRST_EXAMPLE: &str = "\
+H => HO
+H => OH
+O => HH
+
+HOH";
+
+const SECOND_EXAMPLE: &str = "\
+H => HO
+H => OH
+O => HH
+
+HOHOHO";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse(FIRST_EXAMPLE)), 4);
+    assert_eq!(part1(&parse(SECOND_EXAMPLE)), 7);
+}
+
+#[test]
+fn part2_test() {
+    // The example doesn't share the structure of the real input that the solution relies on.
+}
@@ -0,0 +1,12 @@
+use aoc::year2015::day20::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("70")), 4);
+    assert_eq!(part1(&parse("150")), 8);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("70")), 4);
+}
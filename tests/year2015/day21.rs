@@ -0,0 +1,18 @@
+use aoc::year2015::day21::*;
+
+const EXAMPLE: &str = "\
+Hit Points: 104
+Damage: 8
+Armor: 1";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 78);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 148);
+}
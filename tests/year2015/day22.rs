@@ -0,0 +1,12 @@
+use aoc::year2015::day22::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(play([13, 8], 10, 250, false), 226);
+    assert_eq!(play([14, 8], 10, 250, false), 641);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
@@ -0,0 +1,19 @@
+use aoc::year2015::day23::*;
+
+const EXAMPLE: &str = "\
+jio a, +3
+inc b
+tpl b
+inc b";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 4);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 1);
+}
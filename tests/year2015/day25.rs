@@ -0,0 +1,9 @@
+use aoc::year2015::day25::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("row 1, column 1")), 20151125);
+    assert_eq!(part1(&parse("row 2, column 1")), 31916031);
+    assert_eq!(part1(&parse("row 1, column 2")), 18749137);
+    assert_eq!(part1(&parse("row 6, column 6")), 27995004);
+}
@@ -0,0 +1,13 @@
+use aoc::year2016::day01::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("R2, L3")), 5);
+    assert_eq!(part1(&parse("R2, R2, R2")), 2);
+    assert_eq!(part1(&parse("R5, L5, R5, R3")), 12);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("R8, R4, R4, R8")), 4);
+}
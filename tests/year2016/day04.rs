@@ -0,0 +1,18 @@
+use aoc::year2016::day04::*;
+
+const EXAMPLE: &str = "\
+aaaaa-bbb-z-y-x-123[abxyz]
+a-b-c-d-e-f-g-h-987[abcde]
+not-a-real-room-404[oarel]
+totally-real-room-200[decoy]";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 1514);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(decrypt("qzmt-zixmtkozy-ivhz", 343), "very encrypted name");
+}
@@ -0,0 +1,17 @@
+use aoc::year2016::day05::*;
+
+// Each example needs millions of hashes, which is too slow for an unoptimized build.
+
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn part1_test() {
+    let input = parse("abc");
+    assert_eq!(part1(&input), "18f47a30");
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn part2_test() {
+    let input = parse("abc");
+    assert_eq!(part2(&input), "05ace8e3");
+}
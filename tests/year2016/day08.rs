@@ -0,0 +1,19 @@
+use aoc::year2016::day08::*;
+
+const EXAMPLE: &str = "\
+rect 3x2
+rotate column x=1 by 1
+rotate row y=0 by 4
+rotate column x=1 by 1";
+
+#[test]
+fn part1_test() {
+    let screen = screen(EXAMPLE, 7, 3);
+    assert_eq!(part1(&screen), 6);
+}
+
+#[test]
+fn part2_test() {
+    let screen = screen(EXAMPLE, 7, 3);
+    assert_eq!(part2(&screen), "\n.#..#.#\n#.#....\n.#.....");
+}
@@ -0,0 +1,19 @@
+use aoc::year2016::day09::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(parse("ADVENT")), 6);
+    assert_eq!(part1(parse("A(1x5)BC")), 7);
+    assert_eq!(part1(parse("(3x3)XYZ")), 9);
+    assert_eq!(part1(parse("A(2x2)BCD(2x2)EFG")), 11);
+    assert_eq!(part1(parse("(6x1)(1x3)A")), 6);
+    assert_eq!(part1(parse("X(8x2)(3x3)ABCY")), 18);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(parse("(3x3)XYZ")), 9);
+    assert_eq!(part2(parse("X(8x2)(3x3)ABCY")), 20);
+    assert_eq!(part2(parse("(27x12)(20x12)(13x14)(7x10)(1x12)A")), 241920);
+    assert_eq!(part2(parse("(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN")), 445);
+}
@@ -0,0 +1,18 @@
+use aoc::year2016::day11::*;
+
+const EXAMPLE: &str = "\
+The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+The second floor contains a hydrogen generator.
+The third floor contains a lithium generator.
+The fourth floor contains nothing relevant.";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 11);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
@@ -0,0 +1,20 @@
+use aoc::year2016::day12::*;
+
+const EXAMPLE: &str = "\
+cpy 41 a
+inc a
+inc a
+dec a
+jnz a 2
+dec a";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 42);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
@@ -0,0 +1,12 @@
+use aoc::year2016::day13::*;
+
+#[test]
+fn part1_test() {
+    let input = parse("10");
+    assert_eq!(steps(&input, (7, 4)), 11);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
@@ -0,0 +1,15 @@
+use aoc::year2016::day14::*;
+
+#[test]
+fn part1_test() {
+    let input = parse("abc");
+    assert_eq!(part1(input), 22728);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn part2_test() {
+    // Key stretching needs millions of hashes, which is too slow for an unoptimized build.
+    let input = parse("abc");
+    assert_eq!(part2(input), 22551);
+}
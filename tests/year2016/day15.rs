@@ -0,0 +1,16 @@
+use aoc::year2016::day15::*;
+
+const EXAMPLE: &str = "\
+Disc #1 has 5 positions; at time=0, it is at position 4.
+Disc #2 has 2 positions; at time=0, it is at position 1.";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 5);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
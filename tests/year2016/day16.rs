@@ -0,0 +1,12 @@
+use aoc::year2016::day16::*;
+
+#[test]
+fn part1_test() {
+    let input = parse("10000");
+    assert_eq!(checksum(&input, 20), "01100");
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
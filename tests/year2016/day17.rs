@@ -0,0 +1,15 @@
+use aoc::year2016::day17::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("ihgpwlah")), "DDRRRD");
+    assert_eq!(part1(&parse("kglvqrro")), "DDUDRLRRUDRD");
+    assert_eq!(part1(&parse("ulqzkmiv")), "DRURDRUDDLLDLUURRDULRLDUUDDDRR");
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("ihgpwlah")), 370);
+    assert_eq!(part2(&parse("kglvqrro")), 492);
+    assert_eq!(part2(&parse("ulqzkmiv")), 830);
+}
@@ -0,0 +1,12 @@
+use aoc::year2016::day18::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(safe("..^^.", 3), 6);
+    assert_eq!(safe(".^^.^.^^^^", 10), 38);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
@@ -0,0 +1,11 @@
+use aoc::year2016::day19::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("5")), 3);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("5")), 2);
+}
@@ -0,0 +1,18 @@
+use aoc::year2016::day20::*;
+
+const EXAMPLE: &str = "\
+5-8
+0-2
+4-7";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 3);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(allowed(&input, 9), 2);
+}
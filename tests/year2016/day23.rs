@@ -0,0 +1,21 @@
+use aoc::year2016::day23::*;
+
+const EXAMPLE: &str = "\
+cpy 2 a
+tgl a
+tgl a
+tgl a
+cpy 1 a
+dec a
+dec a";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 3);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
@@ -0,0 +1,4 @@
+#[test]
+fn part1_test() {
+    // No example data
+}
@@ -0,0 +1,15 @@
+use aoc::year2017::day03::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&1), 0);
+    assert_eq!(part1(&12), 3);
+    assert_eq!(part1(&23), 2);
+    assert_eq!(part1(&1024), 31);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&747), 806);
+    assert_eq!(part2(&800), 806);
+}
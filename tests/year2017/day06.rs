@@ -0,0 +1,15 @@
+use aoc::year2017::day06::*;
+
+const EXAMPLE: &str = "0 2 7 0";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 5);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 4);
+}
@@ -0,0 +1,24 @@
+use aoc::year2017::day09::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("{}")), 1);
+    assert_eq!(part1(&parse("{{{}}}")), 6);
+    assert_eq!(part1(&parse("{{},{}}")), 5);
+    assert_eq!(part1(&parse("{{{},{},{{}}}}")), 16);
+    assert_eq!(part1(&parse("{<a>,<a>,<a>,<a>}")), 1);
+    assert_eq!(part1(&parse("{{<ab>},{<ab>},{<ab>},{<ab>}}")), 9);
+    assert_eq!(part1(&parse("{{<!!>},{<!!>},{<!!>},{<!!>}}")), 9);
+    assert_eq!(part1(&parse("{{<a!>},{<a!>},{<a!>},{<ab>}}")), 3);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("<>")), 0);
+    assert_eq!(part2(&parse("<random characters>")), 17);
+    assert_eq!(part2(&parse("<<<<>")), 3);
+    assert_eq!(part2(&parse("<{!>}>")), 2);
+    assert_eq!(part2(&parse("<!!>")), 0);
+    assert_eq!(part2(&parse("<!!!>>")), 0);
+    assert_eq!(part2(&parse("<{o\"i!a,<{i<a>")), 10);
+}
@@ -0,0 +1,14 @@
+use aoc::year2017::day10::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(check("3,4,1,5", 5), 12);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(""), "a2582a3a0e66e6e86e3812dcb672a272");
+    assert_eq!(part2("AoC 2017"), "33efeb34ea91902bb2f59c9920caa6cd");
+    assert_eq!(part2("1,2,3"), "3efbe78a8d82f29979031a4aa0b16a9d");
+    assert_eq!(part2("1,2,4"), "63960835bcdc130f0b66d7ff4f6a5a8e");
+}
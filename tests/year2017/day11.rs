@@ -0,0 +1,15 @@
+use aoc::year2017::day11::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("ne,ne,ne")), 3);
+    assert_eq!(part1(&parse("ne,ne,sw,sw")), 0);
+    assert_eq!(part1(&parse("ne,ne,s,s")), 2);
+    assert_eq!(part1(&parse("se,sw,se,sw,sw")), 3);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("ne,ne,ne")), 3);
+    assert_eq!(part2(&parse("ne,ne,sw,sw")), 2);
+}
@@ -0,0 +1,15 @@
+use aoc::year2017::day16::*;
+
+const EXAMPLE: &str = "s1,x3/4,pe/b";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(dance(&input, 5, 1), "baedc");
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(dance(&input, 5, 2), "ceadb");
+}
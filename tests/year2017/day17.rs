@@ -0,0 +1,11 @@
+use aoc::year2017::day17::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&3), 638);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
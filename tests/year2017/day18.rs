@@ -0,0 +1,34 @@
+use aoc::year2017::day18::*;
+
+const FIRST_EXAMPLE: &str = "\
+set a 1
+add a 2
+mul a a
+mod a 5
+snd a
+set a 0
+rcv a
+jgz a -1
+set a 1
+jgz a -2";
+
+const SECOND_EXAMPLE: &str = "\
+snd 1
+snd 2
+snd p
+rcv a
+rcv b
+rcv c
+rcv d";
+
+#[test]
+fn part1_test() {
+    let input = parse(FIRST_EXAMPLE);
+    assert_eq!(part1(&input), 4);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(SECOND_EXAMPLE);
+    assert_eq!(part2(&input), 3);
+}
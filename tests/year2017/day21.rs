@@ -0,0 +1,16 @@
+use aoc::year2017::day21::*;
+
+const EXAMPLE: &str = "\
+../.# => ##./#../...
+.#./..#/### => #..#/..../..../#..#";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(pixels(&input, 2), 12);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
@@ -0,0 +1,20 @@
+use aoc::year2017::day22::*;
+
+const EXAMPLE: &str = "\
+..#
+#..
+...";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(infections(&input, 7, 2), 5);
+    assert_eq!(infections(&input, 70, 2), 41);
+    assert_eq!(part1(&input), 5587);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(infections(&input, 100, 1), 26);
+}
@@ -0,0 +1,22 @@
+use aoc::year2018::day01::*;
+
+const EXAMPLE: &str = "\
++1
+-2
++3
++1";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 3);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 2);
+    assert_eq!(part2(&parse("+3, +3, +4, -2, -4")), 10);
+    assert_eq!(part2(&parse("-6, +3, +8, +5, -6")), 5);
+    assert_eq!(part2(&parse("+7, +7, -2, -7, -4")), 14);
+}
@@ -0,0 +1,16 @@
+use aoc::year2018::day09::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(high_score(9, 25), 32);
+    assert_eq!(part1(&parse("10 players; last marble is worth 1618 points")), 8317);
+    assert_eq!(part1(&parse("13 players; last marble is worth 7999 points")), 146373);
+    assert_eq!(part1(&parse("17 players; last marble is worth 1104 points")), 2764);
+    assert_eq!(part1(&parse("21 players; last marble is worth 6111 points")), 54718);
+    assert_eq!(part1(&parse("30 players; last marble is worth 5807 points")), 37305);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
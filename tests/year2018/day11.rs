@@ -0,0 +1,13 @@
+use aoc::year2018::day11::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("18")), "33,45");
+    assert_eq!(part1(&parse("42")), "21,61");
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("18")), "90,269,16");
+    assert_eq!(part2(&parse("42")), "232,251,12");
+}
@@ -0,0 +1,17 @@
+use aoc::year2018::day14::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1("9"), "5158916779");
+    assert_eq!(part1("5"), "0124515891");
+    assert_eq!(part1("18"), "9251071085");
+    assert_eq!(part1("2018"), "5941429882");
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2("51589"), 9);
+    assert_eq!(part2("01245"), 5);
+    assert_eq!(part2("92510"), 18);
+    assert_eq!(part2("59414"), 2018);
+}
@@ -0,0 +1,54 @@
+use aoc::year2018::day15::*;
+
+const FIRST_EXAMPLE: &str = "\
+#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######";
+
+const SECOND_EXAMPLE: &str = "\
+#######
+#G..#E#
+#E#E.E#
+#G.##.#
+#...#E#
+#...E.#
+#######";
+
+const THIRD_EXAMPLE: &str = "\
+#######
+#E..EG#
+#.#G.E#
+#E.##E#
+#G..#.#
+#..E#.#
+#######";
+
+const FOURTH_EXAMPLE: &str = "\
+#########
+#G......#
+#.E.#...#
+#..##..G#
+#...##..#
+#...#...#
+#.G...G.#
+#.....G.#
+#########";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse(FIRST_EXAMPLE)), 27730);
+    assert_eq!(part1(&parse(SECOND_EXAMPLE)), 36334);
+    assert_eq!(part1(&parse(THIRD_EXAMPLE)), 39514);
+    assert_eq!(part1(&parse(FOURTH_EXAMPLE)), 18740);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse(FIRST_EXAMPLE)), 4988);
+    assert_eq!(part2(&parse(THIRD_EXAMPLE)), 31284);
+    assert_eq!(part2(&parse(FOURTH_EXAMPLE)), 1140);
+}
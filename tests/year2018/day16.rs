@@ -0,0 +1,17 @@
+use aoc::year2018::day16::*;
+
+const EXAMPLE: &str = "\
+Before: [3, 2, 1, 1]
+9 2 1 2
+After:  [3, 2, 2, 1]";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 1);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
@@ -0,0 +1,24 @@
+use aoc::year2018::day18::*;
+
+const EXAMPLE: &str = "\
+.#.#...|#.
+.....#|##|
+.|..|...#.
+..|#.....#
+#.#|||#|#|
+...#.||...
+.|....|...
+||...#|.#|
+|.||||..|.
+...#.|..|.";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 1147);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
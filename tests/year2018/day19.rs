@@ -0,0 +1,22 @@
+use aoc::year2018::day19::*;
+
+const EXAMPLE: &str = "\
+#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 6);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
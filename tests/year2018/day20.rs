@@ -0,0 +1,15 @@
+use aoc::year2018::day20::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("^WNE$")), 3);
+    assert_eq!(part1(&parse("^ENWWW(NEEE|SSE(EE|N))$")), 10);
+    assert_eq!(part1(&parse("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$")), 18);
+    assert_eq!(part1(&parse("^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$")), 23);
+    assert_eq!(part1(&parse("^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$")), 31);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
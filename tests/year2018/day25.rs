@@ -0,0 +1,29 @@
+use aoc::year2018::day25::*;
+
+const FIRST_EXAMPLE: &str = "\
+0,0,0,0
+3,0,0,0
+0,3,0,0
+0,0,3,0
+0,0,0,3
+0,0,0,6
+9,0,0,0
+12,0,0,0";
+
+const SECOND_EXAMPLE: &str = "\
+-1,2,2,0
+0,0,2,-2
+0,0,0,-2
+-1,2,0,0
+-2,-2,-2,2
+3,0,2,-1
+-1,3,2,2
+-1,0,-1,0
+0,2,1,-2
+3,0,0,0";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse(FIRST_EXAMPLE)), 2);
+    assert_eq!(part1(&parse(SECOND_EXAMPLE)), 4);
+}
@@ -0,0 +1,16 @@
+use aoc::year2019::day01::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("12")), 2);
+    assert_eq!(part1(&parse("14")), 2);
+    assert_eq!(part1(&parse("1969")), 654);
+    assert_eq!(part1(&parse("100756")), 33583);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("14")), 2);
+    assert_eq!(part2(&parse("1969")), 966);
+    assert_eq!(part2(&parse("100756")), 50346);
+}
@@ -0,0 +1,15 @@
+use aoc::year2019::day02::*;
+
+const EXAMPLE: &str = "\
+1,9,10,3,2,3,11,0,99,30,40,50";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(execute(&input, 9, 10), 3500);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
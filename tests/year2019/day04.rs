@@ -0,0 +1,15 @@
+use aoc::year2019::day04::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("111111-111111")), 1);
+    assert_eq!(part1(&parse("223450-223450")), 0);
+    assert_eq!(part1(&parse("123789-123789")), 0);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("112233-112233")), 1);
+    assert_eq!(part2(&parse("123444-123444")), 0);
+    assert_eq!(part2(&parse("111122-111122")), 1);
+}
@@ -0,0 +1,14 @@
+use aoc::year2019::day09::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("1102,34915192,34915192,7,4,7,99,0")), 1219070632396864);
+    assert_eq!(part1(&parse("104,1125899906842624,99")), 1125899906842624);
+}
+
+#[test]
+fn part2_test() {
+    // Quine, the last output is the last value of the program.
+    let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    assert_eq!(part2(&parse(quine)), 99);
+}
@@ -0,0 +1,22 @@
+use aoc::year2019::day10::*;
+
+const EXAMPLE: &str = "\
+.#..#
+.....
+#####
+....#
+...##";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 8);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(vaporized(&input, 1), 302);
+    assert_eq!(vaporized(&input, 5), 404);
+    assert_eq!(vaporized(&input, 9), 100);
+}
@@ -0,0 +1,19 @@
+use aoc::year2019::day16::*;
+
+const FIRST_EXAMPLE: &str = "\
+19617804207202209144916044189917";
+
+const SECOND_EXAMPLE: &str = "\
+03036732577212944063491565474664";
+
+#[test]
+fn part1_test() {
+    let input = parse(FIRST_EXAMPLE);
+    assert_eq!(part1(&input), "73745418");
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(SECOND_EXAMPLE);
+    assert_eq!(part2(&input), "84462026");
+}
@@ -0,0 +1,20 @@
+use aoc::year2019::day17::*;
+
+const EXAMPLE: &str = "\
+..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^..";
+
+#[test]
+fn part1_test() {
+    assert_eq!(alignment(EXAMPLE), 76);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
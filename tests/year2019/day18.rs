@@ -0,0 +1,39 @@
+use aoc::year2019::day18::*;
+
+const FIRST_EXAMPLE: &str = "\
+########################
+#f.D.E.e.C.b.A.@.a.B.c.#
+######################.#
+#d.....................#
+########################";
+
+const SECOND_EXAMPLE: &str = "\
+#################
+#i.G..c...e..H.p#
+########.########
+#j.A..b...f..D.o#
+########@########
+#k.E..a...g..B.n#
+########.########
+#l.F..d...h..C.m#
+#################";
+
+const THIRD_EXAMPLE: &str = "\
+#######
+#a.#Cd#
+##...##
+##.@.##
+##...##
+#cB#Ab#
+#######";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse(FIRST_EXAMPLE)), 86);
+    assert_eq!(part1(&parse(SECOND_EXAMPLE)), 136);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse(THIRD_EXAMPLE)), 8);
+}
@@ -0,0 +1,24 @@
+use aoc::year2019::day22::*;
+
+const EXAMPLE: &str = "\
+deal into new stack
+cut -2
+deal with increment 7
+cut 8
+cut -4
+deal with increment 7
+cut 3
+deal with increment 9
+deal with increment 3
+cut -1";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(deck(&input, 10), [9, 2, 5, 8, 1, 4, 7, 0, 3, 6]);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
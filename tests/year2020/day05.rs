@@ -0,0 +1,17 @@
+use aoc::year2020::day05::*;
+
+const EXAMPLE: &str = "\
+BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 820);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
@@ -0,0 +1,21 @@
+use aoc::year2020::day13::*;
+
+const EXAMPLE: &str = "\
+939
+7,13,x,x,59,x,31,19";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 295);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse(EXAMPLE)), 1068781);
+    assert_eq!(part2(&parse("0\n17,x,13,19")), 3417);
+    assert_eq!(part2(&parse("0\n67,7,59,61")), 754018);
+    assert_eq!(part2(&parse("0\n67,x,7,59,61")), 779210);
+    assert_eq!(part2(&parse("0\n67,7,x,59,61")), 1261476);
+    assert_eq!(part2(&parse("0\n1789,37,47,1889")), 1202161486);
+}
@@ -0,0 +1,18 @@
+use aoc::year2020::day15::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("0,3,6")), 436);
+    assert_eq!(part1(&parse("1,3,2")), 1);
+    assert_eq!(part1(&parse("2,1,3")), 10);
+    assert_eq!(part1(&parse("1,2,3")), 27);
+    assert_eq!(part1(&parse("2,3,1")), 78);
+    assert_eq!(part1(&parse("3,2,1")), 438);
+    assert_eq!(part1(&parse("3,1,2")), 1836);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse("0,3,6");
+    assert_eq!(part2(&input), 175594);
+}
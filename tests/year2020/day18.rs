@@ -0,0 +1,21 @@
+use aoc::year2020::day18::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("1 + 2 * 3 + 4 * 5 + 6")), 71);
+    assert_eq!(part1(&parse("1 + (2 * 3) + (4 * (5 + 6))")), 51);
+    assert_eq!(part1(&parse("2 * 3 + (4 * 5)")), 26);
+    assert_eq!(part1(&parse("5 + (8 * 3 + 9 + 3 * 4 * 3)")), 437);
+    assert_eq!(part1(&parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))")), 12240);
+    assert_eq!(part1(&parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2")), 13632);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("1 + 2 * 3 + 4 * 5 + 6")), 231);
+    assert_eq!(part2(&parse("1 + (2 * 3) + (4 * (5 + 6))")), 51);
+    assert_eq!(part2(&parse("2 * 3 + (4 * 5)")), 46);
+    assert_eq!(part2(&parse("5 + (8 * 3 + 9 + 3 * 4 * 3)")), 1445);
+    assert_eq!(part2(&parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))")), 669060);
+    assert_eq!(part2(&parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2")), 23340);
+}
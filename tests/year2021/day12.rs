@@ -0,0 +1,56 @@
+use aoc::year2021::day12::*;
+
+const FIRST_EXAMPLE: &str = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+const SECOND_EXAMPLE: &str = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sg
+kj-HN
+kj-dc";
+
+const THIRD_EXAMPLE: &str = "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW";
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse(FIRST_EXAMPLE)), 10);
+    assert_eq!(part1(&parse(SECOND_EXAMPLE)), 19);
+    assert_eq!(part1(&parse(THIRD_EXAMPLE)), 226);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse(FIRST_EXAMPLE)), 36);
+    assert_eq!(part2(&parse(SECOND_EXAMPLE)), 103);
+    assert_eq!(part2(&parse(THIRD_EXAMPLE)), 3509);
+}
@@ -0,0 +1,42 @@
+use aoc::year2021::day13::*;
+
+const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 17);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    let expected = "
+#####
+#...#
+#...#
+#...#
+#####";
+    assert_eq!(part2(&input), expected);
+}
@@ -0,0 +1,21 @@
+use aoc::year2021::day16::*;
+
+#[test]
+fn part1_test() {
+    assert_eq!(part1(&parse("8A004A801A8002F478")), 16);
+    assert_eq!(part1(&parse("620080001611562C8802118E34")), 12);
+    assert_eq!(part1(&parse("C0015000016115A2E0802F182340")), 23);
+    assert_eq!(part1(&parse("A0016C880162017C3686B18A3D4780")), 31);
+}
+
+#[test]
+fn part2_test() {
+    assert_eq!(part2(&parse("C200B40A82")), 3);
+    assert_eq!(part2(&parse("04005AC33890")), 54);
+    assert_eq!(part2(&parse("880086C3E88112")), 7);
+    assert_eq!(part2(&parse("CE00C43D881120")), 9);
+    assert_eq!(part2(&parse("D8005AC2A8F0")), 1);
+    assert_eq!(part2(&parse("F600BC2D8F")), 0);
+    assert_eq!(part2(&parse("9C005AC2F8F0")), 0);
+    assert_eq!(part2(&parse("9C0141080250320F1802104A08")), 1);
+}
@@ -0,0 +1,48 @@
+use aoc::year2021::day19::*;
+
+// The puzzle's own example is very long, so this smaller one uses the same rules. The two
+// scanners share 12 beacons, with the second at 68,-1246,-43 facing the opposite way along the
+// x and z axes.
+const EXAMPLE: &str = "\
+--- scanner 0 ---
+486,-812,707
+165,-653,147
+-492,-95,-189
+183,-308,297
+-597,318,-366
+-680,-367,-57
+-230,-352,809
+-680,683,-234
+-263,-854,265
+364,-488,900
+-756,-486,-667
+210,33,-105
+873,602,-750
+
+--- scanner 1 ---
+-97,593,-190
+560,1151,146
+-115,938,-340
+665,1564,323
+748,879,14
+298,894,-852
+748,1929,191
+331,392,-308
+-296,758,-943
+824,760,624
+-142,1279,62
+-805,1848,707
+-676,547,9
+934,549,-328";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 15);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part2(&input), 1357);
+}
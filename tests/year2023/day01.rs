@@ -26,3 +26,9 @@ fn part2_test() {
     let input = parse(SECOND_EXAMPLE);
     assert_eq!(part2(&input), 281);
 }
+
+#[test]
+fn differential_test() {
+    let result = reference::differential().check(2023, 1000, &[]);
+    assert!(result.is_ok(), "{}", result.err().unwrap());
+}
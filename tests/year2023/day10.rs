@@ -0,0 +1,56 @@
+use aoc::year2023::day10::*;
+
+const FIRST_EXAMPLE: &str = "\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+
+const SECOND_EXAMPLE: &str = "\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+const THIRD_EXAMPLE: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+const FOURTH_EXAMPLE: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+#[test]
+fn part1_test() {
+    let input = parse(FIRST_EXAMPLE);
+    assert_eq!(part1(&input), 4);
+
+    let input = parse(SECOND_EXAMPLE);
+    assert_eq!(part1(&input), 8);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(THIRD_EXAMPLE);
+    assert_eq!(part2(&input), 4);
+
+    let input = parse(FOURTH_EXAMPLE);
+    assert_eq!(part2(&input), 8);
+}
@@ -0,0 +1,26 @@
+use aoc::year2023::day11::*;
+
+const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 374);
+}
+
+#[test]
+fn part2_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(distances(&input, 10), 1030);
+    assert_eq!(distances(&input, 100), 8410);
+}
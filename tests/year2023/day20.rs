@@ -0,0 +1,29 @@
+use aoc::year2023::day20::*;
+
+const FIRST_EXAMPLE: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+const SECOND_EXAMPLE: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+#[test]
+fn part1_test() {
+    let input = parse(FIRST_EXAMPLE);
+    assert_eq!(part1(&input), 32000000);
+
+    let input = parse(SECOND_EXAMPLE);
+    assert_eq!(part1(&input), 11687500);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}
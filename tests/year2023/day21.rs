@@ -0,0 +1,28 @@
+use aoc::year2023::day21::*;
+
+const EXAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.####..##..
+...........";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(plots(&input, 6), 16);
+}
+
+#[test]
+fn part2_test() {
+    // The example doesn't have the structure part two relies on,
+    // however the infinite garden can still be checked for small step counts.
+    let input = parse(EXAMPLE);
+    assert_eq!(plots(&input, 10), 50);
+}
@@ -0,0 +1,15 @@
+use aoc::year2024::day11::*;
+
+const EXAMPLE: &str = "\
+125 17";
+
+#[test]
+fn part1_test() {
+    let input = parse(EXAMPLE);
+    assert_eq!(part1(&input), 55312);
+}
+
+#[test]
+fn part2_test() {
+    // No example data
+}